@@ -1,8 +1,11 @@
 import { Module } from '@nestjs/common';
 
 import { GamesModule } from './games/games.module';
+import { HealthController } from './health.controller';
+import { VersionController } from './version.controller';
 
 @Module({
-  imports: [GamesModule]
+  imports: [GamesModule],
+  controllers: [HealthController, VersionController]
 })
 export class AppModule {}
@@ -0,0 +1,6 @@
+import { IsUUID } from 'class-validator';
+
+export class CancelPvpGameDto {
+  @IsUUID()
+  playerId!: string;
+}
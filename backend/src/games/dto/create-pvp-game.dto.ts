@@ -1,4 +1,6 @@
-import { IsOptional, IsString, IsUUID, MaxLength, MinLength } from 'class-validator';
+import { IsIn, IsOptional, IsString, IsUUID, MaxLength, MinLength } from 'class-validator';
+
+import { PlayerSymbol } from '../game.types';
 
 export class CreatePvpGameDto {
   @IsUUID()
@@ -14,4 +16,15 @@ export class CreatePvpGameDto {
   @MinLength(3)
   @MaxLength(32)
   password?: string;
+
+  @IsOptional()
+  @IsString()
+  @MinLength(1)
+  @MaxLength(32)
+  playerName?: string;
+
+  // Who moves first; X (the host) when omitted. Clients alternate it between rematches.
+  @IsOptional()
+  @IsIn(['X', 'O'])
+  firstTurn?: PlayerSymbol;
 }
@@ -1,4 +1,6 @@
-import { IsString, IsUUID } from 'class-validator';
+import { IsIn, IsOptional, IsString, IsUUID, MaxLength, MinLength } from 'class-validator';
+
+import { PlayerSymbol } from '../game.types';
 
 export class CreateSoloGameDto {
   @IsUUID()
@@ -6,4 +8,15 @@ export class CreateSoloGameDto {
 
   @IsString()
   clientName!: string;
+
+  @IsOptional()
+  @IsString()
+  @MinLength(1)
+  @MaxLength(32)
+  playerName?: string;
+
+  // Who moves first; X (the host) when omitted. Clients alternate it between rematches.
+  @IsOptional()
+  @IsIn(['X', 'O'])
+  firstTurn?: PlayerSymbol;
 }
@@ -9,4 +9,10 @@ export class JoinPvpGameDto {
   @MinLength(3)
   @MaxLength(32)
   password?: string;
+
+  @IsOptional()
+  @IsString()
+  @MinLength(1)
+  @MaxLength(32)
+  playerName?: string;
 }
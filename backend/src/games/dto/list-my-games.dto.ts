@@ -0,0 +1,7 @@
+import { IsUUID } from 'class-validator';
+
+// Query string of GET /games/pvp/mine.
+export class ListMyGamesDto {
+  @IsUUID()
+  playerId!: string;
+}
@@ -0,0 +1,7 @@
+import { IsUUID } from 'class-validator';
+
+// Shared by the rematch request, accept and cancel endpoints: all they need is who is asking.
+export class RematchDto {
+  @IsUUID()
+  playerId!: string;
+}
@@ -0,0 +1,50 @@
+import {
+  ArrayMaxSize,
+  ArrayMinSize,
+  IsArray,
+  IsIn,
+  IsInt,
+  IsOptional,
+  IsString,
+  IsUUID,
+  MaxLength,
+  Min,
+  MinLength
+} from 'class-validator';
+
+import { PlayerSymbol, ResultOutcome } from '../game.types';
+
+export class ReportResultDto {
+  // Chosen by the client; reporting the same id again is accepted but not counted twice.
+  @IsUUID()
+  resultId!: string;
+
+  @IsUUID()
+  playerId!: string;
+
+  @IsOptional()
+  @IsString()
+  @MinLength(1)
+  @MaxLength(32)
+  playerName?: string;
+
+  @IsString()
+  clientName!: string;
+
+  @IsIn(['WON', 'LOST', 'DRAW'])
+  outcome!: ResultOutcome;
+
+  @IsIn(['X', 'O'])
+  firstTurn!: PlayerSymbol;
+
+  @IsArray()
+  @ArrayMinSize(9)
+  @ArrayMaxSize(9)
+  @IsIn(['X', 'O', null], { each: true })
+  board!: (PlayerSymbol | null)[];
+
+  // Unix seconds on the client's clock.
+  @IsInt()
+  @Min(0)
+  finishedAt!: number;
+}
@@ -0,0 +1,6 @@
+import { IsUUID } from 'class-validator';
+
+export class ResignGameDto {
+  @IsUUID()
+  playerId!: string;
+}
@@ -0,0 +1,12 @@
+import { IsOptional, IsString, IsUUID, MaxLength, MinLength } from 'class-validator';
+
+export class WatchGameDto {
+  @IsUUID()
+  playerId!: string;
+
+  @IsOptional()
+  @IsString()
+  @MinLength(1)
+  @MaxLength(32)
+  playerName?: string;
+}
@@ -1,6 +1,8 @@
 export type PlayerSymbol = 'X' | 'O';
 export type GameMode = 'SOLO' | 'PVP';
 export type GameStatus = 'WAITING_FOR_PLAYER' | 'IN_PROGRESS' | 'WON' | 'DRAW';
+// LINE: three in a row, FORFEIT: the loser resigned, TIMEOUT: the loser went idle (claim-win).
+export type WinReason = 'LINE' | 'FORFEIT' | 'TIMEOUT';
 
 export type GameState = {
   id: string;
@@ -8,17 +10,53 @@ export type GameState = {
   name: string | null;
   hostPlayerId: string;
   guestPlayerId: string | null;
+  hostName: string | null;
+  guestName: string | null;
   board: (PlayerSymbol | null)[];
   currentTurn: PlayerSymbol;
+  firstTurn: PlayerSymbol;
   status: GameStatus;
   winner: PlayerSymbol | null;
+  resignedBy: PlayerSymbol | null;
+  // How a WON game was decided; `winningLine` holds the three board indices for LINE wins.
+  winReason: WinReason | null;
+  winningLine: number[] | null;
   createdAt: string;
   updatedAt: string;
   hasPassword: boolean;
   password: string | null;
+  // Rematch handshake of a finished PvP game: who asked, then the id of the new game once accepted.
+  rematchRequestedBy: PlayerSymbol | null;
+  rematchGameId: string | null;
+  // Wins of the host's and the guest's player in the earlier games of this rematch series.
+  // The public state adds this game's result on top (hostScore/guestScore).
+  hostWinsBefore: number;
+  guestWinsBefore: number;
 };
 
-export type PublicGameState = Omit<GameState, 'password'>;
+// Outcome of a solo game played on the client, from the player's point of view.
+export type ResultOutcome = 'WON' | 'LOST' | 'DRAW';
+
+// A solo game the client played locally and reported afterwards (POST /games/results).
+export type SoloResult = {
+  resultId: string;
+  playerId: string;
+  playerName: string | null;
+  clientName: string;
+  outcome: ResultOutcome;
+  firstTurn: PlayerSymbol;
+  board: (PlayerSymbol | null)[];
+  finishedAt: number;
+  recordedAt: string;
+};
+
+// `spectators` lists the display names of people currently watching (see POST /games/:id/watch).
+// `hostScore`/`guestScore` are each seat's wins across the rematch series, this game included.
+export type PublicGameState = Omit<GameState, 'password' | 'hostWinsBefore' | 'guestWinsBefore'> & {
+  spectators: string[];
+  hostScore: number;
+  guestScore: number;
+};
 
 export const WINNING_LINES: readonly number[][] = [
   [0, 1, 2],
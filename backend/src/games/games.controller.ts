@@ -1,9 +1,18 @@
-import { Body, Controller, Get, Param, Post } from '@nestjs/common';
+import { Body, Controller, Get, MessageEvent, Param, Post, Query, Res, Sse } from '@nestjs/common';
+import { Observable } from 'rxjs';
 
+import { CancelPvpGameDto } from './dto/cancel-pvp-game.dto';
+import { ClaimWinDto } from './dto/claim-win.dto';
 import { CreatePvpGameDto } from './dto/create-pvp-game.dto';
 import { CreateSoloGameDto } from './dto/create-solo-game.dto';
 import { JoinPvpGameDto } from './dto/join-pvp-game.dto';
+import { ListMyGamesDto } from './dto/list-my-games.dto';
+import { ListOpenGamesDto } from './dto/list-open-games.dto';
 import { PlayMoveDto } from './dto/play-move.dto';
+import { RematchDto } from './dto/rematch.dto';
+import { ReportResultDto } from './dto/report-result.dto';
+import { ResignGameDto } from './dto/resign-game.dto';
+import { WatchGameDto } from './dto/watch-game.dto';
 import { GamesService } from './games.service';
 
 @Controller('games')
@@ -20,9 +29,30 @@ export class GamesController {
     return this.gamesService.createPvpGame(body);
   }
 
+  @Post('results')
+  reportResult(@Body() body: ReportResultDto) {
+    return this.gamesService.reportResult(body);
+  }
+
   @Get('pvp/open')
-  listOpenPvpGames() {
-    return this.gamesService.listOpenPvpGames();
+  listOpenPvpGames(
+    @Query() query: ListOpenGamesDto,
+    @Res({ passthrough: true }) res: { setHeader(name: string, value: number): void }
+  ) {
+    // The body stays a plain array; the size of the whole list travels in a header.
+    const { games, total } = this.gamesService.listOpenPvpGames(query.offset, query.limit);
+    res.setHeader('X-Total-Count', total);
+    return games;
+  }
+
+  @Get('pvp/live')
+  listLivePvpGames() {
+    return this.gamesService.listLivePvpGames();
+  }
+
+  @Get('pvp/mine')
+  listMyPvpGames(@Query() query: ListMyGamesDto) {
+    return this.gamesService.listMyPvpGames(query.playerId);
   }
 
   @Post('pvp/:gameId/join')
@@ -30,13 +60,53 @@ export class GamesController {
     return this.gamesService.joinPvpGame(gameId, body);
   }
 
+  @Post('pvp/:gameId/cancel')
+  cancelPvpGame(@Param('gameId') gameId: string, @Body() body: CancelPvpGameDto) {
+    return this.gamesService.cancelPvpGame(gameId, body);
+  }
+
   @Get(':gameId')
   getGame(@Param('gameId') gameId: string) {
     return this.gamesService.getGame(gameId);
   }
 
+  @Sse(':gameId/events')
+  gameEvents(@Param('gameId') gameId: string): Observable<MessageEvent> {
+    return this.gamesService.gameEvents(gameId);
+  }
+
   @Post(':gameId/move')
   playMove(@Param('gameId') gameId: string, @Body() body: PlayMoveDto) {
     return this.gamesService.playMove(gameId, body);
   }
+
+  @Post(':gameId/resign')
+  resignGame(@Param('gameId') gameId: string, @Body() body: ResignGameDto) {
+    return this.gamesService.resignGame(gameId, body);
+  }
+
+  @Post(':gameId/claim-win')
+  claimWin(@Param('gameId') gameId: string, @Body() body: ClaimWinDto) {
+    return this.gamesService.claimWin(gameId, body);
+  }
+
+  @Post(':gameId/watch')
+  watchGame(@Param('gameId') gameId: string, @Body() body: WatchGameDto) {
+    return this.gamesService.watchGame(gameId, body);
+  }
+
+  @Post(':gameId/rematch')
+  requestRematch(@Param('gameId') gameId: string, @Body() body: RematchDto) {
+    return this.gamesService.requestRematch(gameId, body);
+  }
+
+  @Post(':gameId/rematch/accept')
+  acceptRematch(@Param('gameId') gameId: string, @Body() body: RematchDto) {
+    return this.gamesService.acceptRematch(gameId, body);
+  }
+
+  @Post(':gameId/rematch/cancel')
+  cancelRematch(@Param('gameId') gameId: string, @Body() body: RematchDto) {
+    return this.gamesService.cancelRematch(gameId, body);
+  }
 }
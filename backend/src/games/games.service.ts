@@ -1,22 +1,50 @@
 import {
   BadRequestException,
+  ConflictException,
   Injectable,
+  MessageEvent,
   NotFoundException,
   UnauthorizedException
 } from '@nestjs/common';
 import { randomUUID } from 'crypto';
+import { defer, interval, map, mergeMap, Observable, startWith, takeWhile } from 'rxjs';
 
+import { CancelPvpGameDto } from './dto/cancel-pvp-game.dto';
+import { ClaimWinDto } from './dto/claim-win.dto';
 import { CreatePvpGameDto } from './dto/create-pvp-game.dto';
 import { CreateSoloGameDto } from './dto/create-solo-game.dto';
 import { JoinPvpGameDto } from './dto/join-pvp-game.dto';
 import { PlayMoveDto } from './dto/play-move.dto';
-import { GameState, PlayerSymbol, PublicGameState, WINNING_LINES } from './game.types';
+import { RematchDto } from './dto/rematch.dto';
+import { ReportResultDto } from './dto/report-result.dto';
+import { ResignGameDto } from './dto/resign-game.dto';
+import { WatchGameDto } from './dto/watch-game.dto';
+import { GameState, PlayerSymbol, PublicGameState, SoloResult, WINNING_LINES } from './game.types';
+
+// How long a PvP player may sit on their turn before the opponent can claim the win.
+const IDLE_FORFEIT_AFTER_MS = 60_000;
+// Spectators poll /watch about once a second; after this long without a poll they count as gone.
+const SPECTATOR_TIMEOUT_MS = 5_000;
+// How often an event stream looks for changes to its game.
+const EVENTS_CHECK_MS = 250;
+// An idle game's stream still sends a heartbeat this often, so clients can tell a quiet game
+// from a dead connection.
+const EVENTS_HEARTBEAT_MS = 10_000;
+
+type Spectator = {
+  name: string | null;
+  lastSeenAt: number;
+};
 
 @Injectable()
 export class GamesService {
   // In-memory storage is enough for this local-learning project.
   // If you move to production, replace this with a repository/database layer.
   private readonly games = new Map<string, GameState>();
+  // Game id -> player id -> spectator, refreshed by every watch request.
+  private readonly spectators = new Map<string, Map<string, Spectator>>();
+  // Result id -> solo game played offline by a client and reported once it was over.
+  private readonly results = new Map<string, SoloResult>();
 
   createSoloGame(dto: CreateSoloGameDto): PublicGameState {
     const now = new Date().toISOString();
@@ -26,16 +54,32 @@ export class GamesService {
       name: `Solo game (${dto.clientName})`,
       hostPlayerId: dto.playerId,
       guestPlayerId: 'AI',
+      hostName: dto.playerName ?? null,
+      guestName: 'Computer',
       board: Array.from({ length: 9 }, () => null),
-      currentTurn: 'X',
+      currentTurn: dto.firstTurn ?? 'X',
+      firstTurn: dto.firstTurn ?? 'X',
       status: 'IN_PROGRESS',
       winner: null,
+      resignedBy: null,
+      winReason: null,
+      winningLine: null,
       createdAt: now,
       updatedAt: now,
       hasPassword: false,
-      password: null
+      password: null,
+      rematchRequestedBy: null,
+      rematchGameId: null,
+      hostWinsBefore: 0,
+      guestWinsBefore: 0
     };
 
+    // When the computer starts, it plays its opening move before the human sees the board.
+    if (game.currentTurn === 'O') {
+      game.board[this.selectAiMove(game.board)] = 'O';
+      this.applyPostMoveState(game);
+    }
+
     this.games.set(game.id, game);
     return this.toPublic(game);
   }
@@ -48,26 +92,83 @@ export class GamesService {
       name: dto.name,
       hostPlayerId: dto.playerId,
       guestPlayerId: null,
+      hostName: dto.playerName ?? null,
+      guestName: null,
       board: Array.from({ length: 9 }, () => null),
-      currentTurn: 'X',
+      currentTurn: dto.firstTurn ?? 'X',
+      firstTurn: dto.firstTurn ?? 'X',
       status: 'WAITING_FOR_PLAYER',
       winner: null,
+      resignedBy: null,
+      winReason: null,
+      winningLine: null,
       createdAt: now,
       updatedAt: now,
       hasPassword: Boolean(dto.password),
-      password: dto.password ?? null
+      password: dto.password ?? null,
+      rematchRequestedBy: null,
+      rematchGameId: null,
+      hostWinsBefore: 0,
+      guestWinsBefore: 0
     };
 
     this.games.set(game.id, game);
     return this.toPublic(game);
   }
 
-  listOpenPvpGames(): PublicGameState[] {
+  reportResult(dto: ReportResultDto): { resultId: string; recorded: boolean } {
+    // Clients retry uploads whose answer got lost, so a known id is acknowledged, not re-counted.
+    if (this.results.has(dto.resultId)) {
+      return { resultId: dto.resultId, recorded: false };
+    }
+
+    this.results.set(dto.resultId, {
+      resultId: dto.resultId,
+      playerId: dto.playerId,
+      playerName: dto.playerName ?? null,
+      clientName: dto.clientName,
+      outcome: dto.outcome,
+      firstTurn: dto.firstTurn,
+      board: dto.board,
+      finishedAt: dto.finishedAt,
+      recordedAt: new Date().toISOString()
+    });
+    return { resultId: dto.resultId, recorded: true };
+  }
+
+  listOpenPvpGames(offset = 0, limit?: number): { games: PublicGameState[]; total: number } {
     const openGames = [...this.games.values()]
       .filter((game) => game.mode === 'PVP' && game.status === 'WAITING_FOR_PLAYER')
       .sort((a, b) => b.createdAt.localeCompare(a.createdAt));
 
-    return openGames.map((game) => this.toPublic(game));
+    const end = limit === undefined ? undefined : offset + limit;
+    return {
+      games: openGames.slice(offset, end).map((game) => this.toPublic(game)),
+      total: openGames.length
+    };
+  }
+
+  listMyPvpGames(playerId: string): PublicGameState[] {
+    // PvP games the player hosts or plays in that aren't over, most recently active first.
+    const myGames = [...this.games.values()]
+      .filter(
+        (game) =>
+          game.mode === 'PVP' &&
+          (game.status === 'WAITING_FOR_PLAYER' || game.status === 'IN_PROGRESS') &&
+          (game.hostPlayerId === playerId || game.guestPlayerId === playerId)
+      )
+      .sort((a, b) => b.updatedAt.localeCompare(a.updatedAt));
+
+    return myGames.map((game) => this.toPublic(game));
+  }
+
+  listLivePvpGames(): PublicGameState[] {
+    // In-progress PvP games anyone can watch, most recently active first.
+    const liveGames = [...this.games.values()]
+      .filter((game) => game.mode === 'PVP' && game.status === 'IN_PROGRESS')
+      .sort((a, b) => b.updatedAt.localeCompare(a.updatedAt));
+
+    return liveGames.map((game) => this.toPublic(game));
   }
 
   joinPvpGame(gameId: string, dto: JoinPvpGameDto): PublicGameState {
@@ -78,7 +179,8 @@ export class GamesService {
     }
 
     if (game.status !== 'WAITING_FOR_PLAYER') {
-      throw new BadRequestException('This game is not waiting for a second player');
+      // Usually someone else joined first; 409 lets clients tell that race from a bad request.
+      throw new ConflictException('This game is not waiting for a second player');
     }
 
     if (game.hostPlayerId === dto.playerId) {
@@ -90,16 +192,66 @@ export class GamesService {
     }
 
     game.guestPlayerId = dto.playerId;
+    game.guestName = dto.playerName ?? null;
     game.status = 'IN_PROGRESS';
     game.updatedAt = new Date().toISOString();
 
     return this.toPublic(game);
   }
 
+  cancelPvpGame(gameId: string, dto: CancelPvpGameDto): PublicGameState {
+    const game = this.getExistingGame(gameId);
+
+    if (game.mode !== 'PVP' || game.status !== 'WAITING_FOR_PLAYER') {
+      throw new BadRequestException('Only PvP games still waiting for a player can be cancelled');
+    }
+
+    if (game.hostPlayerId !== dto.playerId) {
+      throw new UnauthorizedException('Only the host can cancel this game');
+    }
+
+    // Nobody joined yet, so there is nothing to keep: the game simply disappears.
+    this.games.delete(game.id);
+    this.spectators.delete(game.id);
+    return this.toPublic(game);
+  }
+
   getGame(gameId: string): PublicGameState {
     return this.toPublic(this.getExistingGame(gameId));
   }
 
+  // Server-Sent Events: the game's public state now and after every change, plus a `heartbeat`
+  // event (empty data) after EVENTS_HEARTBEAT_MS without one. The stream ends when the game is
+  // deleted; an unknown id fails right away with a 404.
+  gameEvents(gameId: string): Observable<MessageEvent> {
+    this.getExistingGame(gameId);
+
+    // `defer` gives every subscriber its own record of what it was last sent.
+    return defer(() => {
+      let sent: string | null = null;
+      let sentAt = 0;
+      return interval(EVENTS_CHECK_MS).pipe(
+        startWith(0),
+        map(() => this.games.get(gameId)),
+        takeWhile((game): game is GameState => game !== undefined),
+        mergeMap((game): MessageEvent[] => {
+          const data = JSON.stringify(this.toPublic(game));
+          const now = Date.now();
+          if (data !== sent) {
+            sent = data;
+            sentAt = now;
+            return [{ data }];
+          }
+          if (now - sentAt >= EVENTS_HEARTBEAT_MS) {
+            sentAt = now;
+            return [{ type: 'heartbeat', data: '' }];
+          }
+          return [];
+        })
+      );
+    });
+  }
+
   playMove(gameId: string, dto: PlayMoveDto): PublicGameState {
     const game = this.getExistingGame(gameId);
 
@@ -134,13 +286,203 @@ export class GamesService {
     return this.toPublic(game);
   }
 
+  resignGame(gameId: string, dto: ResignGameDto): PublicGameState {
+    const game = this.getExistingGame(gameId);
+
+    if (game.status !== 'IN_PROGRESS') {
+      throw new BadRequestException('Game is not active');
+    }
+
+    let resigningSymbol: PlayerSymbol;
+    if (game.hostPlayerId === dto.playerId) {
+      resigningSymbol = 'X';
+    } else if (game.guestPlayerId === dto.playerId) {
+      resigningSymbol = 'O';
+    } else {
+      throw new UnauthorizedException('You are not a player in this game');
+    }
+
+    // Resigning hands the win to the other side; the board is left as it was.
+    game.status = 'WON';
+    game.winner = resigningSymbol === 'X' ? 'O' : 'X';
+    game.resignedBy = resigningSymbol;
+    game.winReason = 'FORFEIT';
+    game.updatedAt = new Date().toISOString();
+
+    return this.toPublic(game);
+  }
+
+  claimWin(gameId: string, dto: ClaimWinDto): PublicGameState {
+    const game = this.getExistingGame(gameId);
+
+    if (game.mode !== 'PVP' || game.status !== 'IN_PROGRESS') {
+      throw new BadRequestException('Only active PvP games can be claimed');
+    }
+
+    let claimingSymbol: PlayerSymbol;
+    if (game.hostPlayerId === dto.playerId) {
+      claimingSymbol = 'X';
+    } else if (game.guestPlayerId === dto.playerId) {
+      claimingSymbol = 'O';
+    } else {
+      throw new UnauthorizedException('You are not a player in this game');
+    }
+
+    if (game.currentTurn === claimingSymbol) {
+      throw new BadRequestException('It is your turn, the opponent is not holding up the game');
+    }
+
+    // updatedAt only moves when the game changes, so it doubles as "last activity".
+    if (Date.now() - Date.parse(game.updatedAt) < IDLE_FORFEIT_AFTER_MS) {
+      throw new BadRequestException('Opponent has not been inactive long enough');
+    }
+
+    // The idle player forfeits: recorded like a resignation on their behalf.
+    game.status = 'WON';
+    game.winner = claimingSymbol;
+    game.resignedBy = game.currentTurn;
+    game.winReason = 'TIMEOUT';
+    game.updatedAt = new Date().toISOString();
+
+    return this.toPublic(game);
+  }
+
+  watchGame(gameId: string, dto: WatchGameDto): PublicGameState {
+    const game = this.getExistingGame(gameId);
+
+    // The game's own players never count as spectators.
+    if (dto.playerId !== game.hostPlayerId && dto.playerId !== game.guestPlayerId) {
+      const viewers = this.spectators.get(game.id) ?? new Map<string, Spectator>();
+      viewers.set(dto.playerId, { name: dto.playerName ?? null, lastSeenAt: Date.now() });
+      this.spectators.set(game.id, viewers);
+    }
+
+    return this.toPublic(game);
+  }
+
+  requestRematch(gameId: string, dto: RematchDto): PublicGameState {
+    const game = this.getFinishedPvpGame(gameId);
+    const symbol = this.seatOf(game, dto.playerId);
+
+    if (game.rematchGameId) {
+      return this.toPublic(game);
+    }
+
+    // Both players asking at the same time is as good as one accepting the other.
+    if (game.rematchRequestedBy && game.rematchRequestedBy !== symbol) {
+      this.startRematch(game);
+      return this.toPublic(game);
+    }
+
+    game.rematchRequestedBy = symbol;
+    return this.toPublic(game);
+  }
+
+  acceptRematch(gameId: string, dto: RematchDto): PublicGameState {
+    const game = this.getFinishedPvpGame(gameId);
+    const symbol = this.seatOf(game, dto.playerId);
+
+    if (!game.rematchGameId) {
+      if (!game.rematchRequestedBy || game.rematchRequestedBy === symbol) {
+        throw new BadRequestException('Your opponent has not asked for a rematch');
+      }
+      this.startRematch(game);
+    }
+
+    // The accepting player gets the new game straight away; the requester finds it via rematchGameId.
+    return this.toPublic(this.getExistingGame(game.rematchGameId as string));
+  }
+
+  cancelRematch(gameId: string, dto: RematchDto): PublicGameState {
+    const game = this.getFinishedPvpGame(gameId);
+    this.seatOf(game, dto.playerId);
+
+    if (game.rematchGameId) {
+      throw new BadRequestException('The rematch has already started');
+    }
+
+    // Either side may clear the request: the requester withdraws it, the opponent declines it.
+    game.rematchRequestedBy = null;
+    return this.toPublic(game);
+  }
+
+  private startRematch(game: GameState): void {
+    // Seats swap but the starting symbol stays, so whoever did not start last time starts now.
+    const now = new Date().toISOString();
+    const rematch: GameState = {
+      ...game,
+      id: randomUUID(),
+      hostPlayerId: game.guestPlayerId as string,
+      guestPlayerId: game.hostPlayerId,
+      hostName: game.guestName,
+      guestName: game.hostName,
+      board: Array.from({ length: 9 }, () => null),
+      currentTurn: game.firstTurn,
+      status: 'IN_PROGRESS',
+      winner: null,
+      resignedBy: null,
+      winReason: null,
+      winningLine: null,
+      createdAt: now,
+      updatedAt: now,
+      rematchRequestedBy: null,
+      rematchGameId: null,
+      // The players swap seats, so their series wins swap along with them.
+      hostWinsBefore: game.guestWinsBefore + (game.winner === 'O' ? 1 : 0),
+      guestWinsBefore: game.hostWinsBefore + (game.winner === 'X' ? 1 : 0)
+    };
+
+    this.games.set(rematch.id, rematch);
+    game.rematchRequestedBy = null;
+    game.rematchGameId = rematch.id;
+  }
+
+  private getFinishedPvpGame(gameId: string): GameState {
+    const game = this.getExistingGame(gameId);
+    if (game.mode !== 'PVP' || !['WON', 'DRAW'].includes(game.status)) {
+      throw new BadRequestException('Rematches are only possible after a finished PvP game');
+    }
+
+    return game;
+  }
+
+  private seatOf(game: GameState, playerId: string): PlayerSymbol {
+    if (game.hostPlayerId === playerId) {
+      return 'X';
+    }
+    if (game.guestPlayerId === playerId) {
+      return 'O';
+    }
+
+    throw new UnauthorizedException('You are not a player in this game');
+  }
+
+  private activeSpectatorNames(gameId: string): string[] {
+    const viewers = this.spectators.get(gameId);
+    if (!viewers) {
+      return [];
+    }
+
+    // Drop viewers that stopped polling, then report who is left.
+    const cutoff = Date.now() - SPECTATOR_TIMEOUT_MS;
+    for (const [playerId, viewer] of viewers) {
+      if (viewer.lastSeenAt < cutoff) {
+        viewers.delete(playerId);
+      }
+    }
+
+    return [...viewers.values()].map((viewer) => viewer.name ?? 'Anonymous');
+  }
+
   private applyPostMoveState(game: GameState): void {
     // This function is pure game-rules logic and does not care about mode.
     // Keeping this split makes it easy to test or reuse in another transport layer.
-    const winner = this.getWinner(game.board);
-    if (winner) {
+    const winningLine = this.getWinningLine(game.board);
+    if (winningLine) {
       game.status = 'WON';
-      game.winner = winner;
+      game.winner = game.board[winningLine[0]];
+      game.winReason = 'LINE';
+      game.winningLine = winningLine;
       return;
     }
 
@@ -164,14 +506,21 @@ export class GamesService {
 
   private toPublic(game: GameState): PublicGameState {
     // Never expose password in API responses.
-    const { password: _password, ...publicGame } = game;
-    return publicGame;
+    const { password: _password, hostWinsBefore, guestWinsBefore, ...publicGame } = game;
+    return {
+      ...publicGame,
+      spectators: this.activeSpectatorNames(game.id),
+      // The host always plays X.
+      hostScore: hostWinsBefore + (game.winner === 'X' ? 1 : 0),
+      guestScore: guestWinsBefore + (game.winner === 'O' ? 1 : 0)
+    };
   }
 
-  private getWinner(board: (PlayerSymbol | null)[]): PlayerSymbol | null {
-    for (const [a, b, c] of WINNING_LINES) {
+  private getWinningLine(board: (PlayerSymbol | null)[]): number[] | null {
+    for (const line of WINNING_LINES) {
+      const [a, b, c] = line;
       if (board[a] && board[a] === board[b] && board[b] === board[c]) {
-        return board[a];
+        return [...line];
       }
     }
 
@@ -0,0 +1,10 @@
+import { Controller, Get } from '@nestjs/common';
+
+@Controller('health')
+export class HealthController {
+  // Cheap liveness probe so clients can check the server URL before doing real work.
+  @Get()
+  getHealth() {
+    return { status: 'ok' };
+  }
+}
@@ -0,0 +1,11 @@
+import { Controller, Get } from '@nestjs/common';
+
+@Controller('version')
+export class VersionController {
+  // Lets clients show which server build they talk to (e.g. on their About screen).
+  // npm sets npm_package_version for `npm run ...`; a bare `node dist/main` reports "unknown".
+  @Get()
+  getVersion() {
+    return { version: process.env.npm_package_version ?? 'unknown' };
+  }
+}
@@ -0,0 +1,38 @@
+// Time to build the board's lines for one frame, for the 3x3 board and a hypothetical 9x9 one,
+// in the plain and the boxed (accessibility) grid. Run with `cargo bench`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use tictactoe_tui::{
+    theme::{BoardSymbols, Theme},
+    ui::{render_board_text, BoardView},
+};
+
+// A board filled in an X, O, empty pattern, with the cursor on the last cell.
+fn board(side: usize) -> Vec<Option<String>> {
+    (0..side * side)
+        .map(|idx| ["X", "O", ""][idx % 3])
+        .map(|mark| (!mark.is_empty()).then(|| mark.to_string()))
+        .collect()
+}
+
+fn board_render(c: &mut Criterion) {
+    let view = BoardView {
+        cursor: Some(0),
+        coordinates: true,
+        ..BoardView::read_only(BoardSymbols::Emoji.glyphs(""))
+    };
+    for (grid, palette) in [
+        ("plain", Theme::Classic.palette(false)),
+        ("boxed", Theme::accessible_palette()),
+    ] {
+        for side in [3, 9] {
+            let board = board(side);
+            c.bench_function(&format!("render_board_text {side}x{side} {grid}"), |b| {
+                b.iter(|| render_board_text(black_box(&board), &view, &palette, Some("X")))
+            });
+        }
+    }
+}
+
+criterion_group!(benches, board_render);
+criterion_main!(benches);
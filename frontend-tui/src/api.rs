@@ -6,23 +6,215 @@
 //
 // Throughout, I'll add comments explaining Rust syntax and concepts in comparison to JS/TS.
 
-use anyhow::{Context, Result}; // 'anyhow' is a Rust crate providing error handling; 'Result' is Rust's equivalent to Promise<Result<T, E>>
-use reqwest::Client; // Reqwest is like 'fetch' or 'axios' in JS/TS for HTTP requests
+use std::cell::Cell; // Cell = a value you can overwrite through a shared (&self) reference
+use std::fmt; // 'fmt' provides the Display trait, Rust's version of a custom toString()
+use std::time::{Duration, Instant}; // Instant::now() + elapsed() is Rust's performance.now()
+
+use reqwest::{Client, RequestBuilder, Response, StatusCode}; // Reqwest is like 'fetch' or 'axios' in JS/TS for HTTP requests
 use serde::Deserialize; // Serde handles mapping (deserialization) of JSON responses to Rust structs
 
-use crate::models::{ // This brings in some types for request/response payloads that were defined elsewhere
-    ApiGame, CreatePvpRequest, CreateSoloRequest, JoinPvpRequest, PlayMoveRequest,
+use crate::events::GameEvents; // Background reader for the game's Server-Sent Events stream
+use crate::models::{
+    // This brings in some types for request/response payloads that were defined elsewhere
+    ApiGame,
+    CancelPvpRequest,
+    ClaimWinRequest,
+    CreatePvpRequest,
+    CreateSoloRequest,
+    HealthResponse,
+    JoinPvpRequest,
+    OpenGamesPage,
+    PlayMoveRequest,
+    RematchRequest,
+    ReportResultRequest,
+    ReportResultResponse,
+    ResignGameRequest,
+    VersionResponse,
+    WatchGameRequest,
+    BOARD_CELLS,
 };
 
+// ==============================
+// API Error Type
+// ==============================
+// Callers need to react differently to "couldn't reach the server" vs "server said no",
+// so errors are a tagged union instead of a plain message string.
+// In TS: type ApiError = { kind: 'network' } | { kind: 'rejected', status, message } | ...
+#[derive(Debug)]
+pub enum ApiError {
+    // The request never got a response: server down, wrong URL, timeout, DNS...
+    Network(reqwest::Error),
+    // 404: the game (or, on older servers, the endpoint) doesn't exist
+    NotFound(String),
+    // Any other 4xx: the server understood us and refused (illegal move, wrong password, ...)
+    Rejected { status: StatusCode, message: String },
+    // 5xx: the server failed while handling a valid request
+    Server { status: StatusCode, message: String },
+    // 2xx, but the body didn't match the shape we expected; `body` is the raw text for debugging
+    InvalidResponse { details: String, body: String },
+    // 2xx, but not JSON at all (usually an HTML page from a proxy or the wrong host/port)
+    NotJson { content_type: String, body: String },
+    // Caught before sending: the request itself was malformed (a client bug, not the server's fault)
+    InvalidRequest(String),
+}
+
+// Display is what `{err}` prints inside format!, like overriding toString() in JS
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiError::Network(err) => write!(f, "could not reach the server: {err}"),
+            ApiError::NotFound(message) => write!(f, "not found: {message}"),
+            ApiError::Rejected { status, message } | ApiError::Server { status, message } => {
+                write!(f, "request failed with {status}: {message}")
+            }
+            ApiError::InvalidResponse { details, body } => write!(
+                f,
+                "invalid JSON response shape: {details} (body: {})",
+                body_snippet(body)
+            ),
+            ApiError::NotJson { content_type, .. } => write!(
+                f,
+                "expected JSON, got {content_type} — is the server URL correct?"
+            ),
+            ApiError::InvalidRequest(details) => write!(f, "invalid request: {details}"),
+        }
+    }
+}
+
+// Methods on the enum, like helper functions next to a TS union type
+impl ApiError {
+    // Joining lost the race for the last seat: someone else joined between listing and joining.
+    // Current servers answer 409; older ones send a plain 400, recognised by its message.
+    pub fn is_game_filled(&self) -> bool {
+        match self {
+            ApiError::Rejected { status, message } => {
+                *status == StatusCode::CONFLICT
+                    || (*status == StatusCode::BAD_REQUEST
+                        && message.contains("not waiting for a second player"))
+            }
+            _ => false,
+        }
+    }
+}
+
+// Marks ApiError as a standard error so it also works with `?` into anyhow::Result
+impl std::error::Error for ApiError {}
+
+// 'From' lets the '?' operator convert reqwest errors automatically (send() failures are network errors)
+impl From<reqwest::Error> for ApiError {
+    fn from(err: reqwest::Error) -> Self {
+        ApiError::Network(err)
+    }
+}
+
+// Every backend route the client calls, in the order they appear below (shown on the About screen).
+// `const` arrays are fixed at compile time, like `as const` tuples in TS.
+pub const ENDPOINTS: [&str; 19] = [
+    "GET /health",
+    "GET /version",
+    "POST /games/solo",
+    "POST /games/pvp",
+    "GET /games/pvp/open",
+    "GET /games/pvp/live",
+    "GET /games/pvp/mine",
+    "POST /games/pvp/:gameId/join",
+    "POST /games/pvp/:gameId/cancel",
+    "GET /games/:gameId",
+    "GET /games/:gameId/events",
+    "POST /games/:gameId/move",
+    "POST /games/:gameId/resign",
+    "POST /games/:gameId/claim-win",
+    "POST /games/:gameId/watch",
+    "POST /games/:gameId/rematch",
+    "POST /games/:gameId/rematch/accept",
+    "POST /games/:gameId/rematch/cancel",
+    "POST /games/results",
+];
+
+// Shorthand used by every endpoint below, like `type ApiResult<T> = Promise<T>` with a typed error
+pub type ApiResult<T> = Result<T, ApiError>;
+
+// ==============================
+// GameApi Trait
+// ==============================
+// Everything the app needs from the backend, as a trait (Rust's version of a TS interface).
+// The app only talks to `impl GameApi`, so tests can swap the HTTP client for an in-memory fake,
+// like passing a mock service into a React component instead of the real one.
+// `async fn` in a trait works like an interface method returning a Promise.
+// Rust warns that callers can't require those futures to be `Send` (thread-safe); every impl lives
+// in this crate and runs on the app's own loop, so that never matters here.
+#[allow(async_fn_in_trait)]
+pub trait GameApi {
+    // Points later requests at another server (used when the server URL setting changes)
+    fn set_base_url(&mut self, base_url: &str);
+    // How long the last answered request took, for the latency indicator
+    fn last_latency(&self) -> Option<Duration>;
+    async fn health_check(&self) -> ApiResult<bool>;
+    async fn server_version(&self) -> ApiResult<String>;
+    async fn create_solo_game(
+        &self,
+        player_id: &str,
+        player_name: Option<&str>,
+        client_name: &str,
+        first_turn: Option<&str>,
+    ) -> ApiResult<ApiGame>;
+    async fn create_pvp_game(
+        &self,
+        player_id: &str,
+        player_name: Option<&str>,
+        name: &str,
+        password: Option<String>,
+        first_turn: Option<&str>,
+    ) -> ApiResult<ApiGame>;
+    async fn list_open_pvp_games(&self, offset: usize, limit: usize) -> ApiResult<OpenGamesPage>;
+    async fn list_live_pvp_games(&self) -> ApiResult<Vec<ApiGame>>;
+    async fn list_my_pvp_games(&self, player_id: &str) -> ApiResult<Vec<ApiGame>>;
+    async fn join_pvp_game(
+        &self,
+        player_id: &str,
+        player_name: Option<&str>,
+        game_id: &str,
+        password: Option<String>,
+    ) -> ApiResult<ApiGame>;
+    async fn cancel_pvp_game(&self, player_id: &str, game_id: &str) -> ApiResult<()>;
+    async fn get_game(&self, game_id: &str) -> ApiResult<ApiGame>;
+    async fn play_move(&self, player_id: &str, game_id: &str, index: usize) -> ApiResult<ApiGame>;
+    async fn resign_game(&self, player_id: &str, game_id: &str) -> ApiResult<()>;
+    async fn claim_win(&self, player_id: &str, game_id: &str) -> ApiResult<ApiGame>;
+    async fn watch_game(
+        &self,
+        player_id: &str,
+        player_name: Option<&str>,
+        game_id: &str,
+    ) -> ApiResult<ApiGame>;
+    async fn request_rematch(&self, player_id: &str, game_id: &str) -> ApiResult<ApiGame>;
+    async fn accept_rematch(&self, player_id: &str, game_id: &str) -> ApiResult<ApiGame>;
+    async fn cancel_rematch(&self, player_id: &str, game_id: &str) -> ApiResult<ApiGame>;
+    async fn report_result(&self, result: &ReportResultRequest) -> ApiResult<ReportResultResponse>;
+    // Opens a live stream of the game's state, like `new EventSource(url)` in the browser.
+    // `None` means there is no stream to offer, so the app keeps polling.
+    fn subscribe_game_events(&self, game_id: &str) -> Option<GameEvents>;
+}
+
+// Polls and moves are awaited between key presses, so a server that accepts the connection and
+// never answers would otherwise freeze every key (Ctrl+C too). Past this, the request fails as
+// a network error and the usual retry path takes over.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+// The event stream is meant to stay open, so it only gets a limit on connecting; `events.rs`
+// notices a silent stream on its own.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
 // ==============================
 // API Client Struct Declaration
 // ==============================
 // In Rust, structs are like classes but only contain data. Methods are added in an 'impl' (implementation) block.
 // Here, we're defining a struct that wraps an HTTP client and a base URL.
-// In TS: interface ApiClient { client: AxiosInstance; baseUrl: string }
+// In TS: interface ApiClient { client: AxiosInstance; baseUrl: string; lastLatency?: number }
 pub struct ApiClient {
     client: Client,
+    stream_client: Client, // same, minus the request timeout: used for the SSE stream only
     base_url: String,
+    last_latency: Cell<Option<Duration>>, // round trip of the most recent request that got a response
 }
 
 // ====================================
@@ -32,25 +224,95 @@ pub struct ApiClient {
 impl ApiClient {
     // Constructor: like 'new ApiClient(baseUrl)' in JS/TS
     pub fn new(base_url: &str) -> Self {
+        // `build()` only fails when TLS can't be set up, which `Client::new()` would panic on too
+        let client = Client::builder()
+            .connect_timeout(CONNECT_TIMEOUT)
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .expect("HTTP client");
+        let stream_client = Client::builder()
+            .connect_timeout(CONNECT_TIMEOUT)
+            .build()
+            .expect("HTTP client");
         Self {
-            client: Client::new(), // creates a new HTTP client
+            client,
+            stream_client,
             base_url: base_url.to_string(), // converts &str (string slice) to String
+            last_latency: Cell::new(None),  // nothing measured yet
         }
     }
 
+    // Every endpoint sends through here, so timing lives in one place (like an axios interceptor).
+    // Only requests that got a response are timed; network failures keep the previous value.
+    async fn send(&self, request: RequestBuilder) -> ApiResult<Response> {
+        let started = Instant::now();
+        let response = request.send().await?;
+        self.last_latency.set(Some(started.elapsed()));
+        Ok(response)
+    }
+}
+
+// ====================================
+// The real backend: GameApi over HTTP
+// ====================================
+// 'impl Trait for Type' is like 'class ApiClient implements GameApi' in TS.
+// Trait methods take their visibility from the trait, so they don't need 'pub'.
+impl GameApi for ApiClient {
+    // A new server means a new set of timings, so the old latency is dropped too
+    fn set_base_url(&mut self, base_url: &str) {
+        self.base_url = base_url.to_string();
+        self.last_latency.set(None);
+    }
+
+    // Getter for the latency indicator: how long the last answered request took
+    fn last_latency(&self) -> Option<Duration> {
+        self.last_latency.get()
+    }
+
+    // ===============================
+    // Endpoint: Health Check
+    // ===============================
+    // Cheap "is the server there?" probe, used after changing the server URL.
+    // Returns true only when the server answered with { "status": "ok" }.
+    async fn health_check(&self) -> ApiResult<bool> {
+        let url = format!("{}/health", self.base_url);
+        let response = self.send(self.client.get(url)).await?;
+        let health: HealthResponse = parse_json_response(response).await?;
+        Ok(health.status == "ok")
+    }
+
+    // ===============================
+    // Endpoint: Server Version
+    // ===============================
+    // Which server build we talk to; older servers don't have this route and answer 404.
+    async fn server_version(&self) -> ApiResult<String> {
+        let url = format!("{}/version", self.base_url);
+        let response = self.send(self.client.get(url)).await?;
+        let version: VersionResponse = parse_json_response(response).await?;
+        Ok(version.version)
+    }
+
     // ===============================
     // Endpoint: Create Solo Game
     // ===============================
-    // Async function (like async in JS/TS), returns Result<ApiGame, Error>
-    pub async fn create_solo_game(&self, player_id: &str) -> Result<ApiGame> {
+    // Async function (like async in JS/TS), returns Result<ApiGame, ApiError>
+    async fn create_solo_game(
+        &self,
+        player_id: &str,
+        player_name: Option<&str>, // optional display name shown to other players
+        client_name: &str,         // tells the server which client (or machine) created the game
+        first_turn: Option<&str>, // "X" or "O"; None leaves it to the server (X), which older servers require
+    ) -> ApiResult<ApiGame> {
         let url = format!("{}/games/solo", self.base_url); // build the endpoint URL
         let payload = CreateSoloRequest {
             player_id: player_id.to_string(), // convert to String
-            client_name: "rust-tui-client".to_string(), // hardcoded name for client
+            client_name: client_name.to_string(),
+            player_name: player_name.map(str::to_string), // Option::map = optional chaining + transform
+            first_turn: first_turn.map(str::to_string),
         };
 
         // Make a POST request, serialize payload to JSON, wait for response
-        let response = self.client.post(url).json(&payload).send().await?;
+        let response = self.send(self.client.post(url).json(&payload)).await?;
         // Custom function to parse response as JSON and handle errors
         parse_json_response(response).await
     }
@@ -58,73 +320,264 @@ impl ApiClient {
     // ===============================
     // Endpoint: Create PvP Game
     // ===============================
-    pub async fn create_pvp_game(
+    async fn create_pvp_game(
         &self,
         player_id: &str,
+        player_name: Option<&str>,
         name: &str,
         password: Option<String>, // Option<T> is like T | undefined/null in TS
-    ) -> Result<ApiGame> {
+        first_turn: Option<&str>,
+    ) -> ApiResult<ApiGame> {
         let url = format!("{}/games/pvp", self.base_url);
         let payload = CreatePvpRequest {
             player_id: player_id.to_string(),
             name: name.to_string(),
             password,
+            player_name: player_name.map(str::to_string),
+            first_turn: first_turn.map(str::to_string),
         };
 
-        let response = self.client.post(url).json(&payload).send().await?;
+        let response = self.send(self.client.post(url).json(&payload)).await?;
         parse_json_response(response).await
     }
 
     // ===============================
     // Endpoint: List Open PvP Games
     // ===============================
-    pub async fn list_open_pvp_games(&self) -> Result<Vec<ApiGame>> {
-        let url = format!("{}/games/pvp/open", self.base_url);
-        let response = self.client.get(url).send().await?;
+    // One page of the open games, newest first. The total comes in an `X-Total-Count` header
+    // (like reading res.headers.get(...) next to res.json()); older servers ignore the paging
+    // query, send every game and no total.
+    async fn list_open_pvp_games(&self, offset: usize, limit: usize) -> ApiResult<OpenGamesPage> {
+        let url = format!(
+            "{}/games/pvp/open?offset={offset}&limit={limit}",
+            self.base_url
+        );
+        let response = self.send(self.client.get(url)).await?;
+        let total = response
+            .headers()
+            .get("x-total-count")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse().ok()); // Option chain = value?.toString()?.parse()
+        let games = parse_json_response(response).await?;
+        Ok(OpenGamesPage { games, total })
+    }
+
+    // ===============================
+    // Endpoint: List Live PvP Games
+    // ===============================
+    // In-progress games other people are playing, for spectating
+    async fn list_live_pvp_games(&self) -> ApiResult<Vec<ApiGame>> {
+        let url = format!("{}/games/pvp/live", self.base_url);
+        let response = self.send(self.client.get(url)).await?;
+        parse_json_response(response).await
+    }
+
+    // ===============================
+    // Endpoint: List My PvP Games
+    // ===============================
+    // Games I host or play in that aren't over yet; older servers answer 404
+    async fn list_my_pvp_games(&self, player_id: &str) -> ApiResult<Vec<ApiGame>> {
+        let url = format!("{}/games/pvp/mine?playerId={player_id}", self.base_url);
+        let response = self.send(self.client.get(url)).await?;
         parse_json_response(response).await
     }
 
     // ===============================
     // Endpoint: Join PvP Game
     // ===============================
-    pub async fn join_pvp_game(
+    async fn join_pvp_game(
         &self,
         player_id: &str,
+        player_name: Option<&str>,
         game_id: &str,
         password: Option<String>,
-    ) -> Result<ApiGame> {
+    ) -> ApiResult<ApiGame> {
         let url = format!("{}/games/pvp/{game_id}/join", self.base_url); // Format strings in Rust use curly braces, like template literals
         let payload = JoinPvpRequest {
             player_id: player_id.to_string(),
             password,
+            player_name: player_name.map(str::to_string),
         };
 
-        let response = self.client.post(url).json(&payload).send().await?;
+        let response = self.send(self.client.post(url).json(&payload)).await?;
         parse_json_response(response).await
     }
 
+    // ===============================
+    // Endpoint: Cancel PvP Game
+    // ===============================
+    // Host-only: removes a game nobody has joined yet.
+    // The game is gone afterwards, so only success matters, whether or not a body comes back.
+    async fn cancel_pvp_game(&self, player_id: &str, game_id: &str) -> ApiResult<()> {
+        let url = format!("{}/games/pvp/{game_id}/cancel", self.base_url);
+        let payload = CancelPvpRequest {
+            player_id: player_id.to_string(),
+        };
+
+        let response = self.send(self.client.post(url).json(&payload)).await?;
+        parse_empty_response(response).await
+    }
+
     // ===============================
     // Endpoint: Get Single Game
     // ===============================
-    pub async fn get_game(&self, game_id: &str) -> Result<ApiGame> {
+    async fn get_game(&self, game_id: &str) -> ApiResult<ApiGame> {
         let url = format!("{}/games/{game_id}", self.base_url);
-        let response = self.client.get(url).send().await?;
+        let response = self.send(self.client.get(url)).await?;
         parse_json_response(response).await
     }
 
     // ===============================
     // Endpoint: Play Move
     // ===============================
-    pub async fn play_move(&self, player_id: &str, game_id: &str, index: usize) -> Result<ApiGame> {
+    async fn play_move(&self, player_id: &str, game_id: &str, index: usize) -> ApiResult<ApiGame> {
+        // Guard against cursor bugs: an out-of-range cell would otherwise come back as a confusing 400.
+        // debug_assert! only runs in debug builds (like console.assert in dev), release builds get the error.
+        debug_assert!(
+            index < BOARD_CELLS,
+            "move index {index} is outside the board"
+        );
+        if index >= BOARD_CELLS {
+            return Err(ApiError::InvalidRequest(format!(
+                "cell index {index} is outside the board (0..{BOARD_CELLS})"
+            )));
+        }
+
         let url = format!("{}/games/{game_id}/move", self.base_url);
         let payload = PlayMoveRequest {
             player_id: player_id.to_string(),
             index,
         };
 
-        let response = self.client.post(url).json(&payload).send().await?;
+        let response = self.send(self.client.post(url).json(&payload)).await?;
+        parse_json_response(response).await
+    }
+
+    // ===============================
+    // Endpoint: Resign Game
+    // ===============================
+    // Gives up an in-progress game; the server marks the opponent as the winner.
+    // We leave the game right after resigning, so the final state it may send back isn't needed.
+    async fn resign_game(&self, player_id: &str, game_id: &str) -> ApiResult<()> {
+        let url = format!("{}/games/{game_id}/resign", self.base_url);
+        let payload = ResignGameRequest {
+            player_id: player_id.to_string(),
+        };
+
+        let response = self.send(self.client.post(url).json(&payload)).await?;
+        parse_empty_response(response).await
+    }
+
+    // ===============================
+    // Endpoint: Claim Win
+    // ===============================
+    // Asks the server to end the game in our favor because the opponent stopped playing.
+    // The server decides whether they have been idle long enough (400 if not).
+    async fn claim_win(&self, player_id: &str, game_id: &str) -> ApiResult<ApiGame> {
+        let url = format!("{}/games/{game_id}/claim-win", self.base_url);
+        let payload = ClaimWinRequest {
+            player_id: player_id.to_string(),
+        };
+
+        let response = self.send(self.client.post(url).json(&payload)).await?;
+        parse_json_response(response).await
+    }
+
+    // ===============================
+    // Endpoint: Watch Game
+    // ===============================
+    // Same answer as get_game, but also tells the server we are spectating,
+    // which keeps us in the game's `spectators` list for a few seconds (so call it on every poll).
+    async fn watch_game(
+        &self,
+        player_id: &str,
+        player_name: Option<&str>,
+        game_id: &str,
+    ) -> ApiResult<ApiGame> {
+        let url = format!("{}/games/{game_id}/watch", self.base_url);
+        let payload = WatchGameRequest {
+            player_id: player_id.to_string(),
+            player_name: player_name.map(str::to_string),
+        };
+
+        let response = self.send(self.client.post(url).json(&payload)).await?;
         parse_json_response(response).await
     }
+
+    // ===============================
+    // Endpoint: Request Rematch
+    // ===============================
+    // Asks the opponent of a finished PvP game for another round. Returns the finished game,
+    // now with `rematchRequestedBy` set (or `rematchGameId`, if the opponent had asked too).
+    async fn request_rematch(&self, player_id: &str, game_id: &str) -> ApiResult<ApiGame> {
+        let url = format!("{}/games/{game_id}/rematch", self.base_url);
+        let payload = RematchRequest {
+            player_id: player_id.to_string(),
+        };
+
+        let response = self.send(self.client.post(url).json(&payload)).await?;
+        parse_json_response(response).await
+    }
+
+    // ===============================
+    // Endpoint: Accept Rematch
+    // ===============================
+    // Says yes to the opponent's request. Unlike the other two rematch calls,
+    // this one answers with the NEW game (seats swapped), ready to play.
+    async fn accept_rematch(&self, player_id: &str, game_id: &str) -> ApiResult<ApiGame> {
+        let url = format!("{}/games/{game_id}/rematch/accept", self.base_url);
+        let payload = RematchRequest {
+            player_id: player_id.to_string(),
+        };
+
+        let response = self.send(self.client.post(url).json(&payload)).await?;
+        parse_json_response(response).await
+    }
+
+    // ===============================
+    // Endpoint: Cancel Rematch
+    // ===============================
+    // Withdraws our own request or declines the opponent's; either way the request is cleared.
+    async fn cancel_rematch(&self, player_id: &str, game_id: &str) -> ApiResult<ApiGame> {
+        let url = format!("{}/games/{game_id}/rematch/cancel", self.base_url);
+        let payload = RematchRequest {
+            player_id: player_id.to_string(),
+        };
+
+        let response = self.send(self.client.post(url).json(&payload)).await?;
+        parse_json_response(response).await
+    }
+
+    // ===============================
+    // Endpoint: Report Result
+    // ===============================
+    // Uploads the outcome of a solo game played locally, so it still counts in the server's stats.
+    // Safe to retry: like an idempotency key in fetch(), the same `resultId` is only counted once.
+    async fn report_result(&self, result: &ReportResultRequest) -> ApiResult<ReportResultResponse> {
+        let url = format!("{}/games/results", self.base_url);
+
+        let response = self.send(self.client.post(url).json(result)).await?;
+        // 204 = accepted without saying whether it was new; counting it as new at worst
+        // overstates the "uploaded" toast, the server still keeps it once
+        if response.status() == StatusCode::NO_CONTENT {
+            parse_empty_response(response).await?;
+            return Ok(ReportResultResponse { recorded: true });
+        }
+        parse_json_response(response).await
+    }
+
+    // ===============================
+    // Endpoint: Game Events (SSE)
+    // ===============================
+    // Not async: the stream is read by a background task (like an EventSource's onmessage),
+    // so this only starts it. reqwest's Client is a cheap handle, so the task gets its own copy.
+    fn subscribe_game_events(&self, game_id: &str) -> Option<GameEvents> {
+        Some(GameEvents::subscribe(
+            self.stream_client.clone(),
+            &self.base_url,
+            game_id,
+        ))
+    }
 }
 
 // ===============================
@@ -132,23 +585,84 @@ impl ApiClient {
 // ===============================
 // This takes the HTTP response, checks if the status is success, and parses JSON to the expected type.
 // In TS, you'd do: if (!response.ok) throw Error()
-// 'anyhow' is used for error reporting; .context() annotates errors for easier debugging
+// Failures are sorted into ApiError variants so callers can pick the right message
 async fn parse_json_response<T: for<'de> Deserialize<'de>>(
     response: reqwest::Response,
-) -> Result<T> {
-    let status = response.status();
-    if !status.is_success() {
-        let body = response
-            .text()
-            .await
-            .unwrap_or_else(|_| "<no body>".to_string());
-        anyhow::bail!("request failed with {status}: {body}"); // bail = throw error
+) -> ApiResult<T> {
+    let response = check_status(response).await?;
+
+    // Like checking res.headers.get('content-type') before res.json(): a proxy's HTML error page
+    // often comes back as 200, and the decode error for it says nothing useful
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string); // Option<String>: a missing header is given the benefit of the doubt
+
+    // Read the body as text first (instead of response.json()) so a decode failure can show what arrived
+    let body = response.text().await?;
+    if let Some(content_type) = content_type.filter(|value| !value.contains("json")) {
+        return Err(ApiError::NotJson { content_type, body });
     }
+    serde_json::from_str::<T>(&body).map_err(|err| ApiError::InvalidResponse {
+        details: err.to_string(),
+        body,
+    }) // map_err = catch + rethrow as another type
+}
+
+// For endpoints whose answer carries nothing we need: any success is enough, including a
+// 204 No Content that parse_json_response would reject as an empty (invalid) JSON body.
+// In TS: if (!response.ok) throw Error(); return;
+async fn parse_empty_response(response: reqwest::Response) -> ApiResult<()> {
+    check_status(response).await.map(|_| ())
+}
 
-    response
-        .json::<T>()
+// Turns a non-2xx response into the matching ApiError and hands successful ones back for reading.
+async fn check_status(response: reqwest::Response) -> ApiResult<reqwest::Response> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    let body = response
+        .text()
         .await
-        .context("invalid JSON response shape") // attaches error context/history
+        .unwrap_or_else(|_| "<no body>".to_string());
+    let message = server_message(&body);
+    // 'return Err(...)' = throw error
+    Err(if status == StatusCode::NOT_FOUND {
+        ApiError::NotFound(message)
+    } else if status.is_client_error() {
+        ApiError::Rejected { status, message }
+    } else {
+        ApiError::Server { status, message }
+    })
+}
+
+// Error messages only show the start of a bad body; the full text stays in the error for a debug view.
+const BODY_SNIPPET_CHARS: usize = 120;
+
+fn body_snippet(body: &str) -> String {
+    if body.chars().count() <= BODY_SNIPPET_CHARS {
+        return body.to_string();
+    }
+    let snippet: String = body.chars().take(BODY_SNIPPET_CHARS).collect();
+    format!("{snippet}...")
+}
+
+// NestJS error bodies look like { "statusCode": 400, "message": "...", "error": "Bad Request" },
+// where "message" is a string or, for validation errors, an array of strings.
+// Falls back to the raw body when it isn't that shape.
+fn server_message(body: &str) -> String {
+    let parsed: Option<serde_json::Value> = serde_json::from_str(body).ok();
+    match parsed.as_ref().and_then(|json| json.get("message")) {
+        Some(serde_json::Value::String(message)) => message.clone(),
+        Some(serde_json::Value::Array(messages)) => messages
+            .iter()
+            .filter_map(|message| message.as_str())
+            .collect::<Vec<_>>()
+            .join(", "),
+        _ => body.to_string(),
+    }
 }
 
 // ===============================
@@ -157,11 +671,11 @@ async fn parse_json_response<T: for<'de> Deserialize<'de>>(
 // This Rust module is a direct analog to a TS service file using axios/fetch.
 // - Struct = Typed object/class
 // - impl = implementation of methods, added to struct
-// - Result<T> = Promise<Result<T, E>>
+// - ApiResult<T> = Promise<T> whose rejection is a typed ApiError
 // - async/await works as expected, but with Rust's error handling
 // - Option<T> = T | undefined/null
 // - .to_string() = String(obj)
 // - Format macros and string interpolation = TS template literals
-// - Custom error reporting for debugging
+// - ApiError separates network failures from server rejections
 //
 // If you want to understand Rust, map things to TS as above. You can call these methods from elsewhere in the app, just like you'd call service functions in React/Node.
@@ -1,489 +1,5236 @@
-use std::time::{Duration, Instant};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use anyhow::Result;
-use crossterm::event::{self, Event, KeyCode, KeyEvent};
-use ratatui::{DefaultTerminal, Frame};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use ratatui::{layout::Rect, DefaultTerminal, Frame};
 use uuid::Uuid;
 
 use crate::{
-    api::ApiClient,
-    models::{ApiGame, Screen},
-    ui,
+    api::{self, ApiClient, ApiError, GameApi},
+    clipboard,
+    config::{self, Config},
+    engine,
+    events::{GameEvents, StreamUpdate},
+    export,
+    help::{self, HelpState},
+    input::TextInput,
+    metrics::Metrics,
+    models::{
+        self, ApiGame, GameOutcome, LobbySort, LobbyTab, MoveRecord, ReportResultRequest, Screen,
+        BOARD_CELLS, GAME_NAME_MAX_CHARS, MODE_LOCAL_SOLO, MODE_PRACTICE,
+    },
+    nav, results,
+    secret::Secret,
+    settings::{self, Settings},
+    sound::{self, SoundEvent},
+    theme::{BoardSymbols, Palette, Theme},
+    ui::{
+        self, BoardView, GameOverView, GamePanel, LobbyView, MyGameRow, OtherGame, SettingRow,
+        SettingsView,
+    },
 };
 
 // Main application state.
 // If you know React: this is like one root component state + event handlers.
-pub struct App {
-    api: ApiClient,
+// Generic over the backend so tests can drive it with a fake `GameApi`.
+pub struct App<A = ApiClient> {
+    api: A,
+    config: Config,
+    settings: Settings,
+    // Where settings, the result queue and exports are saved (`settings::app_config_dir()`
+    // outside of tests).
+    config_dir: PathBuf,
+    base_url: String,
+    server_online: Option<bool>,
     player_id: String,
+    text_input: TextInput,
     screen: Screen,
     home_index: usize,
+    settings_index: usize,
+    editing_setting: bool,
+    // Recent server picked with Up/Down while editing the server URL.
+    recent_server_index: Option<usize>,
     board_cursor: usize,
+    confirm_moves: bool,
+    pending_cell: Option<usize>,
+    // Column picked by a coordinate letter, waiting for its row digit.
+    pending_column: Option<usize>,
+    opponent_move: Option<(usize, Instant)>,
+    move_history: Vec<MoveRecord>,
+    focused_panel: GamePanel,
+    history_scroll: Option<usize>,
+    show_full_id: bool,
+    // Beginner hint under the board: what Enter would do on the hovered cell.
+    show_enter_hint: bool,
+    // Privacy mode (Ctrl+P): game ids and typed passwords are hidden, for screenshots.
+    privacy: bool,
+    // `R` asked for an immediate resync; the loop runs it after drawing the "Resyncing" popup.
+    resync_pending: bool,
+    // Analysis mode: the cursor moves and previews cells, but Enter plays nothing.
+    analysis: bool,
+    // Lines of the Game Over recap scrolled past.
+    recap_scroll: u16,
+    replay_step: usize,
     solo_game: Option<ApiGame>,
     pvp_game: Option<ApiGame>,
+    // My other running PvP games while `pvp_game` is on screen; `n` cycles through them.
+    parked_games: Vec<ParkedGame>,
+    own_games: Vec<ApiGame>,
     pvp_games: Vec<ApiGame>,
+    // `pvp_games` in the order the server sent them, re-sorted whenever `lobby_sort` changes.
+    joinable_as_sent: Vec<ApiGame>,
+    lobby_sort: LobbySort,
+    // "Open games" or "My games"; each tab keeps its own selection.
+    lobby_tab: LobbyTab,
+    my_games: Vec<ApiGame>,
+    my_games_index: usize,
+    live_games: Vec<ApiGame>,
+    show_board_previews: bool,
+    // When the lobby lists were last fetched successfully, and whether a manual refresh failed since.
+    lobby_fetched_at: Option<Instant>,
+    lobby_refresh_failed: bool,
+    // Open games fetched so far (pages of `Config::lobby_page`), the server's total if it sent
+    // one, and whether a "Load more" row is offered after the lists.
+    lobby_open_loaded: usize,
+    lobby_open_total: Option<usize>,
+    lobby_has_more: bool,
     pvp_selected_index: usize,
-    create_name: String,
-    create_password: String,
+    create_name: TextInput,
+    create_password: Secret<TextInput>,
     create_field_index: usize,
-    join_password: String,
+    join_password: Secret<TextInput>,
     editing_join_password: bool,
     game_over_message: String,
+    // My result in `finished_game`; `None` when I watched it or played both sides (practice).
+    game_outcome: Option<GameOutcome>,
+    finished_game: Option<ApiGame>,
+    rematch: Option<Rematch>,
+    // Whether we had the first move in the last finished game; a local rematch hands it over.
+    moved_first: bool,
+    game_over_flash_at: Option<Instant>,
+    // When the Game Over screen goes back Home by itself (`--autoreturn`); any key clears it.
+    autoreturn_at: Option<Instant>,
     info_message: String,
+    info_scroll: u16,
+    // Running PvP game the error on the Info screen interrupted, which `x` there resigns.
+    stranded_game: Option<String>,
+    // Request that just failed on a network or server error, which `r` on the Info screen retries.
+    failed_action: Option<PendingAction>,
+    // Game the Info screen says vanished from the server; `l` there opens the lobby. Its id in
+    // `info_message` is hidden at draw time while privacy mode is on.
+    vanished_game: Option<String>,
+    invite_text: Option<String>,
+    last_raw_response: Option<String>,
+    showing_raw_response: bool,
+    confirm: Option<Confirm>,
+    turn_started_at: Instant,
+    claim_offered: bool,
+    command_input: Option<String>,
+    // Restarted by every key press, so the text caret stays solid while typing and blinks when idle.
+    last_key_at: Instant,
+    stats: SessionStats,
+    // Results of local solo games still waiting for the server (mirrored on disk by `results`).
+    pending_results: Vec<ReportResultRequest>,
+    // The server has no results endpoint (404): the queue stays on disk, untouched this session.
+    results_unsupported: bool,
+    // Seed of the local solo game's computer moves, and the generator it started.
+    ai_seed: u64,
+    ai_rng: StdRng,
+    // Opt-in counters from `--metrics`; records nothing without it.
+    metrics: Metrics,
+    // Results per PvP opponent (keyed by their player id) for the current session.
+    head_to_head: HashMap<String, SessionStats>,
+    toast: Option<(String, Instant)>,
+    // Parked game behind the last "your turn" (or "is over") notice; Ctrl+N goes there.
+    turn_notice: Option<String>,
     should_quit: bool,
+    // Something on screen changed since the last frame; the loop only redraws when this is set
+    // or an animation is running.
+    dirty: bool,
     last_poll_at: Instant,
+    poll_interval: Duration,
+    // Server-Sent Events stream of the PvP game on screen. While it is open the game isn't polled;
+    // `None` (no stream offered, or it closed) means polling as usual.
+    game_events: Option<GameEvents>,
+    // When to reopen a stream that dropped after working; meanwhile the game is polled.
+    resubscribe_at: Option<Instant>,
+    // Wall-clock time of the last loop tick. `Instant` stops while the machine is suspended (on
+    // Linux), so only the wall clock shows how long we were gone.
+    last_tick_at: SystemTime,
 }
 
+// A PvP game I play that is off screen. It keeps its move history and is polled on its own,
+// slower cadence so its turns can still be announced.
+struct ParkedGame {
+    game: ApiGame,
+    move_history: Vec<MoveRecord>,
+    polled_at: Instant,
+}
+
+// Yes/no questions shown as a popup; the next key press answers them (`y` = yes).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Confirm {
+    LeaveGame,
+    ClaimWin,
+    ResumeGame,
+    QuitApp,
+}
+
+// A request worth repeating as-is after a transient failure (server down, 5xx).
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PendingAction {
+    StartSolo {
+        first_turn: Option<String>,
+    },
+    OpenLobby,
+    Join {
+        game_id: String,
+        password: Option<Secret<String>>,
+    },
+    Create {
+        name: String,
+        password: Option<Secret<String>>,
+        first_turn: Option<String>,
+    },
+    Move {
+        game_id: String,
+        index: usize,
+    },
+}
+
+// Where the rematch handshake of the finished PvP game stands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Rematch {
+    // We asked and are waiting for the opponent.
+    Requested,
+    // The opponent asked; `r` accepts.
+    Offered,
+}
+
+// Finished-game counters for the current app session.
+#[derive(Debug, Default)]
+struct SessionStats {
+    won: u32,
+    lost: u32,
+    drawn: u32,
+}
+
+impl SessionStats {
+    fn count(&mut self, outcome: GameOutcome) {
+        match outcome {
+            GameOutcome::Won => self.won += 1,
+            GameOutcome::Lost => self.lost += 1,
+            GameOutcome::Draw => self.drawn += 1,
+        }
+    }
+
+    fn total(&self) -> u32 {
+        self.won + self.lost + self.drawn
+    }
+
+    // "You lead 2-1.", "You trail 0-1." or "All square at 1-1.", plus draws if there were any.
+    fn head_to_head_line(&self) -> String {
+        let score = format!("{}-{}", self.won, self.lost);
+        let mut line = match self.won.cmp(&self.lost) {
+            Ordering::Greater => format!("You lead {score}"),
+            Ordering::Less => format!("You trail {score}"),
+            Ordering::Equal => format!("All square at {score}"),
+        };
+        match self.drawn {
+            0 => {}
+            1 => line.push_str(" (1 draw)"),
+            drawn => line.push_str(&format!(" ({drawn} draws)")),
+        }
+        line.push('.');
+        line
+    }
+}
+
+// Base delay between backend polls, randomly shifted by up to POLL_JITTER_MS either way
+// so clients started together don't all hit the server on the same tick.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+const POLL_JITTER_MS: u64 = 200;
+// Default for `--idle-grace`: how long the opponent may sit on their turn before we offer
+// to claim the win (the server only accepts claims after 60s of inactivity).
+const DEFAULT_IDLE_GRACE: Duration = Duration::from_secs(60);
+// Default for `--health-timeout`: how long the health check may hang (e.g. a host that drops
+// packets) before the server counts as offline.
+const DEFAULT_HEALTH_TIMEOUT: Duration = Duration::from_secs(5);
+// From this much opponent inactivity on, the game header shows a warning.
+const IDLE_WARNING_AFTER: Duration = Duration::from_secs(15);
+// How long the loop waits for a key before redrawing: short where the screen changes on its own
+// (polled lobby/game, a toast counting down), longer elsewhere to save wakeups on battery.
+const ACTIVE_INPUT_POLL: Duration = Duration::from_millis(120);
+const IDLE_INPUT_POLL: Duration = Duration::from_millis(500);
+// With `--bell`, a win or loss flashes the game-over screen inverted for this long.
+const GAME_OVER_FLASH: Duration = Duration::from_millis(300);
+// Half period of the blinking text caret.
+const CARET_BLINK: Duration = Duration::from_millis(500);
+// How long a toast notification stays visible.
+const TOAST_DURATION: Duration = Duration::from_secs(3);
+// How long the opponent's latest move stays highlighted on the board.
+const OPPONENT_MOVE_HIGHLIGHT: Duration = Duration::from_secs(3);
+// How often each parked (off-screen) PvP game is polled.
+const PARKED_POLL_INTERVAL: Duration = Duration::from_secs(3);
+// Upper bound on cancelling my unjoined games when quitting, so a slow server can't hold
+// the terminal hostage.
+const QUIT_CLEANUP_TIMEOUT: Duration = Duration::from_secs(2);
+// The loop comes round at least every `IDLE_INPUT_POLL`, so a longer wall-clock gap between two
+// ticks means the machine slept (or the process was stopped).
+const SUSPEND_GAP: Duration = Duration::from_secs(10);
+// Pause before reopening a game's event stream that dropped.
+const STREAM_RETRY: Duration = Duration::from_secs(5);
+// Local solo results kept for upload at most; past that the oldest are dropped, so a client that
+// never reaches a server doesn't grow its queue file forever.
+const MAX_PENDING_RESULTS: usize = 500;
+
 impl App {
-    pub fn new(base_url: &str) -> Self {
+    // `settings` is `None` on first launch, which opens the name setup screen.
+    pub fn new(config: Config, settings: Option<Settings>, config_dir: PathBuf) -> Self {
+        Self::with_api(config, settings, config_dir, ApiClient::new)
+    }
+}
+
+impl<A: GameApi> App<A> {
+    // Like `new`, but `connect` builds the backend client for the resolved server URL.
+    fn with_api(
+        config: Config,
+        settings: Option<Settings>,
+        config_dir: PathBuf,
+        connect: impl FnOnce(&str) -> A,
+    ) -> Self {
+        let confirm_moves = config.confirm_moves;
+        let metrics = Metrics::new(config.metrics.clone());
+        let first_run = settings.is_none();
+        let mut settings = settings.unwrap_or_default();
+        if settings.player_id.is_empty() {
+            settings.player_id = Uuid::new_v4().to_string();
+        }
+        // `--server` beats the saved server, which beats the built-in default.
+        let base_url = config
+            .base_url
+            .clone()
+            .or_else(|| settings.base_url.clone())
+            .unwrap_or_else(|| config::DEFAULT_BASE_URL.to_string());
+        // An unreadable queue file starts over rather than blocking the app.
+        let pending_results = results::load(&config_dir).unwrap_or_default();
+
         Self {
-            api: ApiClient::new(base_url),
-            player_id: Uuid::new_v4().to_string(),
-            screen: Screen::Home,
+            api: connect(&base_url),
+            config,
+            player_id: settings.player_id.clone(),
+            settings,
+            config_dir,
+            base_url,
+            server_online: None,
+            text_input: TextInput::default(),
+            screen: if first_run {
+                Screen::Setup
+            } else {
+                Screen::Home
+            },
             home_index: 0,
+            settings_index: 0,
+            editing_setting: false,
+            recent_server_index: None,
             board_cursor: 0,
+            confirm_moves,
+            pending_cell: None,
+            pending_column: None,
+            opponent_move: None,
+            move_history: Vec::new(),
+            focused_panel: GamePanel::Board,
+            history_scroll: None,
+            show_full_id: false,
+            show_enter_hint: false,
+            privacy: false,
+            resync_pending: false,
+            analysis: false,
+            recap_scroll: 0,
+            replay_step: 0,
             solo_game: None,
             pvp_game: None,
+            parked_games: Vec::new(),
+            own_games: Vec::new(),
             pvp_games: Vec::new(),
+            joinable_as_sent: Vec::new(),
+            lobby_sort: LobbySort::default(),
+            lobby_tab: LobbyTab::default(),
+            my_games: Vec::new(),
+            my_games_index: 0,
+            live_games: Vec::new(),
+            show_board_previews: false,
+            lobby_fetched_at: None,
+            lobby_refresh_failed: false,
+            lobby_open_loaded: 0,
+            lobby_open_total: None,
+            lobby_has_more: false,
             pvp_selected_index: 0,
-            create_name: String::new(),
-            create_password: String::new(),
+            create_name: TextInput::default(),
+            create_password: Secret::default(),
             create_field_index: 0,
-            join_password: String::new(),
+            join_password: Secret::default(),
             editing_join_password: false,
             game_over_message: String::new(),
+            game_outcome: None,
+            finished_game: None,
+            rematch: None,
+            moved_first: false,
+            game_over_flash_at: None,
+            autoreturn_at: None,
             info_message: String::new(),
+            info_scroll: 0,
+            stranded_game: None,
+            vanished_game: None,
+            failed_action: None,
+            invite_text: None,
+            last_raw_response: None,
+            showing_raw_response: false,
+            confirm: None,
+            turn_started_at: Instant::now(),
+            claim_offered: false,
+            command_input: None,
+            last_key_at: Instant::now(),
+            stats: SessionStats::default(),
+            pending_results,
+            results_unsupported: false,
+            ai_seed: 0,
+            ai_rng: StdRng::seed_from_u64(0),
+            metrics,
+            head_to_head: HashMap::new(),
+            toast: None,
+            turn_notice: None,
             should_quit: false,
+            dirty: true,
             last_poll_at: Instant::now(),
+            poll_interval: jittered_poll_interval(),
+            game_events: None,
+            resubscribe_at: None,
+            last_tick_at: SystemTime::now(),
         }
     }
 
     pub async fn run(&mut self, terminal: &mut DefaultTerminal) -> Result<()> {
+        // Something on screen right away while the first health check runs; Home then shows
+        // whether it got through.
+        terminal.draw(|frame| {
+            ui::draw_connecting(
+                frame,
+                &self.palette(),
+                &self.base_url,
+                self.health_timeout(),
+            )
+        })?;
+        self.check_server().await;
+        if self.server_online == Some(true) {
+            // Saved along with the next settings change; writing now would skip first-run setup.
+            self.settings.remember_server(&self.base_url);
+            self.upload_pending_results().await;
+        }
+        self.join_from_config().await;
+        self.offer_resume().await;
+
         while !self.should_quit {
             // Polling in main loop keeps architecture simple.
             // Production apps often move this to background tasks + channels.
+            self.resync_after_suspend().await;
+            self.apply_streamed_updates();
             self.refresh_remote_state_if_needed().await;
-            terminal.draw(|frame| self.draw(frame))?;
+            self.autoreturn_if_due().await;
+            if self.resync_pending {
+                terminal.draw(|frame| {
+                    self.draw(frame);
+                    ui::draw_popup(
+                        frame,
+                        &self.palette(),
+                        "Resyncing",
+                        "Fetching the latest game state...",
+                    );
+                })?;
+                self.resync_game().await;
+            }
+            let animating = self.animating();
+            if self.dirty || animating {
+                terminal.draw(|frame| self.draw(frame))?;
+                // One more frame after an animation stops, so its last state doesn't linger.
+                self.dirty = animating;
+            }
 
-            if event::poll(Duration::from_millis(120))? {
-                if let Event::Key(key_event) = event::read()? {
-                    self.handle_key(key_event).await;
+            if event::poll(self.input_poll_timeout())? {
+                match event::read()? {
+                    // Ctrl+L wipes the terminal so the next frame is drawn from scratch,
+                    // recovering from output that garbled the screen. Works on every screen.
+                    Event::Key(key_event)
+                        if key_event.code == KeyCode::Char('l')
+                            && key_event.modifiers.contains(KeyModifiers::CONTROL) =>
+                    {
+                        terminal.clear()?;
+                        self.dirty = true;
+                    }
+                    Event::Key(key_event) => self.handle_key(key_event).await,
+                    Event::Resize(..) => self.dirty = true,
+                    _ => {}
                 }
             }
         }
 
+        let open_games = self.hosted_waiting_game_ids();
+        if !open_games.is_empty() {
+            terminal.draw(|frame| {
+                self.draw(frame);
+                ui::draw_popup(
+                    frame,
+                    &self.palette(),
+                    "Quitting",
+                    "Cleaning up your open game...",
+                );
+            })?;
+            self.cancel_games_on_quit(&open_games).await;
+        }
+
         Ok(())
     }
 
-    async fn refresh_remote_state_if_needed(&mut self) {
-        if self.last_poll_at.elapsed() < Duration::from_secs(1) {
-            return;
+    // Games I host that nobody joined yet: on screen, parked, or listed in the lobby.
+    fn hosted_waiting_game_ids(&self) -> Vec<String> {
+        let mut ids: Vec<String> = Vec::new();
+        let games = self
+            .pvp_game
+            .iter()
+            .chain(self.parked_games.iter().map(|parked| &parked.game))
+            .chain(self.own_games.iter());
+        for game in games {
+            if game.is_waiting_for_player()
+                && game.host_player_id == self.player_id
+                && !ids.contains(&game.id)
+            {
+                ids.push(game.id.clone());
+            }
         }
+        ids
+    }
 
-        match self.screen {
-            Screen::PvpLobby => {
-                if let Ok(games) = self.api.list_open_pvp_games().await {
-                    self.pvp_games = games;
-                    if self.pvp_selected_index >= self.pvp_games.len() {
-                        self.pvp_selected_index = self.pvp_games.len().saturating_sub(1);
-                    }
-                }
-            }
-            Screen::PvpGame => {
-                // No websocket yet, so we poll server state.
-                if let Some(game_id) = self.pvp_game.as_ref().map(|g| g.id.clone()) {
-                    if let Ok(game) = self.api.get_game(&game_id).await {
-                        if Self::is_game_finished(&game) {
-                            self.open_game_over(&game, "PvP");
-                        }
-                        self.pvp_game = Some(game);
-                    }
-                }
+    // Best effort: whatever isn't cancelled within the timeout is left to the server.
+    async fn cancel_games_on_quit(&self, game_ids: &[String]) {
+        let cancel_all = async {
+            for game_id in game_ids {
+                let _ = self.api.cancel_pvp_game(&self.player_id, game_id).await;
             }
-            _ => {}
-        }
+        };
+        let _ = tokio::time::timeout(QUIT_CLEANUP_TIMEOUT, cancel_all).await;
+    }
 
-        self.last_poll_at = Instant::now();
+    // Screen content that changes with time alone (blinking caret, toast, flash, countdown,
+    // screensaver) rather than with keys or polls.
+    fn animating(&self) -> bool {
+        self.game_over_flashing()
+            || self.screensaver_elapsed().is_some()
+            || self.typing_text()
+            || self.command_input.is_some()
+            || self.toast_showing()
+            || (self.screen == Screen::GameOver && self.autoreturn_at.is_some())
     }
 
-    async fn handle_key(&mut self, key: KeyEvent) {
-        match self.screen {
-            Screen::Home => self.handle_home_key(key).await,
-            Screen::SoloGame => self.handle_solo_key(key).await,
-            Screen::PvpLobby => self.handle_pvp_lobby_key(key).await,
-            Screen::PvpCreate => self.handle_pvp_create_key(key).await,
-            Screen::PvpGame => self.handle_pvp_game_key(key).await,
-            Screen::GameOver => self.handle_game_over_key(key),
-            Screen::Info => self.handle_info_key(key),
-        }
+    fn toast_showing(&self) -> bool {
+        self.toast
+            .as_ref()
+            .is_some_and(|(_, shown_at)| shown_at.elapsed() < TOAST_DURATION)
     }
 
-    async fn handle_home_key(&mut self, key: KeyEvent) {
-        let home_items = ["Solo vs Computer", "PvP", "Exit"];
-        match key.code {
-            KeyCode::Char('q') => self.should_quit = true,
-            KeyCode::Up => {
-                self.home_index = self.home_index.saturating_sub(1);
-            }
-            KeyCode::Down => {
-                if self.home_index + 1 < home_items.len() {
-                    self.home_index += 1;
-                }
-            }
-            KeyCode::Enter => match self.home_index {
-                0 => match self.api.create_solo_game(&self.player_id).await {
-                    Ok(game) => {
-                        self.solo_game = Some(game);
-                        self.board_cursor = 0;
-                        self.screen = Screen::SoloGame;
-                    }
-                    Err(err) => {
-                        self.show_error(format!("Could not start solo game: {err}"));
-                    }
-                },
-                1 => match self.api.list_open_pvp_games().await {
-                    Ok(games) => {
-                        self.pvp_games = games;
-                        self.pvp_selected_index = 0;
-                        self.screen = Screen::PvpLobby;
-                    }
-                    Err(err) => {
-                        self.show_error(format!("Could not load PvP games: {err}"));
-                    }
-                },
-                _ => self.should_quit = true,
-            },
-            _ => {}
+    fn input_poll_timeout(&self) -> Duration {
+        let live_screen = matches!(self.screen, Screen::PvpLobby | Screen::PvpGame)
+            || self.game_over_flashing()
+            || self.screensaver_elapsed().is_some()
+            || self.typing_text()
+            || self.command_input.is_some();
+        if live_screen || self.toast_showing() {
+            ACTIVE_INPUT_POLL
+        } else {
+            IDLE_INPUT_POLL
         }
     }
 
-    async fn handle_solo_key(&mut self, key: KeyEvent) {
-        if matches!(key.code, KeyCode::Char('b')) {
-            self.screen = Screen::Home;
-            return;
-        }
+    // True while a text field has focus, so plain letters are text rather than shortcuts.
+    fn typing_text(&self) -> bool {
+        matches!(self.screen, Screen::Setup | Screen::PvpCreate)
+            || self.editing_join_password
+            || self.editing_setting
+    }
 
-        if matches!(key.code, KeyCode::Char('q')) {
-            self.should_quit = true;
+    // Blink phase of the text caret: on for CARET_BLINK, off for CARET_BLINK, starting solid
+    // after each key press.
+    fn caret_on(&self) -> bool {
+        (self.last_key_at.elapsed().as_millis() / CARET_BLINK.as_millis()).is_multiple_of(2)
+    }
+
+    // Handles `--join <game_id>`: skip the menus and go straight into that game.
+    async fn join_from_config(&mut self) {
+        let Some(game_id) = self.config.join_game_id.take() else {
             return;
-        }
+        };
 
-        self.update_board_cursor(key.code);
+        let password = self.config.join_password.take();
+        self.join_game(&game_id, password).await;
+    }
 
-        let Some(game) = self.solo_game.clone() else {
+    // Asks whether to go back into the game that was open when the app last quit,
+    // as long as it is still running on the server.
+    async fn offer_resume(&mut self) {
+        if self.screen != Screen::Home {
+            return;
+        }
+        let Some(game_id) = self.settings.last_game_id.clone() else {
             return;
         };
 
-        if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
-            let player_turn = game.current_turn == "X";
-            let game_running = game.status == "IN_PROGRESS";
-            if player_turn && game_running {
-                match self
-                    .api
-                    .play_move(&self.player_id, &game.id, self.board_cursor)
-                    .await
-                {
-                    Ok(updated) => {
-                        if Self::is_game_finished(&updated) {
-                            self.open_game_over(&updated, "Solo");
-                        }
-                        self.solo_game = Some(updated);
-                    }
-                    Err(err) => self.show_error(format!("Move failed: {err}")),
-                }
+        match self.api.get_game(&game_id).await {
+            Ok(game) if game.status == "IN_PROGRESS" || game.is_waiting_for_player() => {
+                self.confirm = Some(Confirm::ResumeGame);
             }
+            // Finished or gone: nothing to resume.
+            Ok(_) | Err(ApiError::NotFound(_) | ApiError::Rejected { .. }) => {
+                self.forget_last_game()
+            }
+            // Server unreachable: keep the id and ask again next launch.
+            Err(_) => {}
         }
     }
 
-    async fn handle_pvp_lobby_key(&mut self, key: KeyEvent) {
-        if self.editing_join_password {
-            match key.code {
-                KeyCode::Esc | KeyCode::Enter => self.editing_join_password = false,
-                KeyCode::Backspace => {
-                    self.join_password.pop();
-                }
-                KeyCode::Char(ch) => {
-                    if self.join_password.len() < 32 {
-                        self.join_password.push(ch);
-                    }
-                }
-                _ => {}
-            }
+    async fn resume_last_game(&mut self) {
+        let Some(game_id) = self.settings.last_game_id.clone() else {
             return;
-        }
+        };
 
-        match key.code {
-            KeyCode::Char('b') => self.screen = Screen::Home,
-            KeyCode::Char('q') => self.should_quit = true,
-            KeyCode::Up => {
-                self.pvp_selected_index = self.pvp_selected_index.saturating_sub(1);
-            }
-            KeyCode::Down => {
-                if self.pvp_selected_index + 1 < self.pvp_games.len() {
-                    self.pvp_selected_index += 1;
+        match self.api.get_game(&game_id).await {
+            Ok(game) => {
+                self.board_cursor = 0;
+                self.analysis = false;
+                self.reset_move_history();
+                if game.mode == "SOLO" {
+                    self.solo_game = Some(game);
+                    self.screen = Screen::SoloGame;
+                } else {
+                    self.enter_pvp_game(game);
                 }
             }
-            KeyCode::Char('r') => match self.api.list_open_pvp_games().await {
-                Ok(games) => {
-                    self.pvp_games = games;
-                    self.pvp_selected_index = 0;
-                }
-                Err(err) => self.show_error(format!("Refresh failed: {err}")),
-            },
-            KeyCode::Char('c') => {
-                self.create_name.clear();
-                self.create_password.clear();
-                self.create_field_index = 0;
-                self.screen = Screen::PvpCreate;
+            Err(err) => {
+                self.forget_last_game();
+                self.report_api_error("Could not resume game", err);
             }
-            KeyCode::Char('p') => self.editing_join_password = true,
-            KeyCode::Char('j') | KeyCode::Enter => {
-                if self.pvp_games.is_empty() {
-                    return;
-                }
+        }
+    }
 
-                if let Some(game) = self.pvp_games.get(self.pvp_selected_index) {
-                    let password = if game.has_password {
-                        if self.join_password.is_empty() {
-                            None
-                        } else {
-                            Some(self.join_password.clone())
-                        }
-                    } else {
-                        None
-                    };
+    // Saves the game just entered so the next launch can offer to resume it.
+    fn remember_game(&mut self, game_id: &str) {
+        self.settings.last_game_id = Some(game_id.to_string());
+        self.save_settings();
+    }
 
-                    match self
-                        .api
-                        .join_pvp_game(&self.player_id, &game.id, password)
-                        .await
-                    {
-                        Ok(joined) => {
-                            self.pvp_game = Some(joined);
-                            self.board_cursor = 0;
-                            self.screen = Screen::PvpGame;
-                        }
-                        Err(err) => {
-                            self.show_error(format!("Join failed: {err}"));
-                        }
-                    }
-                }
-            }
-            _ => {}
+    fn forget_last_game(&mut self) {
+        if self.settings.last_game_id.take().is_some() {
+            self.save_settings();
         }
     }
 
-    async fn handle_pvp_create_key(&mut self, key: KeyEvent) {
-        match key.code {
-            KeyCode::Esc | KeyCode::Char('b') => self.screen = Screen::PvpLobby,
-            KeyCode::Tab | KeyCode::Down | KeyCode::Up => {
-                self.create_field_index = (self.create_field_index + 1) % 2;
-            }
-            KeyCode::Backspace => {
-                if self.create_field_index == 0 {
-                    self.create_name.pop();
-                } else {
-                    self.create_password.pop();
-                }
-            }
-            KeyCode::Enter => {
-                if self.create_name.trim().len() < 3 {
-                    self.show_error("Game name must be at least 3 chars".to_string());
-                    return;
-                }
-
-                let password = if self.create_password.trim().is_empty() {
-                    None
-                } else {
-                    Some(self.create_password.trim().to_string())
-                };
+    // Spectators poll through the watch endpoint so they show up in the viewer list.
+    // Servers without it (404) are asked the plain way instead.
+    async fn fetch_pvp_game(&self, game_id: &str) -> Result<ApiGame, ApiError> {
+        let spectating = self
+            .pvp_game
+            .as_ref()
+            .is_some_and(|game| self.player_symbol_for(game) == "?");
+        if !spectating {
+            return self.api.get_game(game_id).await;
+        }
 
-                match self
-                    .api
-                    .create_pvp_game(&self.player_id, self.create_name.trim(), password)
-                    .await
-                {
-                    Ok(game) => {
-                        self.pvp_game = Some(game);
-                        self.screen = Screen::PvpGame;
-                    }
-                    Err(err) => self.show_error(format!("Create game failed: {err}")),
-                }
-            }
-            KeyCode::Char(ch) => {
-                if self.create_field_index == 0 {
-                    if self.create_name.len() < 40 {
-                        self.create_name.push(ch);
-                    }
-                } else if self.create_password.len() < 32 {
-                    self.create_password.push(ch);
-                }
-            }
-            _ => {}
+        match self
+            .api
+            .watch_game(&self.player_id, self.display_name(), game_id)
+            .await
+        {
+            Err(ApiError::NotFound(_)) => self.api.get_game(game_id).await,
+            result => result,
         }
     }
 
-    async fn handle_pvp_game_key(&mut self, key: KeyEvent) {
-        if matches!(key.code, KeyCode::Char('b')) {
-            self.screen = Screen::PvpLobby;
+    async fn refresh_remote_state_if_needed(&mut self) {
+        self.poll_parked_games().await;
+        if self.last_poll_at.elapsed() < self.poll_interval {
             return;
         }
+        // Only these screens show polled state (and its age).
+        if matches!(
+            self.screen,
+            Screen::PvpLobby | Screen::PvpGame | Screen::GameOver
+        ) {
+            self.dirty = true;
+        }
 
-        if matches!(key.code, KeyCode::Char('q')) {
-            self.should_quit = true;
-            return;
+        match self.screen {
+            Screen::PvpLobby => {
+                // Errors are ignored while polling; the next tick simply tries again.
+                let _ = self.refresh_lobby().await;
+            }
+            // The event stream already delivers every change.
+            Screen::PvpGame if self.game_events.is_some() => {}
+            Screen::PvpGame => {
+                // Anything but a 404 may be a blip; the next tick simply tries again.
+                let _ = self.poll_pvp_game().await;
+            }
+            Screen::GameOver => self.poll_rematch().await,
+            _ => {}
         }
 
-        self.update_board_cursor(key.code);
+        self.last_poll_at = Instant::now();
+        self.poll_interval = jittered_poll_interval();
+    }
 
-        let Some(game) = self.pvp_game.clone() else {
-            return;
+    // Fetches the PvP game on screen and applies it: opponent moves, joins, the end of the game.
+    // A 404 means the game is gone for good and is handled here; other failures are returned.
+    async fn poll_pvp_game(&mut self) -> Result<(), ApiError> {
+        let Some(game_id) = self.pvp_game.as_ref().map(|g| g.id.clone()) else {
+            return Ok(());
         };
+        // Polls are awaited right here in the loop, so no key press can switch the screen or the
+        // game before the answer is in: it always belongs to the game it was asked for. (Streamed
+        // updates, which do arrive in the background, are checked in `apply_streamed_updates`.)
+        match self.fetch_pvp_game(&game_id).await {
+            Ok(game) => self.apply_pvp_update(game),
+            // Deleted or expired on the server: polling again won't bring it back.
+            Err(ApiError::NotFound(_)) => self.game_vanished(&game_id),
+            Err(err) => return Err(err),
+        }
+        Ok(())
+    }
 
-        let player_symbol = self.player_symbol_for(&game);
-        let my_turn = player_symbol == game.current_turn;
-
-        if matches!(key.code, KeyCode::Enter | KeyCode::Char(' '))
-            && game.status == "IN_PROGRESS"
-            && my_turn
+    // A fresh state of the PvP game on screen, polled or streamed: opponent moves, joins, the end
+    // of the game.
+    fn apply_pvp_update(&mut self, game: ApiGame) {
+        // Never step back to a board older than the one on screen.
+        if self
+            .pvp_game
+            .as_ref()
+            .is_some_and(|shown| game.is_older_than(shown))
         {
-            match self
-                .api
-                .play_move(&self.player_id, &game.id, self.board_cursor)
-                .await
-            {
-                Ok(updated) => {
-                    if Self::is_game_finished(&updated) {
-                        self.open_game_over(&updated, "PvP");
-                    }
-                    self.pvp_game = Some(updated);
-                }
-                Err(err) => self.show_error(format!("Move failed: {err}")),
+            return;
+        }
+        if self.opponent_just_joined(&game) {
+            self.board_cursor = 0;
+            let you = self.player_symbol_for(&game);
+            if game.current_turn == you {
+                self.show_toast(&format!("Opponent joined! You are {you}, your move."));
+            } else {
+                self.show_toast(&format!("Opponent joined! You are {you}, they move first."));
             }
+            self.alert(SoundEvent::OpponentJoined);
         }
+        if let Some(cell) = self.opponent_move_in(&game) {
+            self.opponent_move = Some((cell, Instant::now()));
+            if game.status == "IN_PROGRESS" {
+                self.cue(SoundEvent::YourTurn);
+            }
+        }
+        let previous = self.pvp_game.clone();
+        self.record_moves(previous.as_ref(), &game);
+        self.track_turn(&game);
+        if Self::is_game_finished(&game) {
+            self.open_game_over(&game, "PvP");
+        }
+        self.pvp_game = Some(game);
+        self.offer_claim_if_idle();
     }
 
-    fn handle_game_over_key(&mut self, key: KeyEvent) {
-        match key.code {
-            KeyCode::Char('q') => self.should_quit = true,
-            KeyCode::Enter | KeyCode::Esc | KeyCode::Char('b') | KeyCode::Char('m') => {
-                self.screen = Screen::Home;
+    // Applies what the event stream pushed since the last tick. The stream belongs to one game,
+    // so it is hung up as soon as another screen or game is up; once it closes, polling resumes
+    // until the stream is reopened.
+    fn apply_streamed_updates(&mut self) {
+        if self
+            .resubscribe_at
+            .is_some_and(|at| Instant::now() >= at && self.game_events.is_none())
+        {
+            self.subscribe_to_game();
+        }
+        while let Some(events) = self.game_events.as_mut() {
+            let on_screen = self.screen == Screen::PvpGame
+                && self
+                    .pvp_game
+                    .as_ref()
+                    .is_some_and(|game| game.id == events.game_id());
+            if !on_screen {
+                self.game_events = None;
+                return;
+            }
+            match events.next_update() {
+                StreamUpdate::Game(game) => {
+                    self.apply_pvp_update(*game);
+                    self.dirty = true;
+                }
+                StreamUpdate::Nothing => return,
+                StreamUpdate::Closed => {
+                    // A stream that never delivered anything means the server has none to offer,
+                    // so that one isn't retried; a working one that dropped is.
+                    let retry = events.delivered();
+                    self.game_events = None;
+                    self.resubscribe_at = retry.then(|| Instant::now() + STREAM_RETRY);
+                    // Nothing was polled while the stream was up, so catch up right away.
+                    self.poll_interval = Duration::ZERO;
+                }
             }
-            _ => {}
         }
     }
 
-    fn handle_info_key(&mut self, key: KeyEvent) {
-        if matches!(key.code, KeyCode::Enter | KeyCode::Esc | KeyCode::Char('b')) {
-            self.screen = Screen::Home;
+    // `R` on a game screen: fetch the game now instead of waiting for the next poll. The loop
+    // draws a "Resyncing" popup first, since the request can take a moment.
+    async fn resync_game(&mut self) {
+        self.resync_pending = false;
+        self.dirty = true;
+        let result = match self.screen {
+            Screen::PvpGame => self.poll_pvp_game().await,
+            Screen::SoloGame => self.resync_solo_game().await,
+            _ => Ok(()),
+        };
+        match result {
+            Ok(()) => self.show_toast("Game resynced."),
+            Err(err) => self.report_api_error("Resync failed", err),
         }
+        // A fresh state, so the next automatic poll can wait its full interval.
+        self.last_poll_at = Instant::now();
     }
 
-    fn update_board_cursor(&mut self, key: KeyCode) {
-        let row = self.board_cursor / 3;
-        let col = self.board_cursor % 3;
-
-        let (next_row, next_col) = match key {
-            KeyCode::Left => (row, col.saturating_sub(1)),
-            KeyCode::Right => (row, (col + 1).min(2)),
-            KeyCode::Up => (row.saturating_sub(1), col),
-            KeyCode::Down => ((row + 1).min(2), col),
-            KeyCode::Char(ch) if ('1'..='9').contains(&ch) => {
-                let index = ch as usize - '1' as usize;
-                self.board_cursor = index;
-                return;
-            }
-            _ => (row, col),
+    async fn resync_solo_game(&mut self) -> Result<(), ApiError> {
+        let Some(game) = self.solo_game.clone() else {
+            return Ok(());
         };
-
-        self.board_cursor = next_row * 3 + next_col;
+        let updated = self.api.get_game(&game.id).await?;
+        self.record_moves(Some(&game), &updated);
+        if Self::is_game_finished(&updated) {
+            self.open_game_over(&updated, "Solo");
+        }
+        self.solo_game = Some(updated);
+        Ok(())
     }
 
-    fn player_symbol_for(&self, game: &ApiGame) -> String {
-        if game.host_player_id == self.player_id {
-            "X".to_string()
-        } else if game.guest_player_id.as_deref() == Some(self.player_id.as_str()) {
-            "O".to_string()
+    // `R` on a game screen asks the loop for a resync; local games have nothing to fetch.
+    fn request_resync(&mut self) {
+        let local = self.screen == Screen::SoloGame
+            && self
+                .solo_game
+                .as_ref()
+                .is_none_or(|game| matches!(game.mode.as_str(), MODE_LOCAL_SOLO | MODE_PRACTICE));
+        if local {
+            self.show_toast("This game runs locally, there is nothing to resync.");
         } else {
-            "?".to_string()
+            self.resync_pending = true;
         }
     }
 
-    fn draw(&self, frame: &mut Frame<'_>) {
-        // Determine the current screen and call the appropriate UI rendering function.
-        match self.screen {
-            // Render the Home screen with the selected menu index highlighted.
-            Screen::Home => ui::draw_home(frame, self.home_index),
-            // Render the Solo Game screen with game details, mode label, current cursor position, and player's symbol.
-            Screen::SoloGame => ui::draw_game(
-                frame,
-                self.solo_game.as_ref(),
-                "Solo Mode",
-                self.board_cursor,
-                self.player_symbol_for_opt(self.solo_game.as_ref()),
-            ),
-            // Render the PvP Lobby screen with available games, selected game index, join password, and editing state.
-            Screen::PvpLobby => ui::draw_pvp_lobby(
-                frame,
-                &self.pvp_games,
-                self.pvp_selected_index,
-                &self.join_password,
-                self.editing_join_password,
-            ),
-            // Render the PvP Create screen with the current input values for game name, password, and the focused field.
-            Screen::PvpCreate => ui::draw_pvp_create(
-                frame,
-                &self.create_name,
-                &self.create_password,
-                self.create_field_index,
-            ),
-            // Render the PvP Game screen with game details, mode label, current cursor position, and player's symbol.
-            Screen::PvpGame => ui::draw_game(
-                frame,
-                self.pvp_game.as_ref(),
-                "PvP Mode",
-                self.board_cursor,
-                self.player_symbol_for_opt(self.pvp_game.as_ref()),
-            ),
-            // Render the Game Over screen with the game's result message.
-            Screen::GameOver => ui::draw_game_over(frame, &self.game_over_message),
-            // Render the Info screen with the provided informational message.
-            Screen::Info => ui::draw_info(frame, &self.info_message),
+    // Back from sleep/suspend: everything on screen may be stale and the server may have restarted
+    // meanwhile. Checks the server again and makes the screen's state and every parked game due
+    // for a poll right away, instead of waiting for their timers.
+    async fn resync_after_suspend(&mut self) {
+        let gap = self.last_tick_at.elapsed().unwrap_or_default();
+        if gap >= SUSPEND_GAP {
+            self.check_server().await;
+            self.poll_interval = Duration::ZERO;
+            // A connection from before the suspend is most likely dead without knowing it.
+            if self.game_events.is_some() {
+                self.subscribe_to_game();
+            }
+            let due = Instant::now().checked_sub(PARKED_POLL_INTERVAL);
+            for parked in &mut self.parked_games {
+                parked.polled_at = due.unwrap_or(parked.polled_at);
+            }
+            self.dirty = true;
         }
+        // Taken after the health check, so a slow one isn't mistaken for another suspend.
+        self.last_tick_at = SystemTime::now();
     }
 
-    fn player_symbol_for_opt(&self, game: Option<&ApiGame>) -> String {
-        game.map(|g| self.player_symbol_for(g))
+    async fn handle_key(&mut self, key: KeyEvent) {
+        let screensaver_on = self.screensaver_elapsed().is_some();
+        self.last_key_at = Instant::now();
+        self.dirty = true;
+        // Someone is at the keyboard, so don't pull the Game Over screen away from them.
+        self.autoreturn_at = None;
+
+        // Raw mode turns Ctrl+C into a normal key press instead of SIGINT, so quit through
+        // the regular path and let `main` restore the terminal.
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            self.should_quit = true;
+            return;
+        }
+
+        // The key that wakes the screensaver only dismisses it (so `q` doesn't quit).
+        if screensaver_on {
+            return;
+        }
+
+        // The invite popup swallows one key press to close itself.
+        if self.invite_text.take().is_some() {
+            return;
+        }
+
+        // F12 opens a debug view of the last response that failed to decode; any key closes it.
+        if self.showing_raw_response {
+            self.showing_raw_response = false;
+            return;
+        }
+        if key.code == KeyCode::F(12) {
+            if self.last_raw_response.is_some() {
+                self.showing_raw_response = true;
+            } else {
+                self.show_toast("No undecodable server response captured yet.");
+            }
+            return;
+        }
+
+        // Ctrl+P hides game ids and passwords on every screen, e.g. before sharing it.
+        if key.code == KeyCode::Char('p') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            self.privacy = !self.privacy;
+            self.show_toast(if self.privacy {
+                "Privacy mode on: game ids and passwords are hidden."
+            } else {
+                "Privacy mode off."
+            });
+            return;
+        }
+
+        // Ctrl+N follows the last "your turn" notice into that game, from any screen.
+        if key.code == KeyCode::Char('n') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            self.jump_to_noticed_game().await;
+            return;
+        }
+
+        // Confirmation prompts also take exactly one key: `y` confirms, anything else declines.
+        if let Some(confirm) = self.confirm.take() {
+            if key.code == KeyCode::Char('y') {
+                match confirm {
+                    Confirm::LeaveGame => self.resign_and_leave().await,
+                    Confirm::ClaimWin => self.claim_win().await,
+                    Confirm::ResumeGame => self.resume_last_game().await,
+                    Confirm::QuitApp => self.should_quit = true,
+                }
+            } else if confirm == Confirm::ResumeGame {
+                self.forget_last_game();
+            }
+            return;
+        }
+
+        if self.command_input.is_some() {
+            self.handle_command_key(key).await;
+            return;
+        }
+
+        if key.code == KeyCode::Char(':') && !self.typing_text() {
+            self.command_input = Some(String::new());
+            return;
+        }
+
+        match self.screen {
+            Screen::Setup => self.handle_setup_key(key),
+            Screen::Home => self.handle_home_key(key).await,
+            Screen::SoloGame => self.handle_solo_key(key).await,
+            Screen::PvpLobby => self.handle_pvp_lobby_key(key).await,
+            Screen::PvpCreate => self.handle_pvp_create_key(key).await,
+            Screen::PvpGame => self.handle_pvp_game_key(key).await,
+            Screen::GameOver => self.handle_game_over_key(key).await,
+            Screen::Replay => self.handle_replay_key(key).await,
+            Screen::Settings => self.handle_settings_key(key).await,
+            Screen::Info => self.handle_info_key(key).await,
+        }
+    }
+
+    fn handle_setup_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc => self.should_quit = true,
+            KeyCode::Enter => match settings::validate_display_name(self.text_input.value()) {
+                Ok(name) => {
+                    self.settings.display_name = name;
+                    self.save_settings();
+                    self.screen = Screen::Home;
+                }
+                Err(message) => self.show_toast(&message),
+            },
+            code => {
+                self.text_input.handle_key(code, 32);
+            }
+        }
+    }
+
+    async fn handle_home_key(&mut self, key: KeyEvent) {
+        // The quick keys legend has no list, so there is no selection to move or pick either.
+        let quick_keys = self.settings.quick_keys_home;
+        if quick_keys {
+            if self.handle_home_quick_key(key.code).await {
+                return;
+            }
+        } else if let Some(index) = nav::step(key.code, self.home_index, ui::HOME_ITEMS.len()) {
+            self.home_index = index;
+            return;
+        }
+
+        match key.code {
+            KeyCode::Char('q') => self.request_quit(),
+            KeyCode::Char('c') => self.open_create_screen(),
+            KeyCode::Char('g') => self.quick_match().await,
+            KeyCode::Enter if !quick_keys => match self.home_index {
+                0 => self.start_solo_game(None).await,
+                1 => self.open_pvp_lobby().await,
+                2 => self.quick_match().await,
+                3 => self.start_practice_game(),
+                4 => self.open_settings(),
+                5 => self.show_about().await,
+                _ => self.request_quit(),
+            },
+            _ => {}
+        }
+    }
+
+    // The "quick keys" Home layout: one key per menu entry. Returns false for keys it
+    // doesn't own, which then go through the regular Home handling.
+    async fn handle_home_quick_key(&mut self, code: KeyCode) -> bool {
+        match code {
+            KeyCode::Char('s') => self.start_solo_game(None).await,
+            KeyCode::Char('p') => self.open_pvp_lobby().await,
+            KeyCode::Char('t') => self.start_practice_game(),
+            KeyCode::Char('o') => self.open_settings(),
+            KeyCode::Char('a') => self.show_about().await,
+            _ => return false,
+        }
+        true
+    }
+
+    fn open_settings(&mut self) {
+        self.settings_index = 0;
+        self.editing_setting = false;
+        self.screen = Screen::Settings;
+    }
+
+    fn selected_setting(&self) -> SettingRow {
+        SettingRow::ALL[self.settings_index]
+    }
+
+    async fn handle_settings_key(&mut self, key: KeyEvent) {
+        if self.editing_setting {
+            match key.code {
+                KeyCode::Esc => self.editing_setting = false,
+                KeyCode::Enter => self.apply_setting_edit().await,
+                KeyCode::Up | KeyCode::Down if self.selected_setting() == SettingRow::ServerUrl => {
+                    self.pick_recent_server(key.code)
+                }
+                code => {
+                    if self.text_input.handle_key(code, usize::MAX) {
+                        self.recent_server_index = None;
+                    }
+                }
+            }
+            return;
+        }
+
+        if let Some(index) = nav::step(key.code, self.settings_index, SettingRow::ALL.len()) {
+            self.settings_index = index;
+            return;
+        }
+
+        match key.code {
+            KeyCode::Char('q') => self.request_quit(),
+            code if nav::is_back(code) => self.screen = Screen::Home,
+            KeyCode::Char('m') => self.screen = Screen::Home,
+            KeyCode::Left | KeyCode::Right if self.selected_setting() == SettingRow::Theme => {
+                self.cycle_theme()
+            }
+            KeyCode::Enter => match self.selected_setting() {
+                SettingRow::ServerUrl => {
+                    self.text_input.set(&self.base_url);
+                    self.recent_server_index = None;
+                    self.editing_setting = true;
+                }
+                SettingRow::Theme => self.cycle_theme(),
+                SettingRow::Colors => {
+                    self.settings.ascii_mode = !self.settings.ascii_mode;
+                    self.save_settings();
+                }
+                SettingRow::DisplayName => {
+                    self.text_input.set(&self.settings.display_name);
+                    self.editing_setting = true;
+                }
+                SettingRow::HighlightOwnMarks => {
+                    self.settings.highlight_own_marks = !self.settings.highlight_own_marks;
+                    self.save_settings();
+                }
+                SettingRow::BoardSymbols => {
+                    self.settings.board_symbols = self.settings.board_symbols.next();
+                    self.save_settings();
+                }
+                SettingRow::CustomSymbols => {
+                    self.text_input.set(&self.settings.custom_symbols);
+                    self.editing_setting = true;
+                }
+                SettingRow::Accessibility => {
+                    self.settings.accessible = !self.settings.accessible;
+                    self.save_settings();
+                }
+                SettingRow::Coordinates => {
+                    self.settings.show_coordinates = !self.settings.show_coordinates;
+                    self.save_settings();
+                }
+                SettingRow::QuickKeysHome => {
+                    self.settings.quick_keys_home = !self.settings.quick_keys_home;
+                    self.save_settings();
+                }
+                SettingRow::SoloGames => {
+                    self.settings.local_solo = !self.settings.local_solo;
+                    self.save_settings();
+                }
+                SettingRow::Layout => {
+                    self.settings.layout = self.settings.layout.next();
+                    self.save_settings();
+                }
+                SettingRow::AutoAdvance => {
+                    self.settings.auto_advance = !self.settings.auto_advance;
+                    self.save_settings();
+                }
+                SettingRow::Sound(event) => {
+                    let muted = &mut self.settings.muted_sounds;
+                    if muted.contains(&event) {
+                        muted.retain(|known| *known != event);
+                    } else {
+                        muted.push(event);
+                    }
+                    self.save_settings();
+                }
+            },
+            _ => {}
+        }
+    }
+
+    // Commits the text typed for the selected settings row (server URL, display name or custom symbols).
+    async fn apply_setting_edit(&mut self) {
+        if self.selected_setting() == SettingRow::CustomSymbols {
+            match settings::validate_custom_symbols(self.text_input.value()) {
+                Ok(symbols) => {
+                    self.editing_setting = false;
+                    self.settings.custom_symbols = symbols;
+                    // Typing symbols only makes sense to use them
+                    self.settings.board_symbols = BoardSymbols::Custom;
+                    self.save_settings();
+                }
+                Err(message) => self.show_toast(&message),
+            }
+            return;
+        }
+
+        if self.selected_setting() == SettingRow::ServerUrl {
+            let url = config::normalize_base_url(self.text_input.value());
+            if url.is_empty() {
+                self.show_toast("Server URL cannot be empty");
+                return;
+            }
+            self.editing_setting = false;
+            self.set_server(url).await;
+            return;
+        }
+
+        match settings::validate_display_name(self.text_input.value()) {
+            Ok(name) => {
+                self.editing_setting = false;
+                self.settings.display_name = name;
+                self.save_settings();
+                self.show_toast("Display name saved (used for new games).");
+            }
+            Err(message) => self.show_toast(&message),
+        }
+    }
+
+    // Up/Down through the recent servers while editing the URL; the pick replaces the typed text.
+    fn pick_recent_server(&mut self, code: KeyCode) {
+        let recent = &self.settings.recent_servers;
+        if recent.is_empty() {
+            return;
+        }
+        let index = match (self.recent_server_index, code) {
+            (None, KeyCode::Up) => recent.len() - 1,
+            (None, _) => 0,
+            (Some(current), code) => nav::step(code, current, recent.len()).unwrap_or(current),
+        };
+        self.text_input.set(&recent[index]);
+        self.recent_server_index = Some(index);
+    }
+
+    fn cycle_theme(&mut self) {
+        self.settings.theme = self.settings.theme.next();
+        self.save_settings();
+    }
+
+    // Points the client at another server, remembers it and checks that it answers.
+    async fn set_server(&mut self, url: String) {
+        self.api.set_base_url(&url);
+        self.base_url = url;
+        self.settings.base_url = Some(self.base_url.clone());
+        self.settings.remember_server(&self.base_url);
+        self.save_settings();
+        self.check_server().await;
+        let status = if self.server_online == Some(true) {
+            "online"
+        } else {
+            "not reachable"
+        };
+        self.show_toast(&format!("Server set to {} ({status})", self.base_url));
+    }
+
+    // A check that outlasts the timeout counts as offline.
+    async fn check_server(&mut self) {
+        let health = tokio::time::timeout(self.health_timeout(), self.api.health_check()).await;
+        self.server_online = Some(matches!(health, Ok(Ok(true))));
+    }
+
+    fn health_timeout(&self) -> Duration {
+        self.config
+            .health_timeout_secs
+            .map_or(DEFAULT_HEALTH_TIMEOUT, Duration::from_secs)
+    }
+
+    async fn handle_command_key(&mut self, key: KeyEvent) {
+        let Some(input) = self.command_input.as_mut() else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc => self.command_input = None,
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Char(ch) => input.push(ch),
+            KeyCode::Enter => {
+                let command = self.command_input.take().unwrap_or_default();
+                self.run_command(command.trim()).await;
+            }
+            _ => {}
+        }
+    }
+
+    // Client and server versions plus the endpoints we rely on, for bug reports.
+    async fn show_about(&mut self) {
+        let server_version = match self.api.server_version().await {
+            Ok(version) => version,
+            Err(ApiError::NotFound(_)) => "not reported (no /version endpoint)".to_string(),
+            Err(ApiError::Network(_)) => "server not reachable".to_string(),
+            Err(err) => format!("unknown ({err})"),
+        };
+
+        let mut about = format!(
+            "About\n\n{} v{}\nServer: {}\nServer version: {server_version}\n\nAPI endpoints used:",
+            env!("CARGO_PKG_NAME"),
+            env!("CARGO_PKG_VERSION"),
+            self.base_url
+        );
+        for endpoint in api::ENDPOINTS {
+            about.push_str("\n  ");
+            about.push_str(endpoint);
+        }
+        self.stranded_game = None;
+        self.failed_action = None;
+        self.vanished_game = None;
+        self.info_message = about;
+        self.info_scroll = 0;
+        self.screen = Screen::Info;
+    }
+
+    // Command palette dispatcher: maps typed commands onto the same flows the menus use.
+    async fn run_command(&mut self, command: &str) {
+        let (name, arg) = match command.split_once(char::is_whitespace) {
+            Some((name, arg)) => (name, arg.trim()),
+            None => (command, ""),
+        };
+
+        match (name, arg) {
+            ("", _) => {}
+            ("solo", _) => self.start_solo_game(None).await,
+            ("pvp", _) => self.open_pvp_lobby().await,
+            ("quick", _) => self.quick_match().await,
+            ("join", id) if !id.is_empty() => self.join_game(id, None).await,
+            ("create", game_name) if !game_name.is_empty() => {
+                match models::validate_game_name(game_name) {
+                    Ok(()) => self.create_game(game_name, None, None).await,
+                    Err(message) => self.show_toast(&message),
+                }
+            }
+            ("server", url) if !url.is_empty() => {
+                self.set_server(config::normalize_base_url(url)).await;
+            }
+            ("quit" | "q", _) => self.request_quit(),
+            ("stats", _) => self.show_stats(),
+            ("about", _) => self.show_about().await,
+            ("join", _) => self.show_toast("Usage: join <game id>"),
+            ("create", _) => self.show_toast("Usage: create <name>"),
+            ("server", _) => self.show_toast("Usage: server <url>"),
+            (other, _) => self.show_toast(&format!("Unknown command: {other}")),
+        }
+    }
+
+    // `first_turn` of "O" lets the computer open; `None` keeps the server default.
+    async fn start_solo_game(&mut self, first_turn: Option<&str>) {
+        if self.settings.local_solo {
+            self.start_local_solo_game(first_turn);
+            return;
+        }
+
+        match self
+            .api
+            .create_solo_game(
+                &self.player_id,
+                self.display_name(),
+                self.config.client_name(),
+                first_turn,
+            )
+            .await
+        {
+            Ok(game) => {
+                self.metrics.game_created();
+                self.remember_game(&game.id);
+                self.board_cursor = 0;
+                self.analysis = false;
+                self.reset_move_history();
+                // The computer's opening move, if it started, is already on the board.
+                self.move_history
+                    .extend(new_moves(&vec![None; BOARD_CELLS], &game.board));
+                self.solo_game = Some(game);
+                self.screen = Screen::SoloGame;
+            }
+            Err(err) => {
+                let action = PendingAction::StartSolo {
+                    first_turn: first_turn.map(str::to_string),
+                };
+                self.action_failed(action, "Could not start solo game", err);
+            }
+        }
+    }
+
+    // Hot-seat game on one board: I play X and O in turn, no computer and no server involved.
+    // It lives in `solo_game` and uses the solo screen; `engine` applies the moves.
+    fn start_practice_game(&mut self) {
+        let mut game = self.new_local_game(MODE_PRACTICE, "Practice");
+        game.guest_player_id = Some(self.player_id.clone());
+        game.guest_name = game.host_name.clone();
+        self.solo_game = Some(game);
+        self.board_cursor = 0;
+        self.analysis = false;
+        self.reset_move_history();
+        self.screen = Screen::SoloGame;
+    }
+
+    // Solo game against the computer without the server (the "Solo games" setting): moves are
+    // instant, and only the result is uploaded once the game ends (see `upload_pending_results`).
+    fn start_local_solo_game(&mut self, first_turn: Option<&str>) {
+        let mut game = self.new_local_game(MODE_LOCAL_SOLO, "Solo game");
+        game.guest_player_id = Some("AI".to_string());
+        game.guest_name = Some("Computer".to_string());
+        let first_turn = first_turn.unwrap_or("X");
+        game.first_turn = Some(first_turn.to_string());
+        game.current_turn = first_turn.to_string();
+        // Every game gets its own seed, so a game worth sharing can be replayed with `--seed`.
+        self.ai_seed = self.config.seed.unwrap_or_else(|| rand::rng().random());
+        self.ai_rng = StdRng::seed_from_u64(self.ai_seed);
+        // Like the server, the computer plays its opening move before the board is shown.
+        if first_turn == "O" {
+            if let Some(index) = engine::computer_move(&game.board, "O", &mut self.ai_rng) {
+                let _ = engine::play(&mut game, index);
+            }
+        }
+
+        self.metrics.game_created();
+        self.board_cursor = 0;
+        self.analysis = false;
+        self.reset_move_history();
+        self.move_history
+            .extend(new_moves(&vec![None; BOARD_CELLS], &game.board));
+        self.solo_game = Some(game);
+        self.screen = Screen::SoloGame;
+    }
+
+    // Empty board hosted by me, for games that only exist in this client.
+    fn new_local_game(&self, mode: &str, name: &str) -> ApiGame {
+        ApiGame {
+            id: Uuid::new_v4().to_string(),
+            mode: mode.to_string(),
+            name: Some(name.to_string()),
+            host_player_id: self.player_id.clone(),
+            guest_player_id: None,
+            host_name: self.display_name().map(str::to_string),
+            guest_name: None,
+            board: vec![None; BOARD_CELLS],
+            current_turn: "X".to_string(),
+            first_turn: None,
+            status: "IN_PROGRESS".to_string(),
+            winner: None,
+            resigned_by: None,
+            win_reason: None,
+            winning_line: None,
+            has_password: false,
+            spectators: None,
+            rematch_requested_by: None,
+            rematch_game_id: None,
+            host_score: None,
+            guest_score: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    fn play_practice_move(&mut self, game: ApiGame, index: usize) {
+        let mut updated = game.clone();
+        if let Err(reason) = engine::play(&mut updated, index) {
+            self.show_toast(reason);
+            return;
+        }
+        self.metrics.move_made(None);
+        self.record_moves(Some(&game), &updated);
+        if Self::is_game_finished(&updated) {
+            self.open_game_over(&updated, "Practice");
+        }
+        self.solo_game = Some(updated);
+    }
+
+    async fn open_pvp_lobby(&mut self) {
+        self.pvp_selected_index = 0;
+        self.lobby_open_loaded = 0;
+        match self.refresh_lobby().await {
+            Ok(()) => self.screen = Screen::PvpLobby,
+            Err(err) => {
+                self.action_failed(PendingAction::OpenLobby, "Could not load PvP games", err);
+            }
+        }
+    }
+
+    // PvP create form, reachable from the lobby and straight from Home. Whatever was typed last
+    // time is still there (leaving with Esc/b by accident loses nothing); Ctrl+R clears it.
+    fn open_create_screen(&mut self) {
+        self.screen = Screen::PvpCreate;
+    }
+
+    fn reset_create_form(&mut self) {
+        self.create_name.clear();
+        self.create_password.clear();
+        self.create_field_index = 0;
+    }
+
+    async fn join_game(&mut self, game_id: &str, password: Option<Secret<String>>) {
+        match self
+            .api
+            .join_pvp_game(
+                &self.player_id,
+                self.display_name(),
+                game_id,
+                password.as_ref().map(|password| password.expose().clone()),
+            )
+            .await
+        {
+            Ok(joined) => {
+                self.remember_game(&joined.id);
+                self.enter_pvp_game(joined);
+            }
+            Err(err) if err.is_game_filled() => self.game_just_filled().await,
+            Err(err) => {
+                let action = PendingAction::Join {
+                    game_id: game_id.to_string(),
+                    password,
+                };
+                self.action_failed(action, "Join failed", err);
+            }
+        }
+    }
+
+    // Someone else took the last seat first: back to a fresh lobby to pick another game,
+    // instead of an error screen about a race nobody could see.
+    async fn game_just_filled(&mut self) {
+        self.screen = Screen::PvpLobby;
+        self.dirty = true;
+        match self.refresh_lobby().await {
+            Ok(()) => self.show_toast("That game just filled — refreshing lobby"),
+            Err(err) => self.report_api_error("Lobby refresh failed", err),
+        }
+    }
+
+    async fn create_game(
+        &mut self,
+        name: &str,
+        password: Option<Secret<String>>,
+        first_turn: Option<&str>,
+    ) {
+        match self
+            .api
+            .create_pvp_game(
+                &self.player_id,
+                self.display_name(),
+                name,
+                password.as_ref().map(|password| password.expose().clone()),
+                first_turn,
+            )
+            .await
+        {
+            Ok(game) => {
+                // The form's draft is used up once it became a game (directly or via retry).
+                if self.create_name.value().trim() == name {
+                    self.reset_create_form();
+                }
+                self.metrics.game_created();
+                self.remember_game(&game.id);
+                self.enter_pvp_game(game);
+            }
+            Err(err) => {
+                let action = PendingAction::Create {
+                    name: name.to_string(),
+                    password,
+                    first_turn: first_turn.map(str::to_string),
+                };
+                self.action_failed(action, "Create game failed", err);
+            }
+        }
+    }
+
+    // One key to a PvP game: join the first open, unlocked game hosted by someone else,
+    // or host a public one and wait for an opponent when there is none.
+    async fn quick_match(&mut self) {
+        let open_games = match self
+            .api
+            .list_open_pvp_games(0, self.config.lobby_page())
+            .await
+        {
+            Ok(page) => page.games,
+            Err(err) => {
+                self.report_api_error("Quick match failed", err);
+                return;
+            }
+        };
+
+        let joinable = open_games
+            .into_iter()
+            .find(|game| !game.has_password && game.host_player_id != self.player_id);
+        match joinable {
+            Some(game) => self.join_game(&game.id, None).await,
+            None => {
+                let name = format!("Quick match #{}", rand::rng().random_range(1000..10000));
+                self.create_game(&name, None, None).await;
+            }
+        }
+    }
+
+    fn show_stats(&mut self) {
+        let SessionStats { won, lost, drawn } = self.stats;
+        self.stranded_game = None;
+        self.failed_action = None;
+        self.vanished_game = None;
+        self.info_scroll = 0;
+        self.info_message = format!(
+            "Session stats\n\nFinished games: {}\nWon: {won}\nLost: {lost}\nDrawn: {drawn}",
+            won + lost + drawn
+        );
+        self.screen = Screen::Info;
+    }
+
+    async fn handle_solo_key(&mut self, key: KeyEvent) {
+        if matches!(key.code, KeyCode::Char('b') | KeyCode::Char('m')) {
+            self.screen = Screen::Home;
+            return;
+        }
+
+        if matches!(key.code, KeyCode::Char('q')) {
+            self.request_quit();
+            return;
+        }
+
+        if matches!(key.code, KeyCode::Char('c')) {
+            self.toggle_confirm_moves();
+            return;
+        }
+
+        if matches!(key.code, KeyCode::Char('i')) {
+            self.show_full_id = !self.show_full_id;
+            return;
+        }
+
+        if matches!(key.code, KeyCode::Char('h')) {
+            self.show_enter_hint = !self.show_enter_hint;
+            return;
+        }
+
+        if matches!(key.code, KeyCode::Char('R')) {
+            self.request_resync();
+            return;
+        }
+
+        if matches!(key.code, KeyCode::Char('e')) {
+            self.export_game();
+            return;
+        }
+
+        if matches!(key.code, KeyCode::Char('a')) {
+            self.toggle_analysis();
+            return;
+        }
+
+        if self.handle_panel_key(key.code) {
+            return;
+        }
+        let board = self.solo_game.as_ref().map(|game| game.board.clone());
+        self.update_board_cursor(key.code, board.as_deref());
+
+        let Some(game) = self.solo_game.clone() else {
+            return;
+        };
+
+        if self.analysis && matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
+            self.show_toast("Analysis mode: moves are off, press a to play.");
+            return;
+        }
+
+        if game.mode == MODE_PRACTICE {
+            // Both sides are mine, so every turn is playable.
+            if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) && self.move_confirmed() {
+                self.play_practice_move(game, self.board_cursor);
+            }
+            return;
+        }
+
+        if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
+            let player_turn = game.current_turn == "X";
+            let game_running = game.status == "IN_PROGRESS";
+            if player_turn && game_running && self.move_confirmed() {
+                self.play_solo_move(game, self.board_cursor).await;
+            }
+        }
+    }
+
+    async fn play_solo_move(&mut self, game: ApiGame, index: usize) {
+        if game.mode == MODE_LOCAL_SOLO {
+            self.play_local_solo_move(game, index).await;
+            return;
+        }
+
+        match self.api.play_move(&self.player_id, &game.id, index).await {
+            Ok(updated) => {
+                self.metrics.move_made(self.api.last_latency());
+                self.record_moves(Some(&game), &updated);
+                if Self::is_game_finished(&updated) {
+                    self.open_game_over(&updated, "Solo");
+                }
+                self.solo_game = Some(updated);
+            }
+            Err(err) => {
+                let action = PendingAction::Move {
+                    game_id: game.id,
+                    index,
+                };
+                self.action_failed(action, "Move failed", err);
+            }
+        }
+    }
+
+    // My move and the computer's answer, both applied by `engine` without a round trip.
+    async fn play_local_solo_move(&mut self, game: ApiGame, index: usize) {
+        let mut updated = game.clone();
+        if let Err(reason) = engine::play(&mut updated, index) {
+            self.show_toast(reason);
+            return;
+        }
+        if updated.status == "IN_PROGRESS" {
+            if let Some(reply) = engine::computer_move(&updated.board, "O", &mut self.ai_rng) {
+                let _ = engine::play(&mut updated, reply);
+            }
+        }
+
+        self.metrics.move_made(None);
+        self.record_moves(Some(&game), &updated);
+        if Self::is_game_finished(&updated) {
+            self.open_game_over(&updated, "Solo");
+            self.queue_result(&updated);
+            self.upload_pending_results().await;
+        }
+        self.solo_game = Some(updated);
+    }
+
+    // Keeps a finished local solo game's result, on disk too, until the server has it.
+    fn queue_result(&mut self, game: &ApiGame) {
+        // The local player is always X.
+        let outcome = game.outcome_for("X").report_value();
+        self.pending_results.push(ReportResultRequest {
+            result_id: game.id.clone(),
+            player_id: self.player_id.clone(),
+            player_name: self.display_name().map(str::to_string),
+            client_name: self.config.client_name().to_string(),
+            outcome: outcome.to_string(),
+            first_turn: game.first_turn().to_string(),
+            board: game.board.clone(),
+            finished_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_secs())
+                .unwrap_or_default(),
+        });
+        let overflow = self
+            .pending_results
+            .len()
+            .saturating_sub(MAX_PENDING_RESULTS);
+        self.pending_results.drain(..overflow);
+        self.save_pending_results();
+    }
+
+    // Sends queued results oldest first. A result the server refuses outright is dropped;
+    // when the server can't be reached the rest wait for the next finished game or the next
+    // launch. A server without the results endpoint isn't asked again until the next launch.
+    async fn upload_pending_results(&mut self) {
+        if self.pending_results.is_empty() || self.results_unsupported {
+            return;
+        }
+
+        let mut waiting = std::mem::take(&mut self.pending_results).into_iter();
+        let mut uploaded = 0;
+        for result in waiting.by_ref() {
+            match self.api.report_result(&result).await {
+                Ok(response) if response.recorded => uploaded += 1,
+                // The server had it already: an earlier upload arrived but its answer didn't
+                Ok(_) => {}
+                Err(ApiError::Network(_) | ApiError::Server { .. }) => {
+                    self.pending_results.push(result);
+                    break;
+                }
+                Err(ApiError::NotFound(_)) => {
+                    self.results_unsupported = true;
+                    self.pending_results.push(result);
+                    break;
+                }
+                Err(_) => {}
+            }
+        }
+        self.pending_results.extend(waiting);
+        self.save_pending_results();
+
+        // A single result going through right after its game needs no announcement
+        let waiting = self.pending_results.len();
+        if self.results_unsupported {
+            self.show_toast(&format!(
+                "This server doesn't accept results: {waiting} result(s) kept for a later launch."
+            ));
+        } else if waiting > 0 {
+            self.show_toast(&format!(
+                "Server not reachable: {waiting} result(s) saved, uploaded later."
+            ));
+        } else if uploaded > 1 {
+            self.show_toast(&format!("Uploaded {uploaded} saved results."));
+        }
+    }
+
+    fn save_pending_results(&mut self) {
+        if let Err(err) = results::save(&self.config_dir, &self.pending_results) {
+            self.show_toast(&format!("Could not save results: {err}"));
+        }
+    }
+
+    async fn handle_pvp_lobby_key(&mut self, key: KeyEvent) {
+        if self.editing_join_password {
+            match key.code {
+                KeyCode::Esc | KeyCode::Enter => self.editing_join_password = false,
+                code => {
+                    self.join_password.handle_key(code, 32);
+                }
+            }
+            return;
+        }
+
+        if key.code == KeyCode::Tab {
+            self.lobby_tab = self.lobby_tab.toggle();
+            if self.lobby_tab == LobbyTab::Mine {
+                if let Err(err) = self.refresh_my_games().await {
+                    self.report_api_error("Could not load your games", err);
+                }
+            }
+            return;
+        }
+        if self.lobby_tab == LobbyTab::Mine {
+            self.handle_my_games_key(key).await;
+            return;
+        }
+
+        // The "Load more" row sits after the last game.
+        let count = self.lobby_games().count() + usize::from(self.lobby_has_more);
+        if let Some(index) = nav::step(key.code, self.pvp_selected_index, count) {
+            self.pvp_selected_index = index;
+            return;
+        }
+
+        match key.code {
+            code if nav::is_back(code) => self.screen = Screen::Home,
+            KeyCode::Char('m') => self.screen = Screen::Home,
+            KeyCode::Char('q') => self.request_quit(),
+            KeyCode::Char('r') => {
+                // The lists from the last good fetch stay up, with a note saying how old they are.
+                self.lobby_refresh_failed = self.refresh_lobby().await.is_err();
+            }
+            KeyCode::Char('v') => self.show_board_previews = !self.show_board_previews,
+            KeyCode::Char('s') => {
+                let selected_id = self.selected_lobby_game_id();
+                self.lobby_sort = self.lobby_sort.next();
+                self.sort_joinable_games();
+                self.select_lobby_game(selected_id);
+            }
+            KeyCode::Char('c') => self.open_create_screen(),
+            KeyCode::Char('g') => self.quick_match().await,
+            KeyCode::Char('p') => self.editing_join_password = true,
+            KeyCode::Char('x') => self.cancel_selected_game().await,
+            KeyCode::Char('n') => self.switch_game(),
+            KeyCode::Enter
+                if self.lobby_has_more && self.pvp_selected_index == self.lobby_games().count() =>
+            {
+                if let Err(err) = self.load_more_lobby_games().await {
+                    self.report_api_error("Could not load more games", err);
+                }
+            }
+            KeyCode::Enter => {
+                let own_count = self.own_games.len();
+                let joinable_count = self.pvp_games.len();
+                let index = self.pvp_selected_index;
+
+                if let Some(game) = self.own_games.get(index).cloned() {
+                    // "Joining" one of my own waiting games just reopens it.
+                    self.remember_game(&game.id);
+                    self.enter_pvp_game(game);
+                } else if let Some(game) = self.pvp_games.get(index - own_count) {
+                    let password = (game.has_password && !self.join_password.is_blank())
+                        .then(|| self.join_password.to_secret());
+
+                    let game_id = game.id.clone();
+                    self.join_game(&game_id, password).await;
+                } else if let Some(game) = self
+                    .live_games
+                    .get(index - own_count - joinable_count)
+                    .cloned()
+                {
+                    self.spectate(game);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // "My games" tab of the lobby: resume a game, or cancel one nobody joined yet.
+    async fn handle_my_games_key(&mut self, key: KeyEvent) {
+        if let Some(index) = nav::step(key.code, self.my_games_index, self.my_games.len()) {
+            self.my_games_index = index;
+            return;
+        }
+
+        match key.code {
+            code if nav::is_back(code) => self.screen = Screen::Home,
+            KeyCode::Char('m') => self.screen = Screen::Home,
+            KeyCode::Char('q') => self.request_quit(),
+            KeyCode::Char('r') => {
+                self.lobby_refresh_failed = self.refresh_lobby().await.is_err();
+            }
+            KeyCode::Char('c') => self.open_create_screen(),
+            KeyCode::Char('x') => {
+                let Some(game) = self.my_games.get(self.my_games_index) else {
+                    return;
+                };
+                if game.is_waiting_for_player() && game.host_player_id == self.player_id {
+                    let game_id = game.id.clone();
+                    self.cancel_game(&game_id).await;
+                } else {
+                    self.show_toast("Only games nobody joined yet can be cancelled.");
+                }
+            }
+            KeyCode::Enter => {
+                if let Some(game) = self.my_games.get(self.my_games_index).cloned() {
+                    self.remember_game(&game.id);
+                    self.enter_pvp_game(game);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    async fn handle_pvp_create_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('b') => self.screen = Screen::PvpLobby,
+            KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.reset_create_form();
+            }
+            KeyCode::Tab | KeyCode::Down | KeyCode::Up => {
+                self.create_field_index = (self.create_field_index + 1) % 2;
+            }
+            KeyCode::Enter => {
+                if let Err(message) = models::validate_game_name(self.create_name.value()) {
+                    self.show_error(message);
+                    return;
+                }
+
+                let password =
+                    (!self.create_password.is_blank()).then(|| self.create_password.to_secret());
+
+                let name = self.create_name.value().trim().to_string();
+                self.create_game(&name, password, None).await;
+            }
+            // Everything else edits the active field at its caret
+            code => {
+                if self.create_field_index == 0 {
+                    self.create_name.handle_key(code, GAME_NAME_MAX_CHARS);
+                } else {
+                    self.create_password.handle_key(code, 32);
+                }
+            }
+        }
+    }
+
+    async fn handle_pvp_game_key(&mut self, key: KeyEvent) {
+        if matches!(key.code, KeyCode::Char('b')) {
+            // Walking away from a running game would strand the opponent, so ask first.
+            // Spectators have nobody to strand.
+            let in_progress = self.pvp_game.as_ref().is_some_and(|game| {
+                game.status == "IN_PROGRESS" && self.player_symbol_for(game) != "?"
+            });
+            let hosting = self.pvp_game.as_ref().is_some_and(|game| {
+                game.is_waiting_for_player() && self.player_symbol_for(game) != "?"
+            });
+            if in_progress {
+                self.confirm = Some(Confirm::LeaveGame);
+            } else {
+                // A hosted game keeps being polled from the lobby, so I hear when it's my turn.
+                if hosting {
+                    self.park_current_game();
+                }
+                self.screen = Screen::PvpLobby;
+            }
+            return;
+        }
+
+        if matches!(key.code, KeyCode::Char('s')) {
+            self.share_invite();
+            return;
+        }
+
+        if matches!(key.code, KeyCode::Char('y')) {
+            self.copy_game_id();
+            return;
+        }
+
+        if matches!(key.code, KeyCode::Char('n')) {
+            self.switch_game();
+            return;
+        }
+
+        if matches!(key.code, KeyCode::Char('l')) {
+            self.park_and_leave(Screen::PvpLobby);
+            return;
+        }
+
+        if matches!(key.code, KeyCode::Char('m')) {
+            self.park_and_leave(Screen::Home);
+            return;
+        }
+
+        if matches!(key.code, KeyCode::Char('q')) {
+            self.request_quit();
+            return;
+        }
+
+        if matches!(key.code, KeyCode::Char('c')) {
+            self.toggle_confirm_moves();
+            return;
+        }
+
+        if matches!(key.code, KeyCode::Char('i')) {
+            self.show_full_id = !self.show_full_id;
+            return;
+        }
+
+        if matches!(key.code, KeyCode::Char('h')) {
+            self.show_enter_hint = !self.show_enter_hint;
+            return;
+        }
+
+        if matches!(key.code, KeyCode::Char('R')) {
+            self.request_resync();
+            return;
+        }
+
+        if matches!(key.code, KeyCode::Char('e')) {
+            self.export_game();
+            return;
+        }
+
+        if matches!(key.code, KeyCode::Char('a')) {
+            self.toggle_analysis();
+            return;
+        }
+
+        if self.handle_panel_key(key.code) {
+            return;
+        }
+        let board = self.pvp_game.as_ref().map(|game| game.board.clone());
+        self.update_board_cursor(key.code, board.as_deref());
+
+        let Some(game) = self.pvp_game.clone() else {
+            return;
+        };
+
+        if self.analysis && matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
+            self.show_toast("Analysis mode: moves are off, press a to play.");
+            return;
+        }
+
+        let player_symbol = self.player_symbol_for(&game);
+        let my_turn = player_symbol == game.current_turn;
+        let wants_move = matches!(key.code, KeyCode::Enter | KeyCode::Char(' '));
+
+        if wants_move && game.is_waiting_for_player() {
+            self.show_toast("Waiting for an opponent to join, moves are disabled.");
+            return;
+        }
+
+        if wants_move && player_symbol == "?" {
+            self.show_toast("You're not a player in this game, moves are disabled.");
+            return;
+        }
+
+        if wants_move && game.status == "IN_PROGRESS" && my_turn && self.move_confirmed() {
+            self.play_pvp_move(game, self.board_cursor).await;
+        }
+    }
+
+    async fn play_pvp_move(&mut self, game: ApiGame, index: usize) {
+        match self.api.play_move(&self.player_id, &game.id, index).await {
+            Ok(updated) => {
+                self.metrics.move_made(self.api.last_latency());
+                self.opponent_move = None;
+                self.record_moves(Some(&game), &updated);
+                self.track_turn(&updated);
+                if Self::is_game_finished(&updated) {
+                    self.open_game_over(&updated, "PvP");
+                }
+                self.pvp_game = Some(updated);
+            }
+            Err(err) => {
+                let action = PendingAction::Move {
+                    game_id: game.id,
+                    index,
+                };
+                self.action_failed(action, "Move failed", err);
+            }
+        }
+    }
+
+    async fn handle_game_over_key(&mut self, key: KeyEvent) {
+        // The recap wraps to at most one line per move, so that bounds the scrolling
+        let recap_lines = self.move_history.len();
+        if let Some(scroll) = nav::step(key.code, usize::from(self.recap_scroll), recap_lines) {
+            self.recap_scroll = scroll as u16;
+            return;
+        }
+
+        match key.code {
+            KeyCode::Char('q') => self.request_quit(),
+            KeyCode::Char('v') if !self.move_history.is_empty() => {
+                self.replay_step = self.move_history.len();
+                self.screen = Screen::Replay;
+            }
+            KeyCode::Char('e') => self.export_game(),
+            KeyCode::Char('y') => self.copy_final_board(),
+            KeyCode::Char('n') if !self.parked_games.is_empty() => {
+                self.cancel_rematch().await;
+                self.switch_game();
+            }
+            KeyCode::Char('r') => self.rematch().await,
+            KeyCode::Char('c') if self.rematch.is_some() => {
+                self.cancel_rematch().await;
+                self.show_toast("Rematch cancelled.");
+            }
+            KeyCode::Enter | KeyCode::Esc | KeyCode::Char('b') | KeyCode::Char('m') => {
+                self.leave_game_over().await;
+            }
+            _ => {}
+        }
+    }
+
+    // Game Over (and its replay) to Home. Don't leave the opponent waiting on a rematch request
+    // nobody will answer.
+    async fn leave_game_over(&mut self) {
+        self.cancel_rematch().await;
+        self.screen = Screen::Home;
+    }
+
+    // `r` on the game-over screen. PvP players go through the server's rematch handshake:
+    // the first `r` asks, the opponent's `r` accepts and both land in a new game with seats swapped.
+    // Solo games, and servers without the rematch endpoints, simply start a fresh game instead.
+    async fn rematch(&mut self) {
+        let Some(finished) = self.finished_game.clone() else {
+            return;
+        };
+        // A fresh game hosted by us (we are X) where whoever didn't start last time starts now.
+        // Servers that never sent `firstTurn` don't accept it either, so it is only sent when known.
+        let first_turn = finished
+            .first_turn
+            .is_some()
+            .then_some(if self.moved_first { "O" } else { "X" });
+        if finished.mode == MODE_PRACTICE {
+            self.start_practice_game();
+            return;
+        }
+        if finished.mode != "PVP" {
+            self.start_solo_game(first_turn).await;
+            return;
+        }
+        if self.player_symbol_for(&finished) == "?" {
+            return;
+        }
+
+        match self.rematch {
+            Some(Rematch::Requested) => {}
+            Some(Rematch::Offered) => {
+                match self.api.accept_rematch(&self.player_id, &finished.id).await {
+                    Ok(game) => self.enter_rematch(game),
+                    Err(err) => {
+                        self.rematch = None;
+                        self.report_api_error("Rematch failed", err);
+                    }
+                }
+            }
+            None => match self
+                .api
+                .request_rematch(&self.player_id, &finished.id)
+                .await
+            {
+                Ok(game) => self.update_rematch(game).await,
+                Err(ApiError::NotFound(_)) => {
+                    let name = finished.name.as_deref().unwrap_or("Rematch");
+                    self.create_game(name, None, first_turn).await;
+                }
+                Err(err) => self.report_api_error("Rematch request failed", err),
+            },
+        }
+    }
+
+    // Withdraws our request or declines the opponent's; errors don't matter since we move on anyway.
+    async fn cancel_rematch(&mut self) {
+        if self.rematch.take().is_none() {
+            return;
+        }
+        if let Some(game_id) = self.finished_game.as_ref().map(|game| game.id.clone()) {
+            let _ = self.api.cancel_rematch(&self.player_id, &game_id).await;
+        }
+    }
+
+    // While a finished PvP game is on screen, watch it for the opponent's side of the handshake.
+    async fn poll_rematch(&mut self) {
+        let Some(finished) = self.finished_game.as_ref() else {
+            return;
+        };
+        if finished.mode != "PVP" || self.player_symbol_for(finished) == "?" {
+            return;
+        }
+        // Awaited inline like every poll (see `poll_pvp_game`), so the answer is still current.
+        let game_id = finished.id.clone();
+        if let Ok(game) = self.api.get_game(&game_id).await {
+            self.update_rematch(game).await;
+        }
+    }
+
+    // Takes a fresh copy of the finished game and reacts to its rematch fields.
+    async fn update_rematch(&mut self, game: ApiGame) {
+        if let Some(rematch_id) = game.rematch_game_id.clone() {
+            match self.api.get_game(&rematch_id).await {
+                Ok(rematch) => self.enter_rematch(rematch),
+                Err(err) => self.report_api_error("Could not open the rematch", err),
+            }
+            return;
+        }
+
+        let mine = self.player_symbol_for(&game);
+        let next = match game.rematch_requested_by.as_deref() {
+            None => None,
+            Some(symbol) if symbol == mine => Some(Rematch::Requested),
+            Some(_) => Some(Rematch::Offered),
+        };
+        match (self.rematch, next) {
+            (None | Some(Rematch::Requested), Some(Rematch::Offered)) => {
+                self.show_toast("Your opponent wants a rematch! Press r to accept.");
+                self.ring_bell();
+            }
+            (Some(Rematch::Requested), None) => {
+                self.show_toast("Your opponent declined the rematch.")
+            }
+            _ => {}
+        }
+        self.rematch = next;
+        self.finished_game = Some(game);
+    }
+
+    fn enter_rematch(&mut self, game: ApiGame) {
+        self.rematch = None;
+        self.remember_game(&game.id);
+        let you = self.player_symbol_for(&game);
+        self.show_toast(&format!("Rematch started! You are {you} this time."));
+        self.enter_pvp_game(game);
+    }
+
+    // Steps run from 0 (empty board) to one per move, so there is one more step than moves.
+    async fn handle_replay_key(&mut self, key: KeyEvent) {
+        let code = match key.code {
+            KeyCode::Left => KeyCode::Up,
+            KeyCode::Right => KeyCode::Down,
+            code => code,
+        };
+        if let Some(step) = nav::step(code, self.replay_step, self.move_history.len() + 1) {
+            self.replay_step = step;
+            return;
+        }
+
+        match code {
+            KeyCode::Char('q') => self.request_quit(),
+            KeyCode::Char('m') => self.leave_game_over().await,
+            code if nav::is_back(code) => self.screen = Screen::GameOver,
+            _ => {}
+        }
+    }
+
+    async fn handle_info_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('r') if self.failed_action.is_some() => self.retry_failed_action().await,
+            KeyCode::Char('x') if self.stranded_game.is_some() => self.concede_and_return().await,
+            KeyCode::Char('l') if self.vanished_game.is_some() => {
+                self.vanished_game = None;
+                self.open_pvp_lobby().await;
+            }
+            KeyCode::Enter | KeyCode::Esc | KeyCode::Char('b') | KeyCode::Char('m') => {
+                self.failed_action = None;
+                self.vanished_game = None;
+                self.screen = Screen::Home;
+            }
+            code => {
+                // Scroll offsets 0..=max are the "rows" of this list
+                let rows = usize::from(self.info_max_scroll()) + 1;
+                if let Some(scroll) = nav::step(code, usize::from(self.info_scroll), rows) {
+                    self.info_scroll = scroll as u16;
+                }
+            }
+        }
+    }
+
+    // The Info message as shown: a vanished game's id is masked in privacy mode.
+    fn info_text(&self) -> Cow<'_, str> {
+        match &self.vanished_game {
+            Some(game_id) if self.privacy => {
+                Cow::Owned(self.info_message.replace(game_id.as_str(), ui::HIDDEN_ID))
+            }
+            _ => Cow::Borrowed(&self.info_message),
+        }
+    }
+
+    // Scrolling stops once the last line is at the bottom, which depends on the terminal size.
+    fn info_max_scroll(&self) -> u16 {
+        let (width, height) = crossterm::terminal::size().unwrap_or((80, 24));
+        ui::info_max_scroll(
+            &self.palette(),
+            Rect::new(0, 0, width, height),
+            &self.info_text(),
+            self.failed_action.is_some(),
+            self.stranded_game.is_some(),
+        )
+    }
+
+    // `r` on the error screen: sends the failed request again, as if the key that caused it
+    // was pressed once more. Another transient failure lands back here with the retry offered again.
+    async fn retry_failed_action(&mut self) {
+        let Some(action) = self.failed_action.take() else {
+            return;
+        };
+
+        match action {
+            PendingAction::StartSolo { first_turn } => {
+                self.start_solo_game(first_turn.as_deref()).await
+            }
+            PendingAction::OpenLobby => self.open_pvp_lobby().await,
+            PendingAction::Join { game_id, password } => self.join_game(&game_id, password).await,
+            PendingAction::Create {
+                name,
+                password,
+                first_turn,
+            } => {
+                self.create_game(&name, password, first_turn.as_deref())
+                    .await
+            }
+            PendingAction::Move { game_id, index } => {
+                // Back to the board first, so a successful move is seen where it was played.
+                if let Some(game) = self.solo_game.clone().filter(|game| game.id == game_id) {
+                    self.screen = Screen::SoloGame;
+                    self.play_solo_move(game, index).await;
+                } else if let Some(game) = self.pvp_game.clone().filter(|game| game.id == game_id) {
+                    self.screen = Screen::PvpGame;
+                    self.play_pvp_move(game, index).await;
+                }
+            }
+        }
+    }
+
+    // Resigns the PvP game an error interrupted, so the opponent isn't left waiting, then goes Home.
+    // If resigning fails too, the new error replaces this one and the offer stays.
+    async fn concede_and_return(&mut self) {
+        let Some(game_id) = self.stranded_game.clone() else {
+            return;
+        };
+
+        match self.api.resign_game(&self.player_id, &game_id).await {
+            Ok(_) => {
+                self.count_resignation(&game_id);
+                self.stranded_game = None;
+                self.pvp_game = None;
+                self.screen = Screen::Home;
+                self.show_toast("You resigned the game.");
+            }
+            Err(err) => self.report_api_error("Resign failed", err),
+        }
+    }
+
+    // Tab moves focus between the board and the move history (Esc also gives it back to the board);
+    // while the history has focus, navigation keys scroll it instead of moving the cursor.
+    // Returns true if the key was used.
+    fn handle_panel_key(&mut self, key: KeyCode) -> bool {
+        if key == KeyCode::Esc && self.focused_panel == GamePanel::Moves {
+            self.focused_panel = GamePanel::Board;
+            return true;
+        }
+        if key == KeyCode::Tab {
+            self.focused_panel = match self.focused_panel {
+                GamePanel::Board => GamePanel::Moves,
+                GamePanel::Moves => GamePanel::Board,
+            };
+            return true;
+        }
+        if self.focused_panel != GamePanel::Moves {
+            return false;
+        }
+
+        let last = self.move_history.len().saturating_sub(1);
+        let current = self.history_scroll.unwrap_or(last);
+        let Some(next) = nav::step(key, current, self.move_history.len()) else {
+            return false;
+        };
+        // Reaching the newest move sticks to it again, so later moves keep scrolling into view.
+        self.history_scroll = (next < last).then_some(next);
+        true
+    }
+
+    // Starts an empty history for a new game, following the newest move with the board focused.
+    fn reset_move_history(&mut self) {
+        self.move_history.clear();
+        self.history_scroll = None;
+        self.focused_panel = GamePanel::Board;
+    }
+
+    // `board` is the game on screen, so digit shortcuts can refuse filled cells.
+    fn update_board_cursor(&mut self, key: KeyCode, board: Option<&[Option<String>]>) {
+        // Coordinates: a column letter (A..C, uppercase since `b`/`c` are shortcuts) jumps to that
+        // column, and a row digit (1..3) right after it completes the cell, e.g. `B2` is the center.
+        let coordinate = match (self.pending_column.take(), key) {
+            (Some(col), KeyCode::Char(row @ '1'..='3')) => {
+                Some((row as usize - '1' as usize) * 3 + col)
+            }
+            (_, KeyCode::Char(col @ 'A'..='C')) => {
+                let col = col as usize - 'A' as usize;
+                self.pending_column = Some(col);
+                Some(self.board_cursor / 3 * 3 + col)
+            }
+            _ => None,
+        };
+        let key = match key {
+            KeyCode::Char(ch) if self.config.numpad => KeyCode::Char(numpad_to_grid_digit(ch)),
+            other => other,
+        };
+        // A digit naming a filled cell leaves the cursor where it is; arrows still go anywhere.
+        if let (None, KeyCode::Char('1'..='9')) = (coordinate, key) {
+            let target = next_cursor(self.board_cursor, key);
+            if board.is_some_and(|board| board.get(target).is_some_and(Option::is_some)) {
+                self.show_toast("That cell is already taken.");
+                return;
+            }
+        }
+        let next = coordinate.unwrap_or_else(|| next_cursor(self.board_cursor, key));
+        if next != self.board_cursor {
+            // Moving away cancels a pending two-step move.
+            self.pending_cell = None;
+        }
+        self.board_cursor = next;
+    }
+
+    // Reloads open and live games. Servers without the live endpoint just show no live games.
+    // Refetches as many open games as were loaded, so "Load more" pages don't vanish on refresh.
+    async fn refresh_lobby(&mut self) -> Result<(), ApiError> {
+        let limit = self.lobby_open_loaded.max(self.config.lobby_page());
+        let page = self.api.list_open_pvp_games(0, limit).await?;
+        let live = self.api.list_live_pvp_games().await.unwrap_or_default();
+        self.note_open_games_page(0, page.games.len(), limit, page.total);
+        self.set_lobby_games(page.games, live);
+        if self.lobby_tab == LobbyTab::Mine {
+            self.refresh_my_games().await?;
+        }
+        self.lobby_fetched_at = Some(Instant::now());
+        self.lobby_refresh_failed = false;
+        Ok(())
+    }
+
+    // My unfinished games. Servers without the endpoint (404) get the list pieced together from
+    // what this client knows: my waiting games in the lobby, parked games and the one on screen.
+    async fn refresh_my_games(&mut self) -> Result<(), ApiError> {
+        let games = match self.api.list_my_pvp_games(&self.player_id).await {
+            Ok(games) => games,
+            Err(ApiError::NotFound(_)) => {
+                let mut games = self.own_games.clone();
+                let known = self
+                    .parked_games
+                    .iter()
+                    .map(|parked| &parked.game)
+                    .chain(self.pvp_game.as_ref());
+                for game in known {
+                    let running = game.status == "IN_PROGRESS" || game.is_waiting_for_player();
+                    let mine = self.player_symbol_for(game) != "?";
+                    if running && mine && !games.iter().any(|known| known.id == game.id) {
+                        games.push(game.clone());
+                    }
+                }
+                games
+            }
+            Err(err) => return Err(err),
+        };
+        self.my_games = games;
+        self.my_games_index = self
+            .my_games_index
+            .min(self.my_games.len().saturating_sub(1));
+        Ok(())
+    }
+
+    // Appends the next page of open games; ones already listed (the list moved meanwhile) are skipped.
+    async fn load_more_lobby_games(&mut self) -> Result<(), ApiError> {
+        let limit = self.config.lobby_page();
+        let page = self
+            .api
+            .list_open_pvp_games(self.lobby_open_loaded, limit)
+            .await?;
+        self.note_open_games_page(self.lobby_open_loaded, page.games.len(), limit, page.total);
+
+        let mut games: Vec<ApiGame> = self
+            .own_games
+            .iter()
+            .chain(&self.joinable_as_sent)
+            .cloned()
+            .collect();
+        let known = games.len();
+        for game in page.games {
+            if !games.iter().any(|known| known.id == game.id) {
+                games.push(game);
+            }
+        }
+        // The highlight moves from the "Load more" row to the first game it brought in.
+        let first_new = games.get(known).map(|game| game.id.clone());
+        let live = self.live_games.clone();
+        self.set_lobby_games(games, live);
+        self.select_lobby_game(first_new);
+        Ok(())
+    }
+
+    // Without a total, a full page means there may be more behind it.
+    fn note_open_games_page(
+        &mut self,
+        offset: usize,
+        received: usize,
+        limit: usize,
+        total: Option<usize>,
+    ) {
+        self.lobby_open_loaded = offset + received;
+        self.lobby_open_total = total;
+        self.lobby_has_more = match total {
+            Some(total) => self.lobby_open_loaded < total,
+            None => received == limit,
+        };
+    }
+
+    // Replaces the lobby list while keeping the highlighted game selected (matched by id).
+    // Falls back to clamping the index when that game disappeared.
+    // Games I host go to `own_games`; only games with a free seat and another host are joinable.
+    fn set_lobby_games(&mut self, games: Vec<ApiGame>, live: Vec<ApiGame>) {
+        let selected_id = self.selected_lobby_game_id();
+
+        let (own, others): (Vec<_>, Vec<_>) = games
+            .into_iter()
+            .filter(|game| game.guest_player_id.is_none())
+            .partition(|game| game.host_player_id == self.player_id);
+        self.own_games = own;
+        self.joinable_as_sent = others;
+        self.sort_joinable_games();
+        self.live_games = live;
+
+        self.select_lobby_game(selected_id);
+    }
+
+    fn sort_joinable_games(&mut self) {
+        self.pvp_games = self.joinable_as_sent.clone();
+        self.lobby_sort.sort(&mut self.pvp_games);
+    }
+
+    fn selected_lobby_game_id(&self) -> Option<String> {
+        self.lobby_games()
+            .nth(self.pvp_selected_index)
+            .map(|game| game.id.clone())
+    }
+
+    // Moves the highlight to the game with `id` wherever the lists put it now.
+    fn select_lobby_game(&mut self, id: Option<String>) {
+        let count = self.lobby_games().count();
+        self.pvp_selected_index = id
+            .and_then(|id| self.lobby_games().position(|game| game.id == id))
+            .unwrap_or_else(|| self.pvp_selected_index.min(count.saturating_sub(1)));
+    }
+
+    // Lobby rows in display order: my waiting games, joinable ones, then live ones.
+    fn lobby_games(&self) -> impl Iterator<Item = &ApiGame> {
+        self.own_games
+            .iter()
+            .chain(&self.pvp_games)
+            .chain(&self.live_games)
+    }
+
+    // Puts a PvP game on screen. A game of mine that is still running there is parked
+    // rather than dropped, and a parked game coming back gets its move history back.
+    fn enter_pvp_game(&mut self, game: ApiGame) {
+        if self
+            .pvp_game
+            .as_ref()
+            .is_some_and(|current| current.id != game.id)
+        {
+            self.park_current_game();
+        }
+        let history = self
+            .parked_games
+            .iter()
+            .position(|parked| parked.game.id == game.id)
+            .map(|idx| self.parked_games.remove(idx).move_history);
+
+        // Whatever brought me here (a resumed id, a join reply without my seat), a game I have no
+        // seat in is only watched. `spectate` replaces this with its own note.
+        if self.player_symbol_for(&game) == "?" {
+            self.show_toast("You're not a player in this game. Watching it instead.");
+        }
+
+        if self.turn_notice.as_deref() == Some(game.id.as_str()) {
+            self.turn_notice = None;
+        }
+        self.track_turn(&game);
+        self.pvp_game = Some(game);
+        self.board_cursor = 0;
+        self.opponent_move = None;
+        // Analysis mode belongs to the board it was switched on for
+        self.analysis = false;
+        self.reset_move_history();
+        if let Some(history) = history {
+            self.move_history = history;
+        }
+        self.screen = Screen::PvpGame;
+        self.subscribe_to_game();
+    }
+
+    // Seated players of a running game follow it over the event stream, if the server has one.
+    // Spectators keep polling `/watch`, which is also what keeps them listed as watching.
+    fn subscribe_to_game(&mut self) {
+        self.resubscribe_at = None;
+        if self.screen != Screen::PvpGame {
+            return;
+        }
+        self.game_events = self
+            .pvp_game
+            .as_ref()
+            .filter(|game| !Self::is_game_finished(game) && self.player_symbol_for(game) != "?")
+            .and_then(|game| self.api.subscribe_game_events(&game.id));
+    }
+
+    // Moves the on-screen PvP game to the parked list if I play in it and it isn't over yet, and
+    // says whether it did. Anything else (a finished game, one I only watch) is simply closed.
+    fn park_current_game(&mut self) -> bool {
+        let Some(game) = self.pvp_game.take() else {
+            return false;
+        };
+        let running = game.status == "IN_PROGRESS" || game.is_waiting_for_player();
+        if !running || self.player_symbol_for(&game) == "?" {
+            return false;
+        }
+        self.parked_games.push(ParkedGame {
+            game,
+            move_history: std::mem::take(&mut self.move_history),
+            polled_at: Instant::now(),
+        });
+        true
+    }
+
+    // `n`: brings the longest-parked game on screen; the current one goes to the back of the line.
+    fn switch_game(&mut self) {
+        let Some(next) = self.parked_games.first().map(|parked| parked.game.clone()) else {
+            self.show_toast("No other games running.");
+            return;
+        };
+        self.remember_game(&next.id);
+        self.enter_pvp_game(next);
+    }
+
+    // `l` (to the lobby) and `m` (to Home): leave without resigning, so another game can be
+    // hosted or joined.
+    fn park_and_leave(&mut self, screen: Screen) {
+        let seated = self
+            .pvp_game
+            .as_ref()
+            .is_some_and(|game| self.player_symbol_for(game) != "?");
+        if !seated {
+            self.screen = screen;
+            return;
+        }
+        // A finished game isn't parked: it has nothing to come back to.
+        if self.park_current_game() {
+            self.show_toast(if screen == Screen::PvpLobby {
+                "Game parked: it keeps running, n switches back."
+            } else {
+                "Game parked: it keeps running, n in the lobby switches back."
+            });
+        }
+        self.screen = screen;
+    }
+
+    // `q` on a game screen, Home, the lobby and Game Over. Quitting mid-game is easy to do by
+    // accident, so it asks first, also while a parked game is still running.
+    fn request_quit(&mut self) {
+        if self.help_state().playing || self.has_running_parked_game() {
+            self.confirm = Some(Confirm::QuitApp);
+        } else {
+            self.should_quit = true;
+        }
+    }
+
+    fn has_running_parked_game(&self) -> bool {
+        self.parked_games
+            .iter()
+            .any(|parked| !Self::is_game_finished(&parked.game))
+    }
+
+    // Parked games poll on their own timers, whatever the screen. Errors are ignored like
+    // the main poll's; the next round tries again.
+    async fn poll_parked_games(&mut self) {
+        let due: Vec<String> = self
+            .parked_games
+            .iter()
+            .filter(|parked| parked.polled_at.elapsed() >= PARKED_POLL_INTERVAL)
+            .filter(|parked| !Self::is_game_finished(&parked.game))
+            .map(|parked| parked.game.id.clone())
+            .collect();
+
+        for game_id in due {
+            let polled = self.api.get_game(&game_id).await;
+            self.dirty = true;
+            let Some(idx) = self
+                .parked_games
+                .iter()
+                .position(|parked| parked.game.id == game_id)
+            else {
+                continue;
+            };
+            self.parked_games[idx].polled_at = Instant::now();
+            let game = match polled {
+                Ok(game) if game.is_older_than(&self.parked_games[idx].game) => continue,
+                Ok(game) => game,
+                Err(ApiError::NotFound(_)) => {
+                    let gone = self.parked_games.remove(idx).game;
+                    let name = gone.name.unwrap_or_else(|| ui::short_id(&gone.id));
+                    self.show_toast(&format!("{name} no longer exists on the server."));
+                    continue;
+                }
+                Err(_) => continue,
+            };
+
+            let me = self.player_symbol_for(&game);
+            let parked = &mut self.parked_games[idx];
+            let name = game.name.clone().unwrap_or_else(|| ui::short_id(&game.id));
+            let notice = if Self::is_game_finished(&game) {
+                Some(format!("'{name}' is over, Ctrl+N shows the result."))
+            } else if game.status == "IN_PROGRESS"
+                && game.current_turn == me
+                && (parked.game.current_turn != me || parked.game.status != "IN_PROGRESS")
+            {
+                Some(format!("Your turn in '{name}' (Ctrl+N jumps there)."))
+            } else {
+                None
+            };
+            parked
+                .move_history
+                .extend(new_moves(&parked.game.board, &game.board));
+            parked.game = game;
+
+            if let Some(notice) = notice {
+                self.turn_notice = Some(game_id);
+                self.show_toast(&notice);
+                self.alert(SoundEvent::YourTurn);
+            }
+        }
+    }
+
+    // Ctrl+N: straight into the parked game the last notice was about.
+    async fn jump_to_noticed_game(&mut self) {
+        let Some(game) = self
+            .turn_notice
+            .take()
+            .and_then(|id| self.parked_games.iter().find(|parked| parked.game.id == id))
+            .map(|parked| parked.game.clone())
+        else {
+            self.show_toast("No game is waiting for you.");
+            return;
+        };
+        if self.screen == Screen::GameOver {
+            self.cancel_rematch().await;
+        }
+        self.remember_game(&game.id);
+        self.enter_pvp_game(game);
+    }
+
+    // With several games going, the board's title names the one on screen.
+    fn pvp_title(&self) -> String {
+        match self.pvp_game.as_ref().and_then(|game| game.name.as_deref()) {
+            Some(name) if !self.parked_games.is_empty() => format!("PvP Mode - {name}"),
+            _ => "PvP Mode".to_string(),
+        }
+    }
+
+    // Parked games listed on the board screen, `None` unless I'm playing the game on screen.
+    fn other_games(&self) -> Option<Vec<OtherGame>> {
+        let game = self.pvp_game.as_ref()?;
+        if self.player_symbol_for(game) == "?" {
+            return None;
+        }
+        Some(
+            self.parked_games
+                .iter()
+                .map(|parked| OtherGame {
+                    name: parked.game.name.clone().unwrap_or_else(|| {
+                        if self.privacy {
+                            ui::HIDDEN_ID.to_string()
+                        } else {
+                            ui::short_id(&parked.game.id)
+                        }
+                    }),
+                    needs_me: Self::is_game_finished(&parked.game)
+                        || (parked.game.status == "IN_PROGRESS"
+                            && parked.game.current_turn == self.player_symbol_for(&parked.game)),
+                })
+                .collect(),
+        )
+    }
+
+    // Rows of the lobby's "My games" tab.
+    fn my_game_rows(&self) -> Vec<MyGameRow> {
+        self.my_games
+            .iter()
+            .map(|game| {
+                let you = self.player_symbol_for(game);
+                let opponent = if you == "O" {
+                    game.host_name.as_deref().unwrap_or("Host")
+                } else if game.guest_player_id.is_some() {
+                    game.guest_name.as_deref().unwrap_or("Guest")
+                } else {
+                    "(open seat)"
+                };
+                MyGameRow {
+                    name: game.name.clone().unwrap_or_else(|| {
+                        if self.privacy {
+                            ui::HIDDEN_ID.to_string()
+                        } else {
+                            ui::short_id(&game.id)
+                        }
+                    }),
+                    opponent: opponent.to_string(),
+                    state: if game.is_waiting_for_player() {
+                        "waiting for an opponent"
+                    } else if game.current_turn == you {
+                        "your turn"
+                    } else {
+                        "their turn"
+                    },
+                }
+            })
+            .collect()
+    }
+
+    // Opens someone else's game read-only; polling keeps the board current.
+    fn spectate(&mut self, game: ApiGame) {
+        self.enter_pvp_game(game);
+        self.show_toast("Spectating: moves are disabled.");
+    }
+
+    async fn cancel_selected_game(&mut self) {
+        let Some(game_id) = self
+            .own_games
+            .get(self.pvp_selected_index)
+            .map(|game| game.id.clone())
+        else {
+            self.show_toast("Only your own games can be cancelled.");
+            return;
+        };
+        self.cancel_game(&game_id).await;
+    }
+
+    async fn cancel_game(&mut self, game_id: &str) {
+        match self.api.cancel_pvp_game(&self.player_id, game_id).await {
+            Ok(_) => {
+                self.show_toast("Game cancelled.");
+                let _ = self.refresh_lobby().await;
+            }
+            Err(err) => self.report_api_error("Cancel failed", err),
+        }
+    }
+
+    fn display_name(&self) -> Option<&str> {
+        Some(self.settings.display_name.as_str()).filter(|name| !name.is_empty())
+    }
+
+    // Dumps the game on screen (or the one just finished) plus the local move history to a JSON file.
+    fn export_game(&mut self) {
+        let game = match self.screen {
+            Screen::SoloGame => self.solo_game.as_ref(),
+            Screen::PvpGame => self.pvp_game.as_ref(),
+            _ => self.finished_game.as_ref(),
+        };
+        let Some(game) = game else {
+            self.show_toast("No game to export.");
+            return;
+        };
+
+        let result = match &self.config.export_dir {
+            Some(dir) => export::export_game(dir, game, &self.move_history),
+            None => export::export_game(&self.config_dir.join("exports"), game, &self.move_history),
+        };
+        match result {
+            Ok(path) => self.show_toast(&format!("Game exported to {}", path.display())),
+            Err(err) => self.show_error(format!("Could not export game: {err:#}")),
+        }
+    }
+
+    fn save_settings(&mut self) {
+        if let Err(err) = self.settings.save(&self.config_dir) {
+            self.show_toast(&format!("Could not save settings: {err}"));
+        }
+    }
+
+    fn toggle_confirm_moves(&mut self) {
+        self.confirm_moves = !self.confirm_moves;
+        self.pending_cell = None;
+        self.show_toast(if self.confirm_moves {
+            "Move confirmation on: press Enter twice to play."
+        } else {
+            "Move confirmation off."
+        });
+    }
+
+    // In two-step mode the first Enter only marks the cell, a second Enter on it commits.
+    fn move_confirmed(&mut self) -> bool {
+        if !self.confirm_moves {
+            return true;
+        }
+
+        if self.pending_cell == Some(self.board_cursor) {
+            self.pending_cell = None;
+            true
+        } else {
+            self.pending_cell = Some(self.board_cursor);
+            false
+        }
+    }
+
+    fn player_symbol_for(&self, game: &ApiGame) -> String {
+        if game.host_player_id == self.player_id {
+            "X".to_string()
+        } else if game.guest_player_id.as_deref() == Some(self.player_id.as_str()) {
+            "O".to_string()
+        } else {
+            "?".to_string()
+        }
+    }
+
+    fn draw(&self, frame: &mut Frame<'_>) {
+        let palette = self.palette();
+        let caret_on = self.caret_on();
+        // Determine the current screen and call the appropriate UI rendering function.
+        match self.screen {
+            // Render the first-run name setup screen with the name typed so far.
+            Screen::Setup => ui::draw_setup(frame, &palette, &self.text_input, caret_on),
+            // Render the Home screen with the selected menu index highlighted.
+            Screen::Home => match self.screensaver_elapsed() {
+                Some(elapsed) => ui::draw_screensaver(frame, &palette, elapsed, self.board_marks()),
+                None => ui::draw_home(
+                    frame,
+                    &palette,
+                    self.home_index,
+                    self.settings.quick_keys_home,
+                    self.server_online,
+                ),
+            },
+            // Render the Solo Game screen with game details, mode label, board view state (cursor, pending move), player's symbol and move history.
+            Screen::SoloGame => ui::draw_game(
+                frame,
+                &palette,
+                self.solo_game.as_ref(),
+                if self.practicing() {
+                    "Practice Mode"
+                } else {
+                    "Solo Mode"
+                },
+                &self.board_view(),
+                self.solo_player_symbol(),
+                &self.move_history,
+            ),
+            // Render the PvP Lobby screen with its sections (own, joinable, live), selection, join password, and editing state.
+            Screen::PvpLobby => ui::draw_pvp_lobby(
+                frame,
+                &palette,
+                &LobbyView {
+                    own_games: &self.own_games,
+                    joinable_games: &self.pvp_games,
+                    live_games: &self.live_games,
+                    selected_index: self.pvp_selected_index,
+                    show_previews: self.show_board_previews,
+                    sort: self.lobby_sort.label(),
+                    open_loaded: self.lobby_open_loaded,
+                    open_total: self.lobby_open_total,
+                    has_more: self.lobby_has_more,
+                    stale_for: self
+                        .lobby_fetched_at
+                        .filter(|_| self.lobby_refresh_failed)
+                        .map(|fetched_at| fetched_at.elapsed()),
+                    tab: self.lobby_tab,
+                    my_games: &self.my_game_rows(),
+                    my_selected_index: self.my_games_index,
+                    privacy: self.privacy,
+                },
+                &self.join_password,
+                self.editing_join_password,
+                caret_on,
+            ),
+            // Render the PvP Create screen with the current input values for game name, password, and the focused field.
+            Screen::PvpCreate => ui::draw_pvp_create(
+                frame,
+                &palette,
+                &self.create_name,
+                &self.create_password,
+                self.create_field_index,
+                caret_on,
+                self.privacy,
+            ),
+            // Render the PvP Game screen with game details, mode label, board view state (cursor, pending move), player's symbol and move history.
+            Screen::PvpGame => ui::draw_game(
+                frame,
+                &palette,
+                self.pvp_game.as_ref(),
+                &self.pvp_title(),
+                &self.board_view(),
+                self.player_symbol_for_opt(self.pvp_game.as_ref()),
+                &self.move_history,
+            ),
+            // Render the Game Over screen with the game's result message.
+            Screen::GameOver => ui::draw_game_over(
+                frame,
+                &palette,
+                &GameOverView {
+                    message: &self.game_over_message,
+                    outcome: self.game_outcome,
+                    controls: help::footer(help::GAME_OVER_KEYS, &self.help_state()),
+                    rematch_hint: self.rematch_hint(),
+                    autoreturn_in: self.autoreturn_secs_left(),
+                    seed: self
+                        .finished_game
+                        .as_ref()
+                        .filter(|game| game.mode == MODE_LOCAL_SOLO)
+                        .map(|_| self.ai_seed),
+                    moves: &self.move_history,
+                    winner: self
+                        .finished_game
+                        .as_ref()
+                        .and_then(|game| game.winner.as_deref()),
+                    recap_scroll: self.recap_scroll,
+                    game_id: self.finished_game.as_ref().map(|game| game.id.as_str()),
+                    privacy: self.privacy,
+                },
+            ),
+            // Render the Replay screen with the board rebuilt up to the selected move.
+            Screen::Replay => ui::draw_replay(
+                frame,
+                &palette,
+                &self.move_history,
+                self.replay_step,
+                &self.board_marks(),
+                self.finished_game.as_ref().and_then(ApiGame::winning_line),
+            ),
+            // Render the Settings screen with current values, the row being edited and server health.
+            Screen::Settings => ui::draw_settings(
+                frame,
+                &palette,
+                &SettingsView {
+                    values: &self.settings_values(),
+                    selected_index: self.settings_index,
+                    editing: self.editing_setting.then_some(&self.text_input),
+                    server_status: self.server_status_text(),
+                    recent_servers: &self.settings.recent_servers,
+                    recent_index: self.recent_server_index,
+                },
+                caret_on,
+            ),
+            // Render the Info screen with the provided informational message.
+            Screen::Info => ui::draw_info(
+                frame,
+                &palette,
+                &self.info_text(),
+                self.failed_action.is_some(),
+                self.stranded_game.is_some(),
+                self.info_scroll,
+            ),
+        }
+
+        if self.game_over_flashing() {
+            ui::draw_flash(frame);
+        }
+
+        ui::draw_status_bar(frame, &palette, self.api.last_latency());
+
+        if let Some(input) = &self.command_input {
+            ui::draw_command_palette(frame, &palette, input, caret_on);
+        }
+
+        match self.confirm {
+            Some(Confirm::LeaveGame) => ui::draw_popup(
+                frame,
+                &palette,
+                "Leave game?",
+                "The game is still running. Leaving resigns it and your opponent wins.\n\ny = resign and leave, any other key = stay",
+            ),
+            Some(Confirm::ClaimWin) => ui::draw_popup(
+                frame,
+                &palette,
+                "Opponent inactive",
+                "Opponent appears to have left - claim win?\n\ny = claim the win, any other key = keep waiting",
+            ),
+            Some(Confirm::ResumeGame) => ui::draw_popup(
+                frame,
+                &palette,
+                "Resume last game?",
+                "The game you were in when the app closed is still running.\n\ny = resume it, n = start fresh",
+            ),
+            Some(Confirm::QuitApp) => ui::draw_popup(
+                frame,
+                &palette,
+                "Quit?",
+                if self.help_state().playing {
+                    "A game is still running.\n\ny = quit the app, any other key = keep playing (m goes to the main menu instead)"
+                } else {
+                    "A parked game is still running.\n\ny = quit the app, any other key = stay"
+                },
+            ),
+            None => {}
+        }
+
+        if let Some(body) = self
+            .last_raw_response
+            .as_deref()
+            .filter(|_| self.showing_raw_response)
+        {
+            ui::draw_popup(
+                frame,
+                &palette,
+                "Raw server response (any key to close)",
+                body,
+            );
+        }
+
+        if let Some(invite) = &self.invite_text {
+            ui::draw_popup(frame, &palette, "Share invite (any key to close)", invite);
+        }
+
+        if let Some((message, shown_at)) = &self.toast {
+            if shown_at.elapsed() < TOAST_DURATION {
+                ui::draw_toast(frame, &palette, message);
+            }
+        }
+    }
+
+    fn palette(&self) -> Palette {
+        let palette = if self.accessible() {
+            Theme::accessible_palette()
+        } else {
+            self.settings.theme.palette(self.settings.ascii_mode)
+        };
+        palette.with_size(self.settings.layout)
+    }
+
+    // `--accessible` turns the mode on for this run without touching the saved setting.
+    fn accessible(&self) -> bool {
+        self.config.accessible || self.settings.accessible
+    }
+
+    // Formatted values for the rows of `SettingRow::ALL`.
+    fn settings_values(&self) -> Vec<String> {
+        SettingRow::ALL
+            .iter()
+            .map(|row| self.setting_value(*row))
+            .collect()
+    }
+
+    fn setting_value(&self, row: SettingRow) -> String {
+        let on_off = |on: bool| if on { "on" } else { "off" }.to_string();
+        match row {
+            SettingRow::ServerUrl => self.base_url.clone(),
+            SettingRow::Theme => self.settings.theme.label().to_string(),
+            SettingRow::Colors => if self.settings.ascii_mode {
+                "off (ASCII)"
+            } else {
+                "on"
+            }
+            .to_string(),
+            SettingRow::DisplayName => self.settings.display_name.clone(),
+            SettingRow::HighlightOwnMarks => on_off(self.settings.highlight_own_marks),
+            SettingRow::BoardSymbols => self.settings.board_symbols.label().to_string(),
+            SettingRow::CustomSymbols => {
+                if self.settings.custom_symbols.is_empty() {
+                    "(not set)".to_string()
+                } else {
+                    self.settings.custom_symbols.clone()
+                }
+            }
+            SettingRow::Accessibility => match (self.settings.accessible, self.config.accessible) {
+                (_, true) => "on (--accessible)",
+                (true, false) => "on",
+                (false, false) => "off",
+            }
+            .to_string(),
+            SettingRow::Coordinates => on_off(self.settings.show_coordinates),
+            SettingRow::QuickKeysHome => on_off(self.settings.quick_keys_home),
+            SettingRow::SoloGames => if self.settings.local_solo {
+                "local, results uploaded"
+            } else {
+                "on the server"
+            }
+            .to_string(),
+            SettingRow::Layout => self.settings.layout.label().to_string(),
+            SettingRow::AutoAdvance => on_off(self.settings.auto_advance),
+            SettingRow::Sound(event) => match (
+                self.settings.muted_sounds.contains(&event),
+                self.config.sound,
+            ) {
+                (true, _) => "off",
+                (false, true) => "on",
+                (false, false) => "on (needs --sound)",
+            }
+            .to_string(),
+        }
+    }
+
+    fn server_status_text(&self) -> &'static str {
+        match self.server_online {
+            None => "Not checked yet",
+            Some(true) => "Online",
+            Some(false) => "Not reachable (is the backend running at this URL?)",
+        }
+    }
+
+    fn rematch_hint(&self) -> &'static str {
+        let Some(finished) = self.finished_game.as_ref() else {
+            return "";
+        };
+        if finished.mode != "PVP" {
+            return "Press r to play again.";
+        }
+        if self.player_symbol_for(finished) == "?" {
+            return "";
+        }
+        match self.rematch {
+            None => "Press r to ask for a rematch.",
+            Some(Rematch::Requested) => "Rematch requested - waiting for opponent (c to cancel).",
+            Some(Rematch::Offered) => "Your opponent wants a rematch: r to accept, c to decline.",
+        }
+    }
+
+    // `--autoreturn`: leave the Game Over screen once its countdown runs out, as if `b` was pressed.
+    async fn autoreturn_if_due(&mut self) {
+        if self.screen != Screen::GameOver
+            || self.autoreturn_at.is_none_or(|at| Instant::now() < at)
+        {
+            return;
+        }
+        self.autoreturn_at = None;
+        self.cancel_rematch().await;
+        self.screen = Screen::Home;
+        self.dirty = true;
+    }
+
+    // Whole seconds left on the auto-return countdown, rounded up so it never shows 0.
+    fn autoreturn_secs_left(&self) -> Option<u64> {
+        let left = self
+            .autoreturn_at?
+            .saturating_duration_since(Instant::now());
+        Some(left.as_millis().div_ceil(1000) as u64)
+    }
+
+    // `--screensaver`: how long the Home screen's idle animation has been playing, if it is.
+    fn screensaver_elapsed(&self) -> Option<Duration> {
+        let idle_after = Duration::from_secs(self.config.screensaver_secs?);
+        let on_home = self.screen == Screen::Home && self.command_input.is_none();
+        let idle = self.last_key_at.elapsed();
+        (on_home && idle >= idle_after).then(|| idle - idle_after)
+    }
+
+    fn game_over_flashing(&self) -> bool {
+        self.screen == Screen::GameOver
+            && self
+                .game_over_flash_at
+                .is_some_and(|flashed_at| flashed_at.elapsed() < GAME_OVER_FLASH)
+    }
+
+    fn board_view(&self) -> BoardView {
+        BoardView {
+            cursor: Some(self.board_cursor),
+            pending_cell: self.pending_cell,
+            highlighted_cell: self
+                .opponent_move
+                .filter(|(_, played_at)| played_at.elapsed() < OPPONENT_MOVE_HIGHLIGHT)
+                .map(|(cell, _)| cell),
+            numpad: self.config.numpad,
+            idle_warning: self
+                .opponent_idle()
+                .filter(|idle| *idle >= IDLE_WARNING_AFTER),
+            focused_panel: self.focused_panel,
+            history_scroll: self.history_scroll,
+            show_full_id: self.show_full_id,
+            enter_hint: self.enter_hint(),
+            privacy: self.privacy,
+            highlight_own_marks: self.settings.highlight_own_marks,
+            opponent_name: self
+                .config
+                .opponent_name
+                .clone()
+                .filter(|_| self.screen == Screen::SoloGame && !self.practicing()),
+            marks: self.board_marks(),
+            coordinates: self.settings.show_coordinates,
+            other_games: self
+                .other_games()
+                .filter(|_| self.screen == Screen::PvpGame),
+            controls: help::footer(help::GAME_KEYS, &self.help_state()),
+            analysis: self.analysis.then(|| self.analysis_note()),
+            ghost: self
+                .analysis_ghost()
+                .map(|(cell, symbol, _)| (cell, symbol)),
+            // Finished games leave for Game Over, whose replay shows the line
+            winning_line: None,
+        }
+    }
+
+    // `a` on a game screen: moves off (to think without misclicking) or back on.
+    fn toggle_analysis(&mut self) {
+        self.analysis = !self.analysis;
+        self.pending_cell = None;
+        self.show_toast(if self.analysis {
+            "Analysis on: moves are off, the hovered cell shows your mark. a turns moves back on."
+        } else {
+            "Analysis off: moves are live again."
+        });
+    }
+
+    // My mark on the hovered cell, if it's free in a running game I play, and what playing
+    // it would do (as worked out by the local engine).
+    fn analysis_ghost(&self) -> Option<(usize, String, &'static str)> {
+        if !self.analysis {
+            return None;
+        }
+        let (game, you) = match self.screen {
+            Screen::PvpGame => (
+                self.pvp_game.as_ref()?,
+                self.player_symbol_for_opt(self.pvp_game.as_ref()),
+            ),
+            Screen::SoloGame => (self.solo_game.as_ref()?, self.solo_player_symbol()),
+            _ => return None,
+        };
+        if you == "?" || game.board.get(self.board_cursor)?.is_some() {
+            return None;
+        }
+        let mut preview = game.clone();
+        preview.current_turn = you.clone();
+        engine::play(&mut preview, self.board_cursor).ok()?;
+        let outcome = match preview.status.as_str() {
+            "WON" => "wins the game",
+            "DRAW" => "ends in a draw",
+            _ => "",
+        };
+        Some((self.board_cursor, you, outcome))
+    }
+
+    fn analysis_note(&self) -> String {
+        let mut note = "ANALYSIS - moves off, a resumes".to_string();
+        if let Some((_, symbol, outcome)) = self.analysis_ghost().filter(|(.., o)| !o.is_empty()) {
+            note.push_str(&format!(" ({symbol} here {outcome})"));
+        }
+        note
+    }
+
+    // What Enter would do on the hovered cell, for the beginner hint under the board. `None` while
+    // the hint is toggled off (`h`).
+    fn enter_hint(&self) -> Option<&'static str> {
+        if !self.show_enter_hint {
+            return None;
+        }
+        let game = match self.screen {
+            Screen::PvpGame => self.pvp_game.as_ref(),
+            _ => self.solo_game.as_ref(),
+        }?;
+        let occupied = game
+            .board
+            .get(self.board_cursor)
+            .is_some_and(Option::is_some);
+        Some(help::enter_effect(
+            &self.help_state(),
+            Self::is_game_finished(game),
+            occupied,
+        ))
+    }
+
+    // Input for the help footers of the game and Game Over screens.
+    fn help_state(&self) -> HelpState {
+        if self.screen == Screen::GameOver {
+            let Some(finished) = self.finished_game.as_ref() else {
+                return HelpState::default();
+            };
+            let seated = finished.mode != "PVP" || self.player_symbol_for(finished) != "?";
+            return HelpState {
+                can_replay: !self.move_history.is_empty(),
+                can_rematch: seated && self.rematch != Some(Rematch::Requested),
+                rematch_pending: self.rematch.is_some(),
+                other_games: !self.parked_games.is_empty(),
+                ..HelpState::default()
+            };
+        }
+
+        let (game, you) = match self.screen {
+            Screen::PvpGame => (
+                self.pvp_game.as_ref(),
+                self.player_symbol_for_opt(self.pvp_game.as_ref()),
+            ),
+            _ => (self.solo_game.as_ref(), self.solo_player_symbol()),
+        };
+        let Some(game) = game else {
+            return HelpState::default();
+        };
+        let playing = you != "?" && game.status == "IN_PROGRESS";
+        HelpState {
+            playing,
+            my_turn: playing && game.current_turn == you,
+            pvp: game.mode == "PVP",
+            online: !matches!(game.mode.as_str(), MODE_LOCAL_SOLO | MODE_PRACTICE),
+            analysis: self.analysis,
+            ..HelpState::default()
+        }
+    }
+
+    // ASCII mode is for terminals with limited glyph support, so it always draws plain letters.
+    fn board_marks(&self) -> [String; 2] {
+        let symbols = if self.settings.ascii_mode {
+            BoardSymbols::Letters
+        } else {
+            self.settings.board_symbols
+        };
+        symbols.glyphs(&self.settings.custom_symbols)
+    }
+
+    fn practicing(&self) -> bool {
+        self.solo_game
+            .as_ref()
+            .is_some_and(|game| game.mode == MODE_PRACTICE)
+    }
+
+    // In practice mode I'm whichever side is to move.
+    fn solo_player_symbol(&self) -> String {
+        match self.solo_game.as_ref() {
+            Some(game) if game.mode == MODE_PRACTICE => game.current_turn.clone(),
+            game => self.player_symbol_for_opt(game),
+        }
+    }
+
+    fn player_symbol_for_opt(&self, game: Option<&ApiGame>) -> String {
+        game.map(|g| self.player_symbol_for(g))
             .unwrap_or_else(|| "?".to_string())
     }
 
-    fn show_error(&mut self, message: String) {
-        self.info_message = message;
-        self.screen = Screen::Info;
+    fn share_invite(&mut self) {
+        let Some(game) = self.pvp_game.as_ref() else {
+            return;
+        };
+
+        let join_command = |id: &str| {
+            format!(
+                "{} --server {} --join {id}",
+                env!("CARGO_PKG_NAME"),
+                self.base_url
+            )
+        };
+        let command = join_command(&game.id);
+        // Privacy mode hides the id on screen; the clipboard still gets the real command.
+        let mut text = if self.privacy {
+            format!("{}\n\n", join_command(ui::HIDDEN_ID))
+        } else {
+            format!("{command}\n\n")
+        };
+        if game.has_password {
+            text.push_str("This game is locked: ask the host for the password\nand add --password <password> to the command.\n\n");
+        }
+        text.push_str(match clipboard::copy_to_clipboard(&command) {
+            Ok(()) => "Copied to clipboard (if your terminal supports OSC 52).",
+            Err(_) if self.privacy => {
+                "Could not copy automatically; turn privacy mode off (Ctrl+P) to see the command."
+            }
+            Err(_) => "Could not copy automatically, copy the line above.",
+        });
+
+        self.invite_text = Some(text);
+    }
+
+    // Just the id, for pasting into a chat; `s` has the whole join command.
+    fn copy_game_id(&mut self) {
+        let Some(game) = self.pvp_game.as_ref() else {
+            return;
+        };
+        let shown = if self.privacy {
+            ui::HIDDEN_ID
+        } else {
+            game.id.as_str()
+        };
+        let toast = match clipboard::copy_to_clipboard(&game.id) {
+            Ok(()) => format!("Copied game id {shown} (if your terminal supports OSC 52)."),
+            Err(_) => format!("Could not copy, the game id is {shown}"),
+        };
+        self.show_toast(&toast);
+    }
+
+    // `y` on Game Over: the final board plus a result line, for pasting into a chat. Without a
+    // working clipboard the text goes under the result instead, to copy by hand.
+    fn copy_final_board(&mut self) {
+        let Some(game) = self.finished_game.as_ref() else {
+            return;
+        };
+        let mut result = match game.winner.as_deref() {
+            Some(winner) => format!("Winner: {winner}"),
+            None => "Result: Draw".to_string(),
+        };
+        if let Some(outcome) = self
+            .game_outcome
+            .filter(|&outcome| outcome != GameOutcome::Draw)
+        {
+            result.push_str(&format!(" ({})", outcome.label()));
+        }
+        let text = format!("{}\n{result}", ui::board_as_text(&game.board));
+        match clipboard::copy_to_clipboard(&text) {
+            Ok(()) => self.show_toast("Copied the final board (if your terminal supports OSC 52)."),
+            Err(_) => {
+                if !self.game_over_message.contains(&text) {
+                    self.game_over_message.push_str(&format!("\n\n{text}"));
+                }
+                self.show_toast("Could not copy, the board is shown below the result.");
+            }
+        }
+    }
+
+    // Restarts the opponent idle clock whenever the game, the turn or the status changes.
+    fn track_turn(&mut self, next: &ApiGame) {
+        let changed = self.pvp_game.as_ref().is_none_or(|previous| {
+            previous.id != next.id
+                || previous.current_turn != next.current_turn
+                || previous.status != next.status
+        });
+        if changed {
+            self.turn_started_at = Instant::now();
+            self.claim_offered = false;
+        }
+    }
+
+    // How long the opponent has been sitting on their turn, `None` when it isn't their turn
+    // or no PvP game is on screen.
+    fn opponent_idle(&self) -> Option<Duration> {
+        if self.screen != Screen::PvpGame {
+            return None;
+        }
+        let game = self.pvp_game.as_ref()?;
+        let me = self.player_symbol_for(game);
+        let waiting_on_opponent =
+            game.status == "IN_PROGRESS" && me != "?" && game.current_turn != me;
+        waiting_on_opponent.then(|| self.turn_started_at.elapsed())
+    }
+
+    // Offers the claim-win prompt once per opponent turn, after the grace period.
+    fn offer_claim_if_idle(&mut self) {
+        let grace = self
+            .config
+            .idle_grace_secs
+            .map_or(DEFAULT_IDLE_GRACE, Duration::from_secs);
+        let idle_too_long = self.opponent_idle().is_some_and(|idle| idle >= grace);
+        if idle_too_long && !self.claim_offered {
+            self.claim_offered = true;
+            self.confirm = Some(Confirm::ClaimWin);
+            self.ring_bell();
+        }
+    }
+
+    async fn claim_win(&mut self) {
+        let Some(game_id) = self.pvp_game.as_ref().map(|game| game.id.clone()) else {
+            return;
+        };
+
+        match self.api.claim_win(&self.player_id, &game_id).await {
+            Ok(game) => {
+                self.open_game_over(&game, "PvP");
+                self.pvp_game = Some(game);
+            }
+            Err(err) => self.report_api_error("Claim failed", err),
+        }
+    }
+
+    // Resigns the current PvP game on the server, then goes back to the lobby.
+    async fn resign_and_leave(&mut self) {
+        let Some(game_id) = self.pvp_game.as_ref().map(|game| game.id.clone()) else {
+            return;
+        };
+
+        match self.api.resign_game(&self.player_id, &game_id).await {
+            Ok(_) => {
+                self.count_resignation(&game_id);
+                self.pvp_game = None;
+                self.screen = Screen::PvpLobby;
+                self.show_toast("You resigned the game.");
+            }
+            Err(err) => self.report_api_error("Resign failed", err),
+        }
+    }
+
+    // Appends the marks that appeared between two snapshots of the same game to the history.
+    fn record_moves(&mut self, previous: Option<&ApiGame>, next: &ApiGame) {
+        let Some(previous) = previous.filter(|previous| previous.id == next.id) else {
+            return;
+        };
+
+        let moves = new_moves(&previous.board, &next.board);
+        if self.settings.auto_advance && !moves.is_empty() {
+            let cursor = next_empty_cell(&next.board, self.board_cursor);
+            if cursor != self.board_cursor {
+                self.pending_cell = None;
+            }
+            self.board_cursor = cursor;
+        }
+        self.move_history.extend(moves);
+    }
+
+    // Diffs the stored board against a polled one and returns the cell the opponent just filled.
+    fn opponent_move_in(&self, polled: &ApiGame) -> Option<usize> {
+        let previous = self.pvp_game.as_ref()?;
+        if previous.id != polled.id {
+            return None;
+        }
+
+        let me = self.player_symbol_for(polled);
+        previous
+            .board
+            .iter()
+            .zip(&polled.board)
+            .position(|(before, after)| {
+                before.is_none() && after.as_deref().is_some_and(|mark| mark != me)
+            })
+    }
+
+    // True when a game I host just went from "no guest" to "has a guest".
+    fn opponent_just_joined(&self, polled: &ApiGame) -> bool {
+        let was_waiting = self
+            .pvp_game
+            .as_ref()
+            .is_some_and(|previous| previous.guest_player_id.is_none());
+
+        was_waiting && polled.guest_player_id.is_some() && polled.host_player_id == self.player_id
+    }
+
+    fn show_toast(&mut self, message: &str) {
+        self.toast = Some((message.to_string(), Instant::now()));
+    }
+
+    fn ring_bell(&self) {
+        if self.config.bell {
+            // BEL character: most terminals beep or flash the window.
+            print!("\x07");
+            let _ = std::io::stdout().flush();
+        }
+    }
+
+    // `--sound`: rings `event`'s own pattern unless it was muted in Settings.
+    fn cue(&self, event: SoundEvent) {
+        if self.config.sound && !self.settings.muted_sounds.contains(&event) {
+            sound::play(event);
+        }
+    }
+
+    // Events that already rang the plain `--bell` get their pattern instead when `--sound` is on.
+    fn alert(&self, event: SoundEvent) {
+        if self.config.sound {
+            self.cue(event);
+        } else {
+            self.ring_bell();
+        }
+    }
+
+    // Network failures and server rejections need different guidance:
+    // a rejection (illegal move, wrong password) keeps you where you were with the server's reason,
+    // while a network failure explains how to get back online.
+    fn report_api_error(&mut self, action: &str, err: ApiError) {
+        self.cue(SoundEvent::Error);
+        match &err {
+            ApiError::Rejected { message, .. } | ApiError::NotFound(message) => {
+                self.show_toast(&format!("{action}: {message}"));
+            }
+            ApiError::Network(_) => self.show_error(format!(
+                "{action}: {err}\n\nCheck your connection and that the server at {} is running, then try again.",
+                self.base_url
+            )),
+            ApiError::InvalidResponse { body, .. } | ApiError::NotJson { body, .. } => {
+                self.last_raw_response = Some(body.clone());
+                self.show_error(format!(
+                    "{action}: {err}\n\nPress F12 to see the full server response."
+                ));
+            }
+            ApiError::Server { .. } | ApiError::InvalidRequest(_) => {
+                self.show_error(format!("{action}: {err}"));
+            }
+        }
+    }
+
+    // Reports a failed request and, when the failure looks transient, keeps it for a retry.
+    fn action_failed(&mut self, action: PendingAction, label: &str, err: ApiError) {
+        let transient = matches!(err, ApiError::Network(_) | ApiError::Server { .. });
+        self.report_api_error(label, err);
+        if transient {
+            self.failed_action = Some(action);
+        }
+    }
+
+    fn show_error(&mut self, message: String) {
+        self.failed_action = None;
+        self.vanished_game = None;
+        // An error on top of an error keeps pointing at the game the first one interrupted.
+        if self.screen != Screen::Info {
+            self.stranded_game = self
+                .pvp_game
+                .as_ref()
+                .filter(|game| self.screen == Screen::PvpGame && game.status == "IN_PROGRESS")
+                .filter(|game| self.player_symbol_for(game) != "?")
+                .map(|game| game.id.clone());
+        }
+        self.info_message = message;
+        self.info_scroll = 0;
+        self.screen = Screen::Info;
+    }
+
+    // The on-screen PvP game answered 404 while polling: it was deleted or expired server-side
+    // (a server restart loses every game). Explains what happened instead of freezing on the old board.
+    fn game_vanished(&mut self, game_id: &str) {
+        self.pvp_game = None;
+        if self.settings.last_game_id.as_deref() == Some(game_id) {
+            self.forget_last_game();
+        }
+        self.show_error(format!(
+            "This game no longer exists.\n\nThe server does not know game {game_id} any more: it was deleted or expired, for example because the server restarted.\n\nPress l to open the PvP lobby, or Enter/b to return Home."
+        ));
+        self.vanished_game = Some(game_id.to_string());
+    }
+
+    fn is_game_finished(game: &ApiGame) -> bool {
+        matches!(game.status.as_str(), "WON" | "DRAW")
+    }
+
+    // Adds a finished game to the session stats and, for PvP, to the opponent's head-to-head.
+    fn count_result(&mut self, opponent: Option<&str>, outcome: GameOutcome) {
+        self.stats.count(outcome);
+        if let Some(opponent) = opponent {
+            self.head_to_head
+                .entry(opponent.to_string())
+                .or_default()
+                .count(outcome);
+        }
+    }
+
+    // The other player of a PvP game I have a seat in; the head-to-head score is kept against them.
+    fn opponent_in(&self, game: &ApiGame) -> Option<String> {
+        match self.player_symbol_for(game).as_str() {
+            _ if game.mode != "PVP" => None,
+            "X" => game.guest_player_id.clone(),
+            "O" => Some(game.host_player_id.clone()),
+            _ => None,
+        }
+    }
+
+    // A game I resigned never reaches Game Over, so its loss is counted here, head-to-head included.
+    fn count_resignation(&mut self, game_id: &str) {
+        let opponent = self
+            .pvp_game
+            .as_ref()
+            .filter(|game| game.id == game_id)
+            .and_then(|game| self.opponent_in(game));
+        self.count_result(opponent.as_deref(), GameOutcome::Lost);
+    }
+
+    fn open_game_over(&mut self, game: &ApiGame, mode_label: &str) {
+        let you = self.player_symbol_for(game);
+        if you != "?" {
+            self.metrics.game_played();
+        }
+        // PvP results also count towards the score against this particular opponent.
+        let opponent = self.opponent_in(game);
+        // Spectated games, and practice where I'm on both sides, don't touch my stats.
+        self.game_outcome =
+            (you != "?" && game.mode != MODE_PRACTICE).then(|| game.outcome_for(&you));
+        if let Some(outcome) = self.game_outcome {
+            self.count_result(opponent.as_deref(), outcome);
+        }
+        let result_line = match self.game_outcome {
+            None => match game.winner.as_deref() {
+                Some(winner) => format!("Winner: {winner}"),
+                None => "Result: Draw".to_string(),
+            },
+            Some(GameOutcome::Draw) => "Result: Draw".to_string(),
+            Some(outcome) => {
+                let winner = game.winner.as_deref().unwrap_or("Unknown");
+                if self.config.bell {
+                    self.game_over_flash_at = Some(Instant::now());
+                }
+                self.alert(match outcome {
+                    GameOutcome::Won => SoundEvent::Won,
+                    _ => SoundEvent::Lost,
+                });
+                let outcome = outcome.label();
+                match game.resigned_by.as_deref() {
+                    Some(resigned) => {
+                        format!("Winner: {winner} ({outcome}) - {resigned} resigned")
+                    }
+                    None => format!("Winner: {winner} ({outcome})"),
+                }
+            }
+        };
+
+        // The game id goes under the first line at draw time, so privacy mode can hide it.
+        self.game_over_message = format!("{mode_label} game finished.\n{result_line}");
+        self.game_over_message.push('\n');
+        self.game_over_message
+            .push_str(&moves_note(game, &you, self.game_outcome));
+        if let Some(how) = game.win_description() {
+            self.game_over_message.push_str(&format!("\n{how}."));
+        }
+        // The running score only means something once the same opponent was played before.
+        if let Some(record) = opponent
+            .and_then(|id| self.head_to_head.get(&id))
+            .filter(|record| record.total() > 1)
+        {
+            self.game_over_message.push('\n');
+            self.game_over_message.push_str(&record.head_to_head_line());
+        }
+        // The server's own count across rematches, where it keeps one.
+        if let Some(series) = game.series_line(&you) {
+            self.game_over_message.push('\n');
+            self.game_over_message.push_str(&series);
+        }
+        self.finished_game = Some(game.clone());
+        self.rematch = None;
+        self.analysis = false;
+        self.recap_scroll = 0;
+        self.moved_first = game.first_turn() == you;
+        self.autoreturn_at = self
+            .config
+            .autoreturn_secs
+            .map(|secs| Instant::now() + Duration::from_secs(secs));
+        self.screen = Screen::GameOver;
+    }
+}
+
+// "You won in 3 moves." for my own wins, the game's total for everything else
+// (draws, losses, and games I wasn't the only player in).
+fn moves_note(game: &ApiGame, you: &str, outcome: Option<GameOutcome>) -> String {
+    let plural = |count: usize| if count == 1 { "move" } else { "moves" };
+    let mine = game.moves_by(you);
+    let total = game.moves_played();
+    if outcome == Some(GameOutcome::Won) {
+        format!("You won in {mine} {}.", plural(mine))
+    } else if outcome == Some(GameOutcome::Lost) {
+        format!("You made {mine} of the {total} {}.", plural(total))
+    } else if game.status == "WON" {
+        format!("Won after {total} {}.", plural(total))
+    } else {
+        format!("Draw after {total} {}.", plural(total))
+    }
+}
+
+fn jittered_poll_interval() -> Duration {
+    let jitter_ms = rand::rng().random_range(0..=2 * POLL_JITTER_MS);
+    POLL_INTERVAL - Duration::from_millis(POLL_JITTER_MS) + Duration::from_millis(jitter_ms)
+}
+
+// Pure cursor transition on the 3x3 board: arrows move one cell and stop at edges
+// (no wrap-around), digits 1..9 jump straight to a cell in reading order.
+fn next_cursor(current: usize, key: KeyCode) -> usize {
+    let row = current / 3;
+    let col = current % 3;
+
+    let (next_row, next_col) = match key {
+        KeyCode::Left => (row, col.saturating_sub(1)),
+        KeyCode::Right => (row, (col + 1).min(2)),
+        KeyCode::Up => (row.saturating_sub(1), col),
+        KeyCode::Down => ((row + 1).min(2), col),
+        KeyCode::Char(ch) if ('1'..='9').contains(&ch) => {
+            return ch as usize - '1' as usize;
+        }
+        _ => (row, col),
+    };
+
+    next_row * 3 + next_col
+}
+
+// First empty cell from `current` on in reading order, wrapping past the last cell;
+// a full board leaves the cursor where it is.
+fn next_empty_cell(board: &[Option<String>], current: usize) -> usize {
+    (0..BOARD_CELLS)
+        .map(|offset| (current + offset) % BOARD_CELLS)
+        .find(|&index| board.get(index).is_some_and(Option::is_none))
+        .unwrap_or(current)
+}
+
+// Marks present in `next` but not in `previous`, in play order.
+// A single response can hold two new marks (solo: my move plus the AI reply), and X always opens,
+// so the side to move first is derived from how many marks were already on the board.
+fn new_moves(previous: &[Option<String>], next: &[Option<String>]) -> Vec<MoveRecord> {
+    let mut moves: Vec<MoveRecord> = previous
+        .iter()
+        .zip(next)
+        .enumerate()
+        .filter_map(|(index, (before, after))| match (before, after) {
+            (None, Some(symbol)) => Some(MoveRecord {
+                index,
+                symbol: symbol.clone(),
+            }),
+            _ => None,
+        })
+        .collect();
+
+    let marks_before = previous.iter().filter(|cell| cell.is_some()).count();
+    let first = if marks_before % 2 == 0 { "X" } else { "O" };
+    moves.sort_by_key(|record| record.symbol != first);
+    moves
+}
+
+// Numpad layout puts 7 8 9 on the top row, so rows are flipped:
+// maps a numpad digit to the digit at the same spot in the 1 2 3 / 4 5 6 / 7 8 9 grid.
+fn numpad_to_grid_digit(ch: char) -> char {
+    match ch {
+        '1'..='3' => (ch as u8 + 6) as char,
+        '7'..='9' => (ch as u8 - 6) as char,
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn auto_advance_finds_the_next_empty_cell() {
+        let mut board = vec![None; BOARD_CELLS];
+        for index in [4, 5, 6, 7, 8] {
+            board[index] = Some("X".to_string());
+        }
+        assert_eq!(next_empty_cell(&board, 4), 0);
+        assert_eq!(next_empty_cell(&board, 1), 1);
+
+        let full = vec![Some("O".to_string()); BOARD_CELLS];
+        assert_eq!(next_empty_cell(&full, 7), 7);
+    }
+
+    #[test]
+    fn left_from_first_column_stays() {
+        assert_eq!(next_cursor(0, KeyCode::Left), 0);
+        assert_eq!(next_cursor(3, KeyCode::Left), 3);
+        assert_eq!(next_cursor(6, KeyCode::Left), 6);
+    }
+
+    #[test]
+    fn right_from_last_column_stays() {
+        assert_eq!(next_cursor(2, KeyCode::Right), 2);
+        assert_eq!(next_cursor(5, KeyCode::Right), 5);
+        assert_eq!(next_cursor(8, KeyCode::Right), 8);
+    }
+
+    #[test]
+    fn up_from_first_row_stays() {
+        assert_eq!(next_cursor(0, KeyCode::Up), 0);
+        assert_eq!(next_cursor(1, KeyCode::Up), 1);
+        assert_eq!(next_cursor(2, KeyCode::Up), 2);
+    }
+
+    #[test]
+    fn down_from_last_row_stays() {
+        assert_eq!(next_cursor(6, KeyCode::Down), 6);
+        assert_eq!(next_cursor(7, KeyCode::Down), 7);
+        assert_eq!(next_cursor(8, KeyCode::Down), 8);
+    }
+
+    #[test]
+    fn arrows_do_not_wrap_between_rows() {
+        assert_eq!(next_cursor(3, KeyCode::Left), 3);
+        assert_eq!(next_cursor(2, KeyCode::Right), 2);
+    }
+
+    #[test]
+    fn arrows_move_one_cell() {
+        assert_eq!(next_cursor(4, KeyCode::Left), 3);
+        assert_eq!(next_cursor(4, KeyCode::Right), 5);
+        assert_eq!(next_cursor(4, KeyCode::Up), 1);
+        assert_eq!(next_cursor(4, KeyCode::Down), 7);
+    }
+
+    #[test]
+    fn digits_jump_to_cell() {
+        assert_eq!(next_cursor(0, KeyCode::Char('5')), 4);
+        assert_eq!(next_cursor(8, KeyCode::Char('1')), 0);
+        assert_eq!(next_cursor(0, KeyCode::Char('9')), 8);
+    }
+
+    #[test]
+    fn other_keys_keep_cursor() {
+        assert_eq!(next_cursor(4, KeyCode::Char('0')), 4);
+        assert_eq!(next_cursor(4, KeyCode::Char('x')), 4);
+        assert_eq!(next_cursor(4, KeyCode::Enter), 4);
+    }
+
+    #[test]
+    fn numpad_digits_flip_rows() {
+        assert_eq!(next_cursor(4, KeyCode::Char(numpad_to_grid_digit('7'))), 0);
+        assert_eq!(next_cursor(4, KeyCode::Char(numpad_to_grid_digit('5'))), 4);
+        assert_eq!(next_cursor(4, KeyCode::Char(numpad_to_grid_digit('3'))), 8);
+        assert_eq!(numpad_to_grid_digit('x'), 'x');
+    }
+
+    #[test]
+    fn head_to_head_line_reads_from_my_side() {
+        let lead = SessionStats {
+            won: 2,
+            lost: 1,
+            drawn: 0,
+        };
+        assert_eq!(lead.head_to_head_line(), "You lead 2-1.");
+
+        let even = SessionStats {
+            won: 1,
+            lost: 1,
+            drawn: 2,
+        };
+        assert_eq!(even.head_to_head_line(), "All square at 1-1 (2 draws).");
+    }
+
+    #[test]
+    fn game_names_report_the_rule_they_break() {
+        assert_eq!(models::validate_game_name("  Friday night  "), Ok(()));
+        assert_eq!(
+            models::validate_game_name(" \t\u{7} "),
+            Err("Game name cannot be blank".to_string())
+        );
+        assert_eq!(
+            models::validate_game_name("ab"),
+            Err("Game name must be at least 3 characters (it has 2)".to_string())
+        );
+        assert_eq!(
+            models::validate_game_name(&"x".repeat(41)),
+            Err("Game name must be at most 40 characters (it has 41)".to_string())
+        );
+    }
+
+    #[test]
+    fn game_over_names_the_winning_line() {
+        // X completed the top row and the left column with the same move.
+        let mut app = test_app(MockApi::default());
+        app.open_game_over(&finished_game_with_line("XXXXOOXOO", None), "Solo");
+        assert!(app.game_over_message.ends_with("Won on the top row."));
+        assert!(app.game_over_message.contains("\nYou won in 5 moves.\n"));
+
+        // The server's line wins over the one read off the board.
+        let mut app = test_app(MockApi::default());
+        app.open_game_over(
+            &finished_game_with_line("XXXXOOXOO", Some(vec![0, 3, 6])),
+            "Solo",
+        );
+        assert!(app.game_over_message.ends_with("Won on the left column."));
+    }
+
+    // End-to-end: scripted key presses against an in-memory backend.
+
+    use std::cell::RefCell;
+    use std::ops::{Deref, DerefMut};
+
+    use tempfile::TempDir;
+
+    use crate::api::ApiResult;
+    use crate::models::{OpenGamesPage, ReportResultResponse, STATUS_WAITING_FOR_PLAYER};
+
+    const PLAYER_ID: &str = "11111111-1111-4111-8111-111111111111";
+    const OPPONENT_ID: &str = "22222222-2222-4222-8222-222222222222";
+
+    // Answers every endpoint from canned games; anything left unset is a 404.
+    #[derive(Default)]
+    struct MockApi {
+        solo_game: Option<ApiGame>,
+        pvp_game: Option<ApiGame>,
+        // Answer to `get_game` (polls, resyncs, resuming), whatever the game's mode.
+        polled_game: Option<ApiGame>,
+        open_games: Vec<ApiGame>,
+        // Answer to every rematch call; unset means the server has no rematch endpoints.
+        rematch_game: Option<ApiGame>,
+        // Results received by `report_result`; `results_down` answers it with a 503 instead,
+        // `results_missing` with a 404 like a server without the endpoint.
+        reported: RefCell<Vec<ReportResultRequest>>,
+        results_down: bool,
+        results_missing: bool,
+        // Health checks never answer, like a host that drops packets.
+        health_hangs: bool,
+        // Game ids passed to `cancel_pvp_game`.
+        cancelled: RefCell<Vec<String>>,
+        // Joins lose the race for the seat and get a 409.
+        join_filled: bool,
+        // Game ids passed to `subscribe_game_events`.
+        subscribed: RefCell<Vec<String>>,
+    }
+
+    fn canned(game: &Option<ApiGame>) -> ApiResult<ApiGame> {
+        game.clone()
+            .ok_or_else(|| ApiError::NotFound("Game not found".to_string()))
+    }
+
+    impl GameApi for MockApi {
+        fn set_base_url(&mut self, _base_url: &str) {}
+
+        fn last_latency(&self) -> Option<Duration> {
+            None
+        }
+
+        async fn health_check(&self) -> ApiResult<bool> {
+            if self.health_hangs {
+                std::future::pending::<()>().await;
+            }
+            Ok(true)
+        }
+
+        async fn server_version(&self) -> ApiResult<String> {
+            Ok("1.0.0".to_string())
+        }
+
+        async fn create_solo_game(
+            &self,
+            _: &str,
+            _: Option<&str>,
+            _: &str,
+            _: Option<&str>,
+        ) -> ApiResult<ApiGame> {
+            canned(&self.solo_game)
+        }
+
+        async fn create_pvp_game(
+            &self,
+            _: &str,
+            _: Option<&str>,
+            _: &str,
+            _: Option<String>,
+            _: Option<&str>,
+        ) -> ApiResult<ApiGame> {
+            canned(&self.pvp_game)
+        }
+
+        async fn list_open_pvp_games(
+            &self,
+            offset: usize,
+            limit: usize,
+        ) -> ApiResult<OpenGamesPage> {
+            Ok(OpenGamesPage {
+                games: self
+                    .open_games
+                    .iter()
+                    .skip(offset)
+                    .take(limit)
+                    .cloned()
+                    .collect(),
+                total: Some(self.open_games.len()),
+            })
+        }
+
+        async fn list_live_pvp_games(&self) -> ApiResult<Vec<ApiGame>> {
+            Ok(Vec::new())
+        }
+
+        // Like an older server: the client pieces the list together itself.
+        async fn list_my_pvp_games(&self, _: &str) -> ApiResult<Vec<ApiGame>> {
+            Err(ApiError::NotFound("Cannot GET /games/pvp/mine".to_string()))
+        }
+
+        async fn join_pvp_game(
+            &self,
+            _: &str,
+            _: Option<&str>,
+            _: &str,
+            _: Option<String>,
+        ) -> ApiResult<ApiGame> {
+            if self.join_filled {
+                return Err(ApiError::Rejected {
+                    status: reqwest::StatusCode::CONFLICT,
+                    message: "This game is not waiting for a second player".to_string(),
+                });
+            }
+            canned(&self.pvp_game)
+        }
+
+        async fn cancel_pvp_game(&self, _: &str, game_id: &str) -> ApiResult<()> {
+            self.cancelled.borrow_mut().push(game_id.to_string());
+            canned(&self.pvp_game).map(|_| ())
+        }
+
+        async fn get_game(&self, _: &str) -> ApiResult<ApiGame> {
+            canned(&self.polled_game)
+        }
+
+        async fn play_move(&self, _: &str, _: &str, _: usize) -> ApiResult<ApiGame> {
+            canned(&self.solo_game)
+        }
+
+        async fn resign_game(&self, _: &str, _: &str) -> ApiResult<()> {
+            canned(&self.pvp_game).map(|_| ())
+        }
+
+        async fn claim_win(&self, _: &str, _: &str) -> ApiResult<ApiGame> {
+            canned(&self.pvp_game)
+        }
+
+        async fn watch_game(&self, _: &str, _: Option<&str>, _: &str) -> ApiResult<ApiGame> {
+            canned(&self.pvp_game)
+        }
+
+        async fn request_rematch(&self, _: &str, _: &str) -> ApiResult<ApiGame> {
+            canned(&self.rematch_game)
+        }
+
+        async fn accept_rematch(&self, _: &str, _: &str) -> ApiResult<ApiGame> {
+            canned(&self.rematch_game)
+        }
+
+        async fn cancel_rematch(&self, _: &str, _: &str) -> ApiResult<ApiGame> {
+            canned(&self.rematch_game)
+        }
+
+        async fn report_result(
+            &self,
+            result: &ReportResultRequest,
+        ) -> ApiResult<ReportResultResponse> {
+            if self.results_down {
+                return Err(ApiError::Server {
+                    status: reqwest::StatusCode::SERVICE_UNAVAILABLE,
+                    message: "Service Unavailable".to_string(),
+                });
+            }
+            if self.results_missing {
+                return Err(ApiError::NotFound("Cannot POST /games/results".to_string()));
+            }
+            self.reported.borrow_mut().push(result.clone());
+            Ok(ReportResultResponse { recorded: true })
+        }
+
+        // Like a server without the events endpoint: games are polled.
+        fn subscribe_game_events(&self, game_id: &str) -> Option<GameEvents> {
+            self.subscribed.borrow_mut().push(game_id.to_string());
+            None
+        }
+    }
+
+    fn game(id: &str, mode: &str, status: &str) -> ApiGame {
+        ApiGame {
+            id: id.to_string(),
+            mode: mode.to_string(),
+            name: Some("Test game".to_string()),
+            host_player_id: PLAYER_ID.to_string(),
+            guest_player_id: None,
+            host_name: Some("Tester".to_string()),
+            guest_name: None,
+            board: vec![None; 9],
+            current_turn: "X".to_string(),
+            first_turn: None,
+            status: status.to_string(),
+            winner: None,
+            resigned_by: None,
+            win_reason: None,
+            winning_line: None,
+            has_password: false,
+            spectators: None,
+            rematch_requested_by: None,
+            rematch_game_id: None,
+            host_score: None,
+            guest_score: None,
+            created_at: None,
+            updated_at: None,
+        }
     }
 
-    fn is_game_finished(game: &ApiGame) -> bool {
-        matches!(game.status.as_str(), "WON" | "DRAW")
+    // A PvP game we hosted and won against OPPONENT_ID, with its game-over screen open.
+    fn finished_pvp_app(api: MockApi) -> TestApp {
+        let mut app = test_app(api);
+        let mut finished = game("pvp-1", "PVP", "WON");
+        finished.guest_player_id = Some(OPPONENT_ID.to_string());
+        finished.winner = Some("X".to_string());
+        app.open_game_over(&finished, "PvP");
+        app
     }
 
-    fn open_game_over(&mut self, game: &ApiGame, mode_label: &str) {
-        let result_line = if game.status == "WON" {
-            let winner = game.winner.as_deref().unwrap_or("Unknown");
-            let you = self.player_symbol_for(game);
-            let outcome = if winner == you {
-                "You won!"
-            } else {
-                "You lost."
+    fn finished_game_with_line(board: &str, winning_line: Option<Vec<usize>>) -> ApiGame {
+        let mut finished = game("solo-1", "SOLO", "WON");
+        finished.board = board
+            .chars()
+            .map(|cell| (cell != '.').then(|| cell.to_string()))
+            .collect();
+        finished.winner = Some("X".to_string());
+        finished.winning_line = winning_line;
+        finished
+    }
+
+    // Starting games saves settings and finished solo games queue results; every app gets a
+    // config directory of its own, away from the real one and from the other tests. The
+    // directory goes away with the app.
+    struct TestApp {
+        app: App<MockApi>,
+        _config_dir: TempDir,
+    }
+
+    impl Deref for TestApp {
+        type Target = App<MockApi>;
+
+        fn deref(&self) -> &App<MockApi> {
+            &self.app
+        }
+    }
+
+    impl DerefMut for TestApp {
+        fn deref_mut(&mut self) -> &mut App<MockApi> {
+            &mut self.app
+        }
+    }
+
+    fn test_app(api: MockApi) -> TestApp {
+        let config_dir = TempDir::new().expect("temp dir for the test's config");
+        let settings = Settings {
+            player_id: PLAYER_ID.to_string(),
+            display_name: "Tester".to_string(),
+            ..Settings::default()
+        };
+        TestApp {
+            app: App::with_api(
+                Config::default(),
+                Some(settings),
+                config_dir.path().to_path_buf(),
+                |_| api,
+            ),
+            _config_dir: config_dir,
+        }
+    }
+
+    async fn press(app: &mut App<MockApi>, keys: &[KeyCode]) {
+        for &code in keys {
+            app.handle_key(KeyEvent::from(code)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn enter_on_solo_starts_a_solo_game() {
+        let mut app = test_app(MockApi {
+            solo_game: Some(game("solo-1", "SOLO", "IN_PROGRESS")),
+            ..MockApi::default()
+        });
+
+        press(&mut app, &[KeyCode::Enter]).await;
+
+        assert_eq!(app.screen, Screen::SoloGame);
+        assert_eq!(
+            app.solo_game.as_ref().map(|g| g.id.as_str()),
+            Some("solo-1")
+        );
+        assert_eq!(app.settings.last_game_id.as_deref(), Some("solo-1"));
+    }
+
+    #[tokio::test]
+    async fn failed_solo_start_stays_home_with_a_toast() {
+        let mut app = test_app(MockApi::default());
+
+        press(&mut app, &[KeyCode::Enter]).await;
+
+        assert_eq!(app.screen, Screen::Home);
+        assert!(app.toast.is_some());
+    }
+
+    #[tokio::test]
+    async fn lobby_c_opens_create_form() {
+        let mut app = test_app(MockApi {
+            open_games: vec![game("open-1", "PVP", STATUS_WAITING_FOR_PLAYER)],
+            ..MockApi::default()
+        });
+
+        press(&mut app, &[KeyCode::Down, KeyCode::Enter]).await;
+        assert_eq!(app.screen, Screen::PvpLobby);
+        // Hosted by us, so it is listed under "Your games"
+        assert_eq!(app.own_games.len(), 1);
+
+        press(&mut app, &[KeyCode::Char('c')]).await;
+        assert_eq!(app.screen, Screen::PvpCreate);
+    }
+
+    #[tokio::test]
+    async fn joining_a_game_that_just_filled_refreshes_the_lobby() {
+        let mut taken = game("open-1", "PVP", STATUS_WAITING_FOR_PLAYER);
+        taken.host_player_id = OPPONENT_ID.to_string();
+        let mut app = test_app(MockApi {
+            open_games: vec![taken],
+            join_filled: true,
+            ..MockApi::default()
+        });
+
+        press(&mut app, &[KeyCode::Down, KeyCode::Enter]).await;
+        app.lobby_fetched_at = None;
+        press(&mut app, &[KeyCode::Enter]).await;
+
+        assert_eq!(app.screen, Screen::PvpLobby);
+        assert!(app.lobby_fetched_at.is_some());
+        let (message, _) = app.toast.as_ref().unwrap();
+        assert_eq!(message, "That game just filled — refreshing lobby");
+    }
+
+    #[tokio::test]
+    async fn waking_from_suspend_polls_right_away() {
+        let mut app = test_app(MockApi::default());
+        app.poll_interval = Duration::from_secs(60);
+        app.dirty = false;
+
+        app.resync_after_suspend().await;
+        assert_eq!(app.poll_interval, Duration::from_secs(60));
+        assert!(!app.dirty);
+
+        app.last_tick_at = SystemTime::now() - Duration::from_secs(600);
+        app.resync_after_suspend().await;
+        assert_eq!(app.poll_interval, Duration::ZERO);
+        assert!(app.dirty);
+    }
+
+    #[tokio::test]
+    async fn shift_r_refetches_the_game_right_away() {
+        let mut shown = game("pvp-1", "PVP", "IN_PROGRESS");
+        shown.guest_player_id = Some(OPPONENT_ID.to_string());
+        let mut moved = shown.clone();
+        moved.board[4] = Some("X".to_string());
+        moved.current_turn = "O".to_string();
+        let mut app = test_app(MockApi {
+            polled_game: Some(moved),
+            ..MockApi::default()
+        });
+        app.pvp_game = Some(shown);
+        app.screen = Screen::PvpGame;
+
+        press(&mut app, &[KeyCode::Char('R')]).await;
+        assert!(app.resync_pending);
+        app.resync_game().await;
+
+        assert!(!app.resync_pending);
+        assert_eq!(
+            app.pvp_game.as_ref().unwrap().board[4].as_deref(),
+            Some("X")
+        );
+        let (message, _) = app.toast.as_ref().unwrap();
+        assert_eq!(message, "Game resynced.");
+    }
+
+    #[tokio::test]
+    async fn idle_home_is_not_redrawn_until_something_changes() {
+        let mut app = test_app(MockApi::default());
+        app.dirty = false;
+        app.poll_interval = Duration::ZERO;
+
+        app.refresh_remote_state_if_needed().await;
+        assert!(!app.dirty && !app.animating());
+
+        press(&mut app, &[KeyCode::Down]).await;
+        assert!(app.dirty);
+
+        app.dirty = false;
+        app.show_toast("Hello");
+        assert!(app.animating());
+    }
+
+    #[tokio::test]
+    async fn server_url_can_be_picked_from_recent_servers() {
+        let mut app = test_app(MockApi::default());
+        app.settings.recent_servers = vec![
+            "http://localhost:3000".to_string(),
+            "http://staging:3000".to_string(),
+            "http://lan:3000".to_string(),
+        ];
+        app.open_settings();
+
+        press(&mut app, &[KeyCode::Enter, KeyCode::Down, KeyCode::Down]).await;
+        assert_eq!(app.text_input.value(), "http://staging:3000");
+
+        press(&mut app, &[KeyCode::Enter]).await;
+        assert_eq!(app.base_url, "http://staging:3000");
+        assert_eq!(
+            app.settings.recent_servers,
+            [
+                "http://staging:3000",
+                "http://localhost:3000",
+                "http://lan:3000"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn enter_on_a_sound_row_mutes_only_that_cue() {
+        let mut app = test_app(MockApi::default());
+        app.open_settings();
+
+        press(&mut app, &[KeyCode::Down; 15]).await;
+        assert_eq!(app.selected_setting(), SettingRow::Sound(SoundEvent::Lost));
+        press(&mut app, &[KeyCode::Enter]).await;
+        assert_eq!(app.settings.muted_sounds, [SoundEvent::Lost]);
+        assert!(screen_text(&app).contains("Sound: you lost: off"));
+    }
+
+    #[tokio::test]
+    async fn quitting_cancels_only_my_unjoined_games() {
+        let mut joined = game("joined-1", "PVP", "IN_PROGRESS");
+        joined.guest_player_id = Some(OPPONENT_ID.to_string());
+        let mut app = test_app(MockApi {
+            open_games: vec![game("open-1", "PVP", STATUS_WAITING_FOR_PLAYER), joined],
+            pvp_game: Some(game("open-2", "PVP", STATUS_WAITING_FOR_PLAYER)),
+            ..MockApi::default()
+        });
+
+        press(&mut app, &[KeyCode::Down, KeyCode::Enter]).await;
+        app.enter_pvp_game(game("open-2", "PVP", STATUS_WAITING_FOR_PLAYER));
+
+        let open_games = app.hosted_waiting_game_ids();
+        app.cancel_games_on_quit(&open_games).await;
+
+        assert_eq!(*app.api.cancelled.borrow(), ["open-2", "open-1"]);
+    }
+
+    #[tokio::test]
+    async fn lobby_sort_keeps_the_selected_game() {
+        let named = |id: &str, name: &str| ApiGame {
+            name: Some(name.to_string()),
+            host_player_id: OPPONENT_ID.to_string(),
+            ..game(id, "PVP", STATUS_WAITING_FOR_PLAYER)
+        };
+        let mut app = test_app(MockApi {
+            open_games: vec![named("g-1", "zebra"), named("g-2", "Apple")],
+            ..MockApi::default()
+        });
+        press(&mut app, &[KeyCode::Down, KeyCode::Enter]).await;
+        assert_eq!(app.pvp_games[0].id, "g-1");
+        assert_eq!(app.pvp_selected_index, 0);
+
+        // By name: "Apple" moves up and the highlight follows "zebra"
+        press(&mut app, &[KeyCode::Char('s')]).await;
+        assert_eq!(app.lobby_sort, LobbySort::Name);
+        assert_eq!(app.pvp_games[0].id, "g-2");
+        assert_eq!(app.pvp_selected_index, 1);
+
+        // The order survives a refresh
+        press(&mut app, &[KeyCode::Char('r')]).await;
+        assert_eq!(app.pvp_games[0].id, "g-2");
+    }
+
+    #[tokio::test]
+    async fn load_more_appends_the_next_lobby_page() {
+        let open_games = (0..25)
+            .map(|n| ApiGame {
+                host_player_id: OPPONENT_ID.to_string(),
+                ..game(&format!("g-{n}"), "PVP", STATUS_WAITING_FOR_PLAYER)
+            })
+            .collect();
+        let mut app = test_app(MockApi {
+            open_games,
+            ..MockApi::default()
+        });
+        press(&mut app, &[KeyCode::Down, KeyCode::Enter]).await;
+        assert_eq!(app.pvp_games.len(), config::DEFAULT_LOBBY_PAGE);
+        assert!(app.lobby_has_more);
+
+        // End lands on the "Load more" row after the last game
+        press(&mut app, &[KeyCode::End, KeyCode::Enter]).await;
+        assert_eq!(app.pvp_games.len(), 25);
+        assert!(!app.lobby_has_more);
+        assert_eq!(app.pvp_selected_index, config::DEFAULT_LOBBY_PAGE);
+
+        // A refresh keeps both pages
+        press(&mut app, &[KeyCode::Char('r')]).await;
+        assert_eq!(app.pvp_games.len(), 25);
+    }
+
+    #[tokio::test]
+    async fn quick_keys_home_opens_pvp_with_p() {
+        let mut app = test_app(MockApi::default());
+
+        // The arrow-key list ignores `p`
+        press(&mut app, &[KeyCode::Char('p')]).await;
+        assert_eq!(app.screen, Screen::Home);
+
+        app.settings.quick_keys_home = true;
+        press(&mut app, &[KeyCode::Char('p')]).await;
+        assert_eq!(app.screen, Screen::PvpLobby);
+    }
+
+    #[tokio::test]
+    async fn quick_keys_home_ignores_the_hidden_menu_list() {
+        let mut app = test_app(MockApi {
+            solo_game: Some(game("solo-1", "SOLO", "IN_PROGRESS")),
+            ..MockApi::default()
+        });
+        app.settings.quick_keys_home = true;
+
+        press(&mut app, &[KeyCode::Down, KeyCode::Enter]).await;
+        assert_eq!(app.screen, Screen::Home);
+        assert_eq!(app.home_index, 0);
+        assert!(app.solo_game.is_none());
+    }
+
+    #[tokio::test]
+    async fn create_form_keeps_its_draft_until_reset() {
+        let mut app = test_app(MockApi::default());
+        press(
+            &mut app,
+            &[KeyCode::Down, KeyCode::Enter, KeyCode::Char('c')],
+        )
+        .await;
+        press(
+            &mut app,
+            &[KeyCode::Char('F'), KeyCode::Char('u'), KeyCode::Char('n')],
+        )
+        .await;
+
+        // Leaving and coming back keeps the name
+        press(&mut app, &[KeyCode::Esc, KeyCode::Char('c')]).await;
+        assert_eq!(app.screen, Screen::PvpCreate);
+        assert_eq!(app.create_name.value(), "Fun");
+
+        app.handle_key(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL))
+            .await;
+        assert_eq!(app.create_name.value(), "");
+    }
+
+    #[tokio::test]
+    async fn a_game_without_my_seat_is_only_watched() {
+        let mut app = test_app(MockApi::default());
+        app.enter_pvp_game(ApiGame {
+            host_player_id: OPPONENT_ID.to_string(),
+            guest_player_id: Some("someone-else".to_string()),
+            ..game("g-1", "PVP", "IN_PROGRESS")
+        });
+        assert_eq!(app.screen, Screen::PvpGame);
+        let toast = app.toast.as_ref().map(|(text, _)| text.as_str());
+        assert_eq!(
+            toast,
+            Some("You're not a player in this game. Watching it instead.")
+        );
+
+        press(&mut app, &[KeyCode::Enter]).await;
+        let toast = app.toast.as_ref().map(|(text, _)| text.as_str());
+        assert_eq!(
+            toast,
+            Some("You're not a player in this game, moves are disabled.")
+        );
+    }
+
+    #[tokio::test]
+    async fn hanging_health_check_times_out_as_offline() {
+        let mut app = test_app(MockApi {
+            health_hangs: true,
+            ..MockApi::default()
+        });
+        app.config.health_timeout_secs = Some(0);
+
+        app.check_server().await;
+        assert_eq!(app.server_online, Some(false));
+    }
+
+    #[tokio::test]
+    async fn my_games_tab_resumes_a_hosted_game() {
+        let joinable = |id: &str| ApiGame {
+            host_player_id: OPPONENT_ID.to_string(),
+            ..game(id, "PVP", STATUS_WAITING_FOR_PLAYER)
+        };
+        let mut app = test_app(MockApi {
+            open_games: vec![
+                game("mine-1", "PVP", STATUS_WAITING_FOR_PLAYER),
+                joinable("g-1"),
+                joinable("g-2"),
+            ],
+            ..MockApi::default()
+        });
+        press(&mut app, &[KeyCode::Down, KeyCode::Enter, KeyCode::End]).await;
+        assert_eq!(app.pvp_selected_index, 2);
+
+        press(&mut app, &[KeyCode::Tab]).await;
+        assert_eq!(app.lobby_tab, LobbyTab::Mine);
+        assert_eq!(app.my_games.len(), 1);
+        assert_eq!(app.my_game_rows()[0].state, "waiting for an opponent");
+
+        // Each tab keeps its own selection
+        press(&mut app, &[KeyCode::Tab]).await;
+        assert_eq!(app.pvp_selected_index, 2);
+
+        press(&mut app, &[KeyCode::Tab, KeyCode::Enter]).await;
+        assert_eq!(app.screen, Screen::PvpGame);
+        assert_eq!(app.pvp_game.as_ref().unwrap().id, "mine-1");
+    }
+
+    #[tokio::test]
+    async fn b_leaves_solo_game_for_home() {
+        let mut app = test_app(MockApi {
+            solo_game: Some(game("solo-1", "SOLO", "IN_PROGRESS")),
+            ..MockApi::default()
+        });
+
+        press(&mut app, &[KeyCode::Enter, KeyCode::Char('b')]).await;
+
+        assert_eq!(app.screen, Screen::Home);
+    }
+
+    #[tokio::test]
+    async fn command_palette_opens_lobby() {
+        let mut app = test_app(MockApi::default());
+
+        let mut keys = vec![KeyCode::Char(':')];
+        keys.extend("pvp".chars().map(KeyCode::Char));
+        keys.push(KeyCode::Enter);
+        press(&mut app, &keys).await;
+
+        assert_eq!(app.screen, Screen::PvpLobby);
+        assert!(app.command_input.is_none());
+    }
+
+    #[tokio::test]
+    async fn accepting_a_rematch_swaps_seats() {
+        let mut rematch = game("pvp-2", "PVP", "IN_PROGRESS");
+        rematch.host_player_id = OPPONENT_ID.to_string();
+        rematch.guest_player_id = Some(PLAYER_ID.to_string());
+        let mut app = finished_pvp_app(MockApi {
+            rematch_game: Some(rematch),
+            ..MockApi::default()
+        });
+        app.rematch = Some(Rematch::Offered);
+
+        press(&mut app, &[KeyCode::Char('r')]).await;
+
+        assert_eq!(app.screen, Screen::PvpGame);
+        let game = app.pvp_game.as_ref().expect("rematch game");
+        assert_eq!(game.id, "pvp-2");
+        assert_eq!(app.player_symbol_for(game), "O");
+    }
+
+    #[tokio::test]
+    async fn rematch_without_server_support_hosts_a_new_game() {
+        let mut app = finished_pvp_app(MockApi {
+            pvp_game: Some(game("pvp-3", "PVP", STATUS_WAITING_FOR_PLAYER)),
+            ..MockApi::default()
+        });
+
+        press(&mut app, &[KeyCode::Char('r')]).await;
+
+        assert_eq!(app.screen, Screen::PvpGame);
+        assert_eq!(app.pvp_game.as_ref().map(|g| g.id.as_str()), Some("pvp-3"));
+    }
+
+    #[tokio::test]
+    async fn a_second_game_parks_the_first_until_switching_back() {
+        let mut app = test_app(MockApi {
+            pvp_game: Some(game("pvp-2", "PVP", STATUS_WAITING_FOR_PLAYER)),
+            ..MockApi::default()
+        });
+        let mut first = game("pvp-1", "PVP", "IN_PROGRESS");
+        first.guest_player_id = Some(OPPONENT_ID.to_string());
+        app.enter_pvp_game(first);
+
+        app.create_game("Second game", None, None).await;
+        assert_eq!(app.pvp_game.as_ref().map(|g| g.id.as_str()), Some("pvp-2"));
+        assert_eq!(app.parked_games.len(), 1);
+
+        press(&mut app, &[KeyCode::Char('n')]).await;
+        assert_eq!(app.screen, Screen::PvpGame);
+        assert_eq!(app.pvp_game.as_ref().map(|g| g.id.as_str()), Some("pvp-1"));
+        let parked: Vec<&str> = app
+            .parked_games
+            .iter()
+            .map(|p| p.game.id.as_str())
+            .collect();
+        assert_eq!(parked, ["pvp-2"]);
+    }
+
+    #[tokio::test]
+    async fn main_menu_key_in_the_replay_withdraws_my_rematch_request() {
+        let mut app = finished_pvp_app(MockApi::default());
+        app.rematch = Some(Rematch::Requested);
+        app.screen = Screen::Replay;
+
+        press(&mut app, &[KeyCode::Char('m')]).await;
+        assert_eq!(app.screen, Screen::Home);
+        assert!(app.rematch.is_none());
+    }
+
+    #[tokio::test]
+    async fn q_asks_first_while_a_parked_game_is_running() {
+        let mut app = test_app(MockApi::default());
+        let mut running = game("pvp-1", "PVP", "IN_PROGRESS");
+        running.guest_player_id = Some(OPPONENT_ID.to_string());
+        app.enter_pvp_game(running);
+        press(&mut app, &[KeyCode::Char('l')]).await;
+        assert_eq!(app.screen, Screen::PvpLobby);
+
+        press(&mut app, &[KeyCode::Char('q')]).await;
+        assert_eq!(app.confirm, Some(Confirm::QuitApp));
+        press(&mut app, &[KeyCode::Char('n'), KeyCode::Char('m')]).await;
+        assert_eq!(app.screen, Screen::Home);
+
+        press(&mut app, &[KeyCode::Char('q')]).await;
+        assert_eq!(app.confirm, Some(Confirm::QuitApp));
+        press(&mut app, &[KeyCode::Char('y')]).await;
+        assert!(app.should_quit);
+    }
+
+    #[tokio::test]
+    async fn main_menu_key_parks_a_running_game_and_q_asks_first() {
+        let mut app = test_app(MockApi::default());
+        let mut running = game("pvp-1", "PVP", "IN_PROGRESS");
+        running.guest_player_id = Some(OPPONENT_ID.to_string());
+        app.enter_pvp_game(running);
+
+        // `q` mid-game only asks; declining keeps the game on screen
+        press(&mut app, &[KeyCode::Char('q')]).await;
+        assert_eq!(app.confirm, Some(Confirm::QuitApp));
+        press(&mut app, &[KeyCode::Char('n')]).await;
+        assert!(!app.should_quit);
+        assert_eq!(app.screen, Screen::PvpGame);
+
+        // `m` goes Home without resigning
+        press(&mut app, &[KeyCode::Char('m')]).await;
+        assert_eq!(app.screen, Screen::Home);
+        assert!(app.confirm.is_none());
+        assert_eq!(app.parked_games.len(), 1);
+
+        // The parked game still runs, so `q` at Home asks too
+        press(&mut app, &[KeyCode::Char('q')]).await;
+        assert_eq!(app.confirm, Some(Confirm::QuitApp));
+    }
+
+    #[tokio::test]
+    async fn colon_q_mid_game_asks_first() {
+        let mut app = test_app(MockApi::default());
+        let mut running = game("pvp-1", "PVP", "IN_PROGRESS");
+        running.guest_player_id = Some(OPPONENT_ID.to_string());
+        app.enter_pvp_game(running);
+
+        press(
+            &mut app,
+            &[KeyCode::Char(':'), KeyCode::Char('q'), KeyCode::Enter],
+        )
+        .await;
+        assert_eq!(app.confirm, Some(Confirm::QuitApp));
+        assert!(!app.should_quit);
+        press(&mut app, &[KeyCode::Char('y')]).await;
+        assert!(app.should_quit);
+    }
+
+    #[tokio::test]
+    async fn q_on_settings_asks_first_while_a_parked_game_is_running() {
+        let mut app = test_app(MockApi::default());
+        let mut running = game("pvp-1", "PVP", "IN_PROGRESS");
+        running.guest_player_id = Some(OPPONENT_ID.to_string());
+        app.enter_pvp_game(running);
+        press(&mut app, &[KeyCode::Char('m')]).await;
+        app.open_settings();
+
+        press(&mut app, &[KeyCode::Char('q')]).await;
+        assert_eq!(app.confirm, Some(Confirm::QuitApp));
+        press(&mut app, &[KeyCode::Char('n')]).await;
+        assert!(!app.should_quit);
+        assert_eq!(app.screen, Screen::Settings);
+    }
+
+    #[tokio::test]
+    async fn error_screen_keeps_r_for_retry_and_x_for_resigning() {
+        let mut running = game("pvp-1", "PVP", "IN_PROGRESS");
+        running.guest_player_id = Some(OPPONENT_ID.to_string());
+        let mut app = test_app(MockApi {
+            pvp_game: Some(running.clone()),
+            ..MockApi::default()
+        });
+        let fail_mid_game = |app: &mut App<MockApi>| {
+            app.enter_pvp_game(running.clone());
+            let unavailable = ApiError::Server {
+                status: reqwest::StatusCode::SERVICE_UNAVAILABLE,
+                message: "Service Unavailable".to_string(),
             };
-            format!("Winner: {winner} ({outcome})")
-        } else {
-            "Result: Draw".to_string()
+            app.action_failed(
+                PendingAction::OpenLobby,
+                "Could not load the lobby",
+                unavailable,
+            );
+        };
+
+        fail_mid_game(&mut app);
+        let shown = screen_text(&app);
+        assert!(shown.contains("Press r to try again."), "{shown}");
+        assert!(shown.contains("Press x to resign the game"), "{shown}");
+        press(&mut app, &[KeyCode::Char('x')]).await;
+        assert_eq!(app.screen, Screen::Home);
+        assert_eq!(app.stats.lost, 1);
+        assert_eq!(app.head_to_head[OPPONENT_ID].lost, 1);
+
+        fail_mid_game(&mut app);
+        press(&mut app, &[KeyCode::Char('r')]).await;
+        assert_eq!(app.screen, Screen::PvpLobby);
+        assert_eq!(app.stats.lost, 1);
+    }
+
+    #[tokio::test]
+    async fn resigning_with_b_counts_against_the_opponent() {
+        let mut running = game("pvp-1", "PVP", "IN_PROGRESS");
+        running.guest_player_id = Some(OPPONENT_ID.to_string());
+        let mut app = test_app(MockApi {
+            pvp_game: Some(running.clone()),
+            ..MockApi::default()
+        });
+        app.enter_pvp_game(running);
+
+        press(&mut app, &[KeyCode::Char('b')]).await;
+        assert_eq!(app.confirm, Some(Confirm::LeaveGame));
+        press(&mut app, &[KeyCode::Char('y')]).await;
+        assert_eq!(app.screen, Screen::PvpLobby);
+        assert_eq!(app.stats.lost, 1);
+        assert_eq!(app.head_to_head[OPPONENT_ID].lost, 1);
+    }
+
+    #[tokio::test]
+    async fn a_game_deleted_on_the_server_leads_back_to_the_lobby() {
+        let mut app = test_app(MockApi::default());
+        let mut running = game("pvp-1", "PVP", "IN_PROGRESS");
+        running.guest_player_id = Some(OPPONENT_ID.to_string());
+        app.enter_pvp_game(running);
+        app.poll_interval = Duration::ZERO;
+
+        app.refresh_remote_state_if_needed().await;
+        assert_eq!(app.screen, Screen::Info);
+        assert!(app.info_message.starts_with("This game no longer exists."));
+        assert!(app.pvp_game.is_none());
+
+        press(&mut app, &[KeyCode::Char('l')]).await;
+        assert_eq!(app.screen, Screen::PvpLobby);
+    }
+
+    #[tokio::test]
+    async fn only_a_parked_game_is_announced_when_leaving() {
+        let mut app = test_app(MockApi::default());
+        let mut running = game("pvp-1", "PVP", "IN_PROGRESS");
+        running.guest_player_id = Some(OPPONENT_ID.to_string());
+        app.enter_pvp_game(running.clone());
+        app.toast = None;
+        app.park_and_leave(Screen::PvpLobby);
+        assert_eq!(app.parked_games.len(), 1);
+        assert!(app.toast.as_ref().unwrap().0.starts_with("Game parked"));
+
+        // The game ended before I left: nothing to park, nothing to announce
+        running.id = "pvp-2".to_string();
+        running.status = "DRAW".to_string();
+        app.enter_pvp_game(running);
+        app.toast = None;
+        app.park_and_leave(Screen::Home);
+        assert_eq!(app.parked_games.len(), 1);
+        assert!(app.pvp_game.is_none());
+        assert!(app.toast.is_none());
+        assert_eq!(app.screen, Screen::Home);
+    }
+
+    #[tokio::test]
+    async fn streamed_updates_replace_polling_until_the_stream_closes() {
+        let mut app = test_app(MockApi::default());
+        let mut running = game("pvp-1", "PVP", "IN_PROGRESS");
+        running.guest_player_id = Some(OPPONENT_ID.to_string());
+        app.enter_pvp_game(running.clone());
+        let (sender, updates) = tokio::sync::mpsc::unbounded_channel();
+        app.game_events = Some(GameEvents::from_channel("pvp-1", updates));
+
+        running.board[4] = Some("X".to_string());
+        running.board[0] = Some("O".to_string());
+        sender.send(running).unwrap();
+        app.apply_streamed_updates();
+        assert_eq!(app.pvp_game.as_ref().unwrap().moves_played(), 2);
+        assert!(app.game_events.is_some());
+
+        // A dropped connection hands the game back to polling, due right away
+        drop(sender);
+        app.apply_streamed_updates();
+        assert!(app.game_events.is_none());
+        assert_eq!(app.poll_interval, Duration::ZERO);
+
+        // ...and the stream is reopened a little later
+        app.api.subscribed.borrow_mut().clear();
+        app.resubscribe_at = Some(Instant::now());
+        app.apply_streamed_updates();
+        assert_eq!(*app.api.subscribed.borrow(), ["pvp-1"]);
+        assert!(app.resubscribe_at.is_none());
+    }
+
+    #[tokio::test]
+    async fn a_stream_that_never_delivered_is_not_reopened() {
+        let mut app = test_app(MockApi::default());
+        let mut running = game("pvp-1", "PVP", "IN_PROGRESS");
+        running.guest_player_id = Some(OPPONENT_ID.to_string());
+        app.enter_pvp_game(running);
+        let (sender, updates) = tokio::sync::mpsc::unbounded_channel::<ApiGame>();
+        app.game_events = Some(GameEvents::from_channel("pvp-1", updates));
+
+        drop(sender);
+        app.apply_streamed_updates();
+        assert!(app.game_events.is_none());
+        assert!(app.resubscribe_at.is_none());
+    }
+
+    // Everything the app would put on a 100x40 terminal, as plain text.
+    fn screen_text(app: &App<MockApi>) -> String {
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(100, 40)).unwrap();
+        terminal.draw(|frame| app.draw(frame)).unwrap();
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content()
+            .chunks(buffer.area.width as usize)
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>() + "\n")
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn privacy_mode_keeps_ids_off_the_game_screen() {
+        let mut app = test_app(MockApi::default());
+        let mut parked = game("backgrounded-game-id", "PVP", "IN_PROGRESS");
+        parked.name = None;
+        parked.guest_player_id = Some(OPPONENT_ID.to_string());
+        app.enter_pvp_game(parked);
+        app.park_current_game();
+        let mut running = game("secret-game-id", "PVP", "IN_PROGRESS");
+        running.name = None;
+        running.guest_player_id = Some(OPPONENT_ID.to_string());
+        app.enter_pvp_game(running);
+        app.privacy = true;
+
+        let shown = screen_text(&app);
+        assert!(shown.contains(ui::HIDDEN_ID));
+        press(&mut app, &[KeyCode::Char('s')]).await;
+        let invite = screen_text(&app);
+        assert!(invite.contains("--join"));
+        press(&mut app, &[KeyCode::Esc, KeyCode::Char('y')]).await;
+        let copied = screen_text(&app);
+        assert!(copied.contains("game id"));
+        for text in [shown, invite, copied] {
+            assert!(!text.contains("secret"), "{text}");
+            assert!(!text.contains("backgrounded"), "{text}");
+        }
+    }
+
+    #[tokio::test]
+    async fn privacy_mode_keeps_ids_off_game_over_and_the_vanished_game_screen() {
+        let mut app = test_app(MockApi::default());
+        let mut finished = game("secret-game-id", "PVP", "WON");
+        finished.guest_player_id = Some(OPPONENT_ID.to_string());
+        finished.winner = Some("X".to_string());
+        app.open_game_over(&finished, "PvP");
+        assert!(screen_text(&app).contains("Game id: secret-game-id"));
+        app.privacy = true;
+        let game_over = screen_text(&app);
+        assert!(game_over.contains(&format!("Game id: {}", ui::HIDDEN_ID)));
+
+        let mut running = game("secret-game-id", "PVP", "IN_PROGRESS");
+        running.guest_player_id = Some(OPPONENT_ID.to_string());
+        app.enter_pvp_game(running);
+        app.game_vanished("secret-game-id");
+        let vanished = screen_text(&app);
+        assert!(vanished.contains("no longer exists"));
+        assert!(vanished.contains(ui::HIDDEN_ID));
+        for text in [game_over, vanished] {
+            assert!(!text.contains("secret"), "{text}");
+        }
+    }
+
+    #[tokio::test]
+    async fn h_toggles_the_enter_hint_on_the_solo_board() {
+        let mut app = test_app(MockApi {
+            solo_game: Some(game("solo-1", "SOLO", "IN_PROGRESS")),
+            ..MockApi::default()
+        });
+        press(&mut app, &[KeyCode::Enter]).await;
+        assert!(!screen_text(&app).contains("Enter here"));
+
+        press(&mut app, &[KeyCode::Char('h')]).await;
+        assert!(screen_text(&app).contains("Enter here: Play here"));
+
+        press(&mut app, &[KeyCode::Char('h')]).await;
+        assert!(!screen_text(&app).contains("Enter here"));
+    }
+
+    #[tokio::test]
+    async fn enter_hint_shows_on_the_pvp_board() {
+        let mut app = test_app(MockApi::default());
+        let mut running = game("pvp-1", "PVP", "IN_PROGRESS");
+        running.guest_player_id = Some(OPPONENT_ID.to_string());
+        running.board[4] = Some("O".to_string());
+        app.enter_pvp_game(running);
+        press(&mut app, &[KeyCode::Char('h')]).await;
+        assert!(screen_text(&app).contains("Enter here: Play here"));
+
+        app.board_cursor = 4;
+        assert!(screen_text(&app).contains("Enter here: Occupied"));
+    }
+
+    #[tokio::test]
+    async fn polling_never_steps_back_to_an_older_board() {
+        let snapshot = |moves: &[usize], updated_at: &str| {
+            let mut snapshot = game("pvp-1", "PVP", "IN_PROGRESS");
+            snapshot.guest_player_id = Some(OPPONENT_ID.to_string());
+            snapshot.updated_at = Some(updated_at.to_string());
+            for (turn, &cell) in moves.iter().enumerate() {
+                snapshot.board[cell] = Some(if turn % 2 == 0 { "X" } else { "O" }.to_string());
+            }
+            snapshot
         };
+        // The server answers the poll with the board from before my last move
+        let mut app = test_app(MockApi {
+            polled_game: Some(snapshot(&[4], "2024-01-01T00:00:01.000Z")),
+            ..MockApi::default()
+        });
+        app.enter_pvp_game(snapshot(&[4, 0, 8], "2024-01-01T00:00:03.000Z"));
+        app.poll_interval = Duration::ZERO;
 
-        self.game_over_message = format!(
-            "{mode_label} game finished.\nGame id: {}\n{result_line}",
-            game.id
+        app.refresh_remote_state_if_needed().await;
+        assert_eq!(app.pvp_game.as_ref().unwrap().moves_played(), 3);
+    }
+
+    #[tokio::test]
+    async fn hosted_game_left_for_the_lobby_announces_my_turn() {
+        let mut joined = game("pvp-1", "PVP", "IN_PROGRESS");
+        joined.guest_player_id = Some(OPPONENT_ID.to_string());
+        let mut app = test_app(MockApi {
+            polled_game: Some(joined),
+            ..MockApi::default()
+        });
+        app.enter_pvp_game(game("pvp-1", "PVP", STATUS_WAITING_FOR_PLAYER));
+        press(&mut app, &[KeyCode::Char('b')]).await;
+        assert_eq!(app.screen, Screen::PvpLobby);
+        assert_eq!(app.parked_games.len(), 1);
+
+        // Someone joins while I'm away; the next poll of the parked game says so
+        app.parked_games[0].polled_at -= PARKED_POLL_INTERVAL;
+        app.poll_parked_games().await;
+        let toast = app.toast.as_ref().map(|(text, _)| text.as_str());
+        assert_eq!(
+            toast,
+            Some("Your turn in 'Test game' (Ctrl+N jumps there).")
         );
-        self.screen = Screen::GameOver;
+
+        app.screen = Screen::Home;
+        app.handle_key(KeyEvent::new(KeyCode::Char('n'), KeyModifiers::CONTROL))
+            .await;
+        assert_eq!(app.screen, Screen::PvpGame);
+        assert_eq!(app.pvp_game.as_ref().unwrap().status, "IN_PROGRESS");
+    }
+
+    #[tokio::test]
+    async fn local_solo_result_waits_for_the_server() {
+        let mut app = test_app(MockApi {
+            results_down: true,
+            ..MockApi::default()
+        });
+        app.settings.local_solo = true;
+        press(&mut app, &[KeyCode::Enter]).await;
+        assert_eq!(app.screen, Screen::SoloGame);
+
+        // Keep taking the first free cell; the computer answers each move right away.
+        while app.screen == Screen::SoloGame {
+            let board = &app.solo_game.as_ref().unwrap().board;
+            let free = board.iter().position(Option::is_none).unwrap();
+            let digit = char::from_digit(free as u32 + 1, 10).unwrap();
+            press(&mut app, &[KeyCode::Char(digit), KeyCode::Enter]).await;
+        }
+
+        assert_eq!(app.screen, Screen::GameOver);
+        assert_eq!(app.stats.won + app.stats.lost + app.stats.drawn, 1);
+        assert_eq!(app.pending_results.len(), 1);
+        assert!(app.api.reported.borrow().is_empty());
+
+        // Back online: the queued result goes up and the queue empties.
+        app.api.results_down = false;
+        app.upload_pending_results().await;
+        assert!(app.pending_results.is_empty());
+        assert_eq!(app.api.reported.borrow().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn a_server_without_the_results_endpoint_is_not_asked_again() {
+        let mut app = test_app(MockApi {
+            results_missing: true,
+            ..MockApi::default()
+        });
+        app.queue_result(&finished_game_with_line("XXXOO....", None));
+        app.upload_pending_results().await;
+        assert_eq!(app.pending_results.len(), 1);
+        let toast = &app.toast.as_ref().unwrap().0;
+        assert!(
+            toast.starts_with("This server doesn't accept results"),
+            "{toast}"
+        );
+
+        // Kept for a later launch, but not sent again this session
+        app.api.results_missing = false;
+        app.upload_pending_results().await;
+        assert_eq!(app.pending_results.len(), 1);
+        assert!(app.api.reported.borrow().is_empty());
+    }
+
+    #[test]
+    fn the_result_queue_drops_its_oldest_entries_when_full() {
+        let mut app = test_app(MockApi::default());
+        let mut finished = finished_game_with_line("XXXOO....", None);
+        app.queue_result(&finished);
+        let oldest = app.pending_results[0].clone();
+        app.pending_results = vec![oldest; MAX_PENDING_RESULTS];
+        app.pending_results[0].result_id = "solo-oldest".to_string();
+
+        finished.id = "solo-newest".to_string();
+        app.queue_result(&finished);
+        assert_eq!(app.pending_results.len(), MAX_PENDING_RESULTS);
+        assert_eq!(app.pending_results.last().unwrap().result_id, "solo-newest");
+        assert_ne!(app.pending_results[0].result_id, "solo-oldest");
+    }
+
+    #[tokio::test]
+    async fn practice_alternates_sides_locally_and_keeps_stats_untouched() {
+        let mut app = test_app(MockApi::default());
+        press(
+            &mut app,
+            &[KeyCode::Down, KeyCode::Down, KeyCode::Down, KeyCode::Enter],
+        )
+        .await;
+        assert_eq!(app.screen, Screen::SoloGame);
+
+        // X: 1 2 3 (top row), O: 4 5 in between.
+        for digit in ['1', '4', '2', '5', '3'] {
+            press(&mut app, &[KeyCode::Char(digit), KeyCode::Enter]).await;
+        }
+
+        assert_eq!(app.screen, Screen::GameOver);
+        assert!(app.game_over_message.contains("Winner: X"));
+        assert!(app.game_over_message.contains("Won after 5 moves."));
+        assert!(app.game_over_message.ends_with("Won on the top row."));
+        assert_eq!(app.stats.won + app.stats.lost + app.stats.drawn, 0);
+    }
+
+    #[tokio::test]
+    async fn digit_shortcut_skips_filled_cells() {
+        let mut app = test_app(MockApi::default());
+        app.start_practice_game();
+        press(&mut app, &[KeyCode::Char('5'), KeyCode::Enter]).await;
+        press(&mut app, &[KeyCode::Char('1')]).await;
+
+        press(&mut app, &[KeyCode::Char('5')]).await;
+        assert_eq!(app.board_cursor, 0);
+        let (message, _) = app.toast.as_ref().unwrap();
+        assert_eq!(message, "That cell is already taken.");
+
+        // Arrows still cross filled cells
+        press(&mut app, &[KeyCode::Down, KeyCode::Right]).await;
+        assert_eq!(app.board_cursor, 4);
+    }
+
+    #[tokio::test]
+    async fn analysis_mode_previews_without_playing() {
+        let mut app = test_app(MockApi::default());
+        app.start_practice_game();
+        // X: 1 2, O: 4 5, so X completes the top row at 3.
+        for digit in ['1', '4', '2', '5'] {
+            press(&mut app, &[KeyCode::Char(digit), KeyCode::Enter]).await;
+        }
+
+        press(
+            &mut app,
+            &[KeyCode::Char('a'), KeyCode::Char('3'), KeyCode::Enter],
+        )
+        .await;
+        let game = app.solo_game.as_ref().unwrap();
+        assert_eq!(game.board[2], None);
+        assert_eq!(app.board_view().ghost, Some((2, "X".to_string())));
+        assert!(app.analysis_note().ends_with("(X here wins the game)"));
+
+        press(&mut app, &[KeyCode::Char('a'), KeyCode::Enter]).await;
+        assert_eq!(app.screen, Screen::GameOver);
+    }
+
+    #[tokio::test]
+    async fn analysis_mode_stays_with_its_game() {
+        let mut app = test_app(MockApi::default());
+        app.start_practice_game();
+        press(&mut app, &[KeyCode::Char('a'), KeyCode::Char('m')]).await;
+        assert!(app.analysis);
+        app.start_practice_game();
+        assert!(!app.analysis);
+
+        let mut first = game("pvp-1", "PVP", "IN_PROGRESS");
+        first.guest_player_id = Some(OPPONENT_ID.to_string());
+        let second = ApiGame {
+            id: "pvp-2".to_string(),
+            ..first.clone()
+        };
+        app.enter_pvp_game(first);
+        press(&mut app, &[KeyCode::Char('a')]).await;
+        assert!(app.analysis);
+        app.enter_pvp_game(second);
+        assert!(!app.analysis);
+        // Back to the parked first game: moves are on there too
+        press(&mut app, &[KeyCode::Char('n')]).await;
+        assert_eq!(app.pvp_game.as_ref().unwrap().id, "pvp-1");
+        assert!(!app.analysis);
+    }
+
+    #[tokio::test]
+    async fn coordinates_move_the_cursor() {
+        let mut app = test_app(MockApi::default());
+        app.start_practice_game();
+
+        press(&mut app, &[KeyCode::Char('B'), KeyCode::Char('2')]).await;
+        assert_eq!(app.board_cursor, 4);
+
+        // A letter alone picks the column in the current row; a digit alone still jumps by number.
+        press(&mut app, &[KeyCode::Char('C')]).await;
+        assert_eq!(app.board_cursor, 5);
+        press(&mut app, &[KeyCode::Char('7')]).await;
+        assert_eq!(app.board_cursor, 6);
     }
 }
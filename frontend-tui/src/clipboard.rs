@@ -0,0 +1,12 @@
+use std::io::{self, Write};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+// Copies text using the OSC 52 terminal escape sequence.
+// No system clipboard library needed: the terminal itself owns the clipboard,
+// which also works over SSH. Terminals without OSC 52 support simply ignore it.
+pub fn copy_to_clipboard(text: &str) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    write!(stdout, "\x1b]52;c;{}\x07", STANDARD.encode(text))?;
+    stdout.flush()
+}
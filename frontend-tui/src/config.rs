@@ -0,0 +1,131 @@
+use std::{num::NonZeroUsize, path::PathBuf, str::FromStr};
+
+use anyhow::{Context, Result};
+
+use crate::{metrics::MetricsTarget, secret::Secret};
+
+pub const DEFAULT_BASE_URL: &str = "http://localhost:3000";
+// Sent as `clientName` when creating solo games, unless `--client-name` says otherwise.
+pub const DEFAULT_CLIENT_NAME: &str = "rust-tui-client";
+// Open games fetched per lobby page, unless `--lobby-page` says otherwise.
+pub const DEFAULT_LOBBY_PAGE: usize = 20;
+
+// Runtime options parsed from command-line flags.
+// Think of this like a small `process.argv` parser producing a typed options object.
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    // `None` when `--server` wasn't given, so the saved setting (or the default) applies.
+    pub base_url: Option<String>,
+    pub bell: bool,
+    // Distinct bell patterns per event (see `sound`), each one mutable in Settings.
+    pub sound: bool,
+    pub numpad: bool,
+    pub confirm_moves: bool,
+    // Accessibility mode for this run, on top of the saved setting.
+    pub accessible: bool,
+    // Seconds the opponent may sit on their turn before the app offers to claim the win.
+    pub idle_grace_secs: Option<u64>,
+    // Seconds the health check may take before the server counts as offline.
+    pub health_timeout_secs: Option<u64>,
+    // Seconds the Game Over screen waits for a key before going back Home; `None` stays put.
+    pub autoreturn_secs: Option<u64>,
+    // Seconds without a key press on Home before the screensaver starts; `None` never starts it.
+    pub screensaver_secs: Option<u64>,
+    // Seeds the local computer opponent's choices; `None` picks a new seed per game.
+    pub seed: Option<u64>,
+    // Open games the lobby fetches at a time; "Load more" gets the next batch.
+    pub lobby_page: Option<usize>,
+    pub join_game_id: Option<String>,
+    pub join_password: Option<Secret<String>>,
+    // Identifies this client to the server, e.g. one name per machine.
+    pub client_name: Option<String>,
+    // Shown instead of the server's name for the solo computer opponent.
+    pub opponent_name: Option<String>,
+    // Where `e` writes game exports; `None` means `exports/` next to the settings file.
+    pub export_dir: Option<PathBuf>,
+    // Opt-in usage counters (`--metrics`); `None` records nothing.
+    pub metrics: Option<MetricsTarget>,
+}
+
+impl Config {
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self> {
+        let mut config = Self::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--bell" => config.bell = true,
+                "--sound" => config.sound = true,
+                "--numpad" => config.numpad = true,
+                "--confirm-moves" => config.confirm_moves = true,
+                "--accessible" => config.accessible = true,
+                "--server" => {
+                    let url = flag_value(&arg, args.next())?;
+                    config.base_url = Some(normalize_base_url(&url));
+                }
+                "--idle-grace" => {
+                    config.idle_grace_secs = Some(parse_flag(&arg, args.next(), "seconds")?);
+                }
+                "--health-timeout" => {
+                    config.health_timeout_secs = Some(parse_flag(&arg, args.next(), "seconds")?);
+                }
+                "--autoreturn" => {
+                    config.autoreturn_secs = Some(parse_flag(&arg, args.next(), "seconds")?);
+                }
+                "--screensaver" => {
+                    config.screensaver_secs = Some(parse_flag(&arg, args.next(), "seconds")?);
+                }
+                "--seed" => config.seed = Some(parse_flag(&arg, args.next(), "a number")?),
+                "--metrics" => {
+                    let target = flag_value(&arg, args.next())?;
+                    config.metrics = Some(MetricsTarget::parse(&target)?);
+                }
+                "--lobby-page" => {
+                    let size: NonZeroUsize = parse_flag(&arg, args.next(), "a positive number")?;
+                    config.lobby_page = Some(size.get());
+                }
+                "--join" => config.join_game_id = Some(flag_value(&arg, args.next())?),
+                "--password" => {
+                    config.join_password = Some(Secret::new(flag_value(&arg, args.next())?))
+                }
+                "--client-name" => config.client_name = Some(flag_value(&arg, args.next())?),
+                "--opponent-name" => config.opponent_name = Some(flag_value(&arg, args.next())?),
+                "--export-dir" => {
+                    config.export_dir = Some(PathBuf::from(flag_value(&arg, args.next())?));
+                }
+                other => anyhow::bail!("unknown argument: {other}"),
+            }
+        }
+
+        Ok(config)
+    }
+
+    pub fn client_name(&self) -> &str {
+        self.client_name.as_deref().unwrap_or(DEFAULT_CLIENT_NAME)
+    }
+
+    pub fn lobby_page(&self) -> usize {
+        self.lobby_page.unwrap_or(DEFAULT_LOBBY_PAGE)
+    }
+}
+
+// Trailing slashes would produce `//games/...` paths when endpoints are appended.
+pub fn normalize_base_url(url: &str) -> String {
+    url.trim().trim_end_matches('/').to_string()
+}
+
+fn flag_value(flag: &str, value: Option<String>) -> Result<String> {
+    value.with_context(|| format!("{flag} expects a value"))
+}
+
+// `flag`'s value parsed as a `T`; `what` names the expected kind of value in the error.
+fn parse_flag<T>(flag: &str, value: Option<String>, what: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    let value = flag_value(flag, value)?;
+    value
+        .parse()
+        .with_context(|| format!("{flag} expects {what}, got {value}"))
+}
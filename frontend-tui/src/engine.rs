@@ -0,0 +1,110 @@
+use rand::{seq::IndexedRandom, Rng};
+
+use crate::models::{completed_line, ApiGame, BOARD_CELLS, WINNING_LINES};
+
+// Local game rules, for games that never touch the server (practice and local solo games).
+// Mirrors what the backend does after each move: place the mark, then check for a win,
+// a draw, or hand the turn to the other symbol.
+
+// Plays `index` for whoever's turn it is. `Err` explains why the move isn't allowed.
+pub fn play(game: &mut ApiGame, index: usize) -> Result<(), &'static str> {
+    if game.status != "IN_PROGRESS" {
+        return Err("The game is over.");
+    }
+    if index >= BOARD_CELLS {
+        return Err("There is no such cell.");
+    }
+    if game.board[index].is_some() {
+        return Err("That cell is already taken.");
+    }
+
+    game.board[index] = Some(game.current_turn.clone());
+
+    if let Some(line) = completed_line(&game.board) {
+        game.status = "WON".to_string();
+        game.winner = game.board[line[0]].clone();
+        game.win_reason = Some("LINE".to_string());
+        game.winning_line = Some(line.to_vec());
+    } else if game.board.iter().all(Option::is_some) {
+        game.status = "DRAW".to_string();
+    } else {
+        game.current_turn = if game.current_turn == "X" { "O" } else { "X" }.to_string();
+    }
+    Ok(())
+}
+
+// The computer's move for `symbol`, with the backend's strategy so local solo games play alike:
+// win now, block the opponent's win, take the center, a corner, then any free cell.
+// Where a step offers several cells (two ways to win, which corner), `rng` picks one; the
+// same seeded `rng` and the same moves give the same answers (`--seed`).
+pub fn computer_move(board: &[Option<String>], symbol: &str, rng: &mut impl Rng) -> Option<usize> {
+    let opponent = if symbol == "X" { "O" } else { "X" };
+    let free = |cells: &[usize]| -> Vec<usize> {
+        cells
+            .iter()
+            .copied()
+            .filter(|&cell| board[cell].is_none())
+            .collect()
+    };
+    let all_cells: Vec<usize> = (0..BOARD_CELLS).collect();
+    [
+        finishing_moves(board, symbol),
+        finishing_moves(board, opponent),
+        free(&[4]),
+        free(&[0, 2, 6, 8]),
+        free(&all_cells),
+    ]
+    .into_iter()
+    .find(|cells| !cells.is_empty())
+    .and_then(|cells| cells.choose(rng).copied())
+}
+
+// Empty cells that complete a line holding two of `symbol`'s marks.
+fn finishing_moves(board: &[Option<String>], symbol: &str) -> Vec<usize> {
+    let mut cells: Vec<usize> = WINNING_LINES
+        .into_iter()
+        .filter(|line| {
+            line.iter()
+                .filter(|&&cell| board[cell].as_deref() == Some(symbol))
+                .count()
+                == 2
+        })
+        .filter_map(|line| line.into_iter().find(|&cell| board[cell].is_none()))
+        .collect();
+    cells.sort_unstable();
+    cells.dedup();
+    cells
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn same_seed_gives_the_same_replies() {
+        // Only corners are left after X takes the center: the seed picks which one
+        let mut board = vec![None; BOARD_CELLS];
+        board[4] = Some("X".to_string());
+
+        let replies = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            (0..8)
+                .map(|_| computer_move(&board, "O", &mut rng).unwrap())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(replies(7), replies(7));
+        assert!(replies(7).iter().all(|cell| [0, 2, 6, 8].contains(cell)));
+    }
+
+    #[test]
+    fn winning_beats_blocking() {
+        let mut board = vec![None; BOARD_CELLS];
+        for (cell, mark) in [(0, "O"), (1, "O"), (3, "X"), (4, "X")] {
+            board[cell] = Some(mark.to_string());
+        }
+        let mut rng = StdRng::seed_from_u64(1);
+        assert_eq!(computer_move(&board, "O", &mut rng), Some(2));
+    }
+}
@@ -0,0 +1,164 @@
+use std::time::Duration;
+
+use reqwest::{header::ACCEPT, Client};
+use tokio::{
+    sync::mpsc::{self, error::TryRecvError, UnboundedReceiver, UnboundedSender},
+    task::JoinHandle,
+};
+
+use crate::models::ApiGame;
+
+// Live updates of one game over Server-Sent Events (`GET /games/:gameId/events`). A background
+// task reads the stream and hands every game state it carries to the run loop through a channel;
+// the channel closing (no such endpoint, dropped connection, unreadable stream) is the app's cue
+// to go back to polling.
+
+// The server sends at least a heartbeat every 10s; a stream silent for this long is taken for a
+// dead connection (a half-open socket after a network change never reports an error).
+const SILENCE_LIMIT: Duration = Duration::from_secs(30);
+
+pub struct GameEvents {
+    game_id: String,
+    updates: UnboundedReceiver<ApiGame>,
+    task: JoinHandle<()>,
+    // At least one game state came through, so the server does have the endpoint.
+    delivered: bool,
+}
+
+// What the stream had to say since the last look.
+#[derive(Debug)]
+pub enum StreamUpdate {
+    Game(Box<ApiGame>),
+    Nothing,
+    Closed,
+}
+
+impl GameEvents {
+    // Starts listening right away; the first event is the game as it is now.
+    pub fn subscribe(client: Client, base_url: &str, game_id: &str) -> Self {
+        let url = format!("{base_url}/games/{game_id}/events");
+        let (sender, updates) = mpsc::unbounded_channel();
+        Self {
+            game_id: game_id.to_string(),
+            updates,
+            task: tokio::spawn(listen(client, url, sender)),
+            delivered: false,
+        }
+    }
+
+    // A stream fed by a test instead of a server.
+    #[cfg(test)]
+    pub fn from_channel(game_id: &str, updates: UnboundedReceiver<ApiGame>) -> Self {
+        Self {
+            game_id: game_id.to_string(),
+            updates,
+            task: tokio::spawn(async {}),
+            delivered: false,
+        }
+    }
+
+    pub fn game_id(&self) -> &str {
+        &self.game_id
+    }
+
+    pub fn delivered(&self) -> bool {
+        self.delivered
+    }
+
+    // Never waits: the run loop calls this between key presses.
+    pub fn next_update(&mut self) -> StreamUpdate {
+        match self.updates.try_recv() {
+            Ok(game) => {
+                self.delivered = true;
+                StreamUpdate::Game(Box::new(game))
+            }
+            Err(TryRecvError::Empty) => StreamUpdate::Nothing,
+            Err(TryRecvError::Disconnected) => StreamUpdate::Closed,
+        }
+    }
+}
+
+// Leaving the game hangs up the connection too.
+impl Drop for GameEvents {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+// Any failure just ends the task; dropping `sender` closes the channel, which the app notices.
+async fn listen(client: Client, url: String, sender: UnboundedSender<ApiGame>) {
+    let request = client.get(url).header(ACCEPT, "text/event-stream");
+    let Ok(mut response) = request.send().await else {
+        return;
+    };
+    // Older servers answer 404 here; a proxy might answer with anything.
+    let is_stream = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("text/event-stream"));
+    if !response.status().is_success() || !is_stream {
+        return;
+    }
+
+    let mut parser = EventParser::default();
+    while let Ok(Ok(Some(chunk))) = tokio::time::timeout(SILENCE_LIMIT, response.chunk()).await {
+        for data in parser.feed(&chunk) {
+            // An event that isn't a game (a heartbeat, a newer server's extra event) is skipped;
+            // it still counts as a sign of life.
+            let Ok(game) = serde_json::from_str::<ApiGame>(&data) else {
+                continue;
+            };
+            if sender.send(game).is_err() {
+                return;
+            }
+        }
+    }
+}
+
+// Splits the raw stream into events. Chunks can end anywhere, even inside a UTF-8 character, so
+// bytes wait in `pending` until their line is complete.
+#[derive(Default)]
+struct EventParser {
+    pending: Vec<u8>,
+    data: Vec<String>,
+}
+
+impl EventParser {
+    // The `data` of every event completed by `chunk`. An event's `data:` lines are joined with
+    // newlines and it ends at a blank line; other fields (`id:`, `event:`, comments) are ignored.
+    fn feed(&mut self, chunk: &[u8]) -> Vec<String> {
+        self.pending.extend_from_slice(chunk);
+        let mut events = Vec::new();
+        while let Some(end) = self.pending.iter().position(|&byte| byte == b'\n') {
+            let line: Vec<u8> = self.pending.drain(..=end).collect();
+            let line = String::from_utf8_lossy(&line);
+            let line = line.trim_end_matches(['\n', '\r']);
+            if line.is_empty() {
+                if !self.data.is_empty() {
+                    events.push(self.data.join("\n"));
+                    self.data.clear();
+                }
+            } else if let Some(value) = line.strip_prefix("data:") {
+                self.data
+                    .push(value.strip_prefix(' ').unwrap_or(value).to_string());
+            }
+        }
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn events_are_put_back_together_across_chunks() {
+        let mut parser = EventParser::default();
+        assert!(parser
+            .feed(b": keep-alive\n\nid: 1\ndata: {\"a\":")
+            .is_empty());
+        assert_eq!(parser.feed(b"1}\r\n\r\ndata:x\ndata: y\n"), ["{\"a\":1}"]);
+        assert_eq!(parser.feed(b"\n"), ["x\ny"]);
+    }
+}
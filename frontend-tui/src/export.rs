@@ -0,0 +1,40 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use crate::models::{ApiGame, MoveRecord};
+
+// What ends up in an export file: the server's view of the game plus the moves we saw locally.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GameExport<'a> {
+    // Seconds since the Unix epoch, same value as in the file name.
+    exported_at: u64,
+    game: &'a ApiGame,
+    moves: &'a [MoveRecord],
+}
+
+// Writes `game-<first 8 id chars>-<unix seconds>.json` into `dir` (created if missing)
+// and returns the path, so the user can attach it to a bug report.
+pub fn export_game(dir: &Path, game: &ApiGame, moves: &[MoveRecord]) -> Result<PathBuf> {
+    let exported_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default();
+    let short_id: String = game.id.chars().take(8).collect();
+    let path = dir.join(format!("game-{short_id}-{exported_at}.json"));
+
+    fs::create_dir_all(dir).with_context(|| format!("could not create {}", dir.display()))?;
+    let raw = serde_json::to_string_pretty(&GameExport {
+        exported_at,
+        game,
+        moves,
+    })?;
+    fs::write(&path, raw).with_context(|| format!("could not write {}", path.display()))?;
+    Ok(path)
+}
@@ -0,0 +1,224 @@
+// Key hints for the screens' help footers. Each hint says when its key does something, so a
+// footer only lists what can be used right now; a new key only needs a row in the table of its
+// screen to show up in the help.
+
+// What the footers need to know about the game on screen (or the one just finished).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HelpState {
+    // I hold a seat (or both, in practice) and the game is still running.
+    pub playing: bool,
+    pub my_turn: bool,
+    pub pvp: bool,
+    // The game lives on the server (not a local solo or practice game), so it can be refetched.
+    pub online: bool,
+    // Analysis mode is on, so Enter plays nothing.
+    pub analysis: bool,
+    // Game Over only: moves were tracked, a rematch can be asked for, one is being negotiated,
+    // and other PvP games of mine are running.
+    pub can_replay: bool,
+    pub can_rematch: bool,
+    pub rematch_pending: bool,
+    pub other_games: bool,
+}
+
+pub struct KeyHint {
+    pub keys: &'static str,
+    pub action: &'static str,
+    pub shown: fn(&HelpState) -> bool,
+}
+
+fn always(_: &HelpState) -> bool {
+    true
+}
+
+pub const GAME_KEYS: &[KeyHint] = &[
+    KeyHint {
+        keys: "Enter/Space",
+        action: "move",
+        shown: |state| state.my_turn && !state.analysis,
+    },
+    KeyHint {
+        keys: "a",
+        action: "analysis on/off",
+        shown: |state| state.playing,
+    },
+    KeyHint {
+        keys: "c",
+        action: "toggle confirm",
+        shown: |state| state.playing,
+    },
+    KeyHint {
+        keys: "s",
+        action: "share invite",
+        shown: |state| state.pvp,
+    },
+    KeyHint {
+        keys: "y",
+        action: "copy id",
+        shown: |state| state.pvp,
+    },
+    KeyHint {
+        keys: "e",
+        action: "export",
+        shown: always,
+    },
+    KeyHint {
+        keys: "h",
+        action: "Enter hint on/off",
+        shown: always,
+    },
+    KeyHint {
+        keys: "R",
+        action: "resync",
+        shown: |state| state.online,
+    },
+    // Unlike `b`, never resigns: a running PvP game is parked
+    KeyHint {
+        keys: "m",
+        action: "main menu",
+        shown: always,
+    },
+    KeyHint {
+        keys: "l",
+        action: "lobby (game keeps running)",
+        shown: |state| state.pvp,
+    },
+    KeyHint {
+        keys: "n",
+        action: "next parked game",
+        shown: |state| state.pvp,
+    },
+    // Leaving a running PvP game resigns it (after asking)
+    KeyHint {
+        keys: "b",
+        action: "forfeit",
+        shown: |state| state.pvp && state.playing,
+    },
+    KeyHint {
+        keys: "b",
+        action: "back",
+        shown: |state| !(state.pvp && state.playing),
+    },
+    KeyHint {
+        keys: "q",
+        action: "exit",
+        shown: always,
+    },
+];
+
+pub const GAME_OVER_KEYS: &[KeyHint] = &[
+    KeyHint {
+        keys: "Enter/b/m",
+        action: "main menu",
+        shown: always,
+    },
+    KeyHint {
+        keys: "r",
+        action: "rematch",
+        shown: |state| state.can_rematch,
+    },
+    KeyHint {
+        keys: "c",
+        action: "cancel rematch",
+        shown: |state| state.rematch_pending,
+    },
+    KeyHint {
+        keys: "v",
+        action: "replay",
+        shown: |state| state.can_replay,
+    },
+    KeyHint {
+        keys: "Up/Down",
+        action: "scroll recap",
+        shown: |state| state.can_replay,
+    },
+    KeyHint {
+        keys: "n",
+        action: "next game",
+        shown: |state| state.other_games,
+    },
+    KeyHint {
+        keys: "e",
+        action: "export",
+        shown: always,
+    },
+    KeyHint {
+        keys: "y",
+        action: "copy board",
+        shown: always,
+    },
+    KeyHint {
+        keys: "q",
+        action: "exit",
+        shown: always,
+    },
+];
+
+// What pressing Enter on the hovered cell would do right now, for the game screen's `h` hint.
+pub fn enter_effect(state: &HelpState, game_over: bool, occupied: bool) -> &'static str {
+    if game_over {
+        "Game is over"
+    } else if occupied {
+        "Occupied"
+    } else if !state.my_turn {
+        "Not your turn"
+    } else if state.analysis {
+        "Nothing, analysis mode is on (a to play)"
+    } else {
+        "Play here"
+    }
+}
+
+// "key = action, ..." for the hints of `keys` that `state` allows.
+pub fn footer(keys: &[KeyHint], state: &HelpState) -> String {
+    keys.iter()
+        .filter(|hint| (hint.shown)(state))
+        .map(|hint| format!("{} = {}", hint.keys, hint.action))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn game_footer_follows_the_turn() {
+        let mut state = HelpState {
+            playing: true,
+            pvp: true,
+            online: true,
+            ..HelpState::default()
+        };
+        assert_eq!(
+            footer(GAME_KEYS, &state),
+            "a = analysis on/off, c = toggle confirm, s = share invite, y = copy id, e = export, \
+             h = Enter hint on/off, R = resync, m = main menu, l = lobby (game keeps running), \
+             n = next parked game, b = forfeit, q = exit"
+        );
+
+        state.my_turn = true;
+        assert!(footer(GAME_KEYS, &state).starts_with("Enter/Space = move, "));
+        state.analysis = true;
+        assert!(footer(GAME_KEYS, &state).starts_with("a = analysis on/off, "));
+
+        state.playing = false;
+        state.my_turn = false;
+        assert!(footer(GAME_KEYS, &state).contains("b = back"));
+    }
+
+    #[test]
+    fn enter_effect_explains_why_nothing_happens() {
+        let mut state = HelpState {
+            playing: true,
+            my_turn: true,
+            ..HelpState::default()
+        };
+        assert_eq!(enter_effect(&state, false, false), "Play here");
+        assert_eq!(enter_effect(&state, false, true), "Occupied");
+        assert_eq!(enter_effect(&state, true, false), "Game is over");
+
+        state.my_turn = false;
+        assert_eq!(enter_effect(&state, false, false), "Not your turn");
+    }
+}
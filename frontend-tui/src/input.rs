@@ -0,0 +1,109 @@
+use crossterm::event::KeyCode;
+
+// Single-line text field with a caret, like a controlled <input> in React.
+// The caret counts characters, not bytes, so typing accents or emoji never splits one.
+#[derive(Debug, Clone, Default)]
+pub struct TextInput {
+    value: String,
+    caret: usize,
+}
+
+impl TextInput {
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    // Position of the caret in characters (0 = before the first one).
+    pub fn caret(&self) -> usize {
+        self.caret
+    }
+
+    pub fn clear(&mut self) {
+        self.value.clear();
+        self.caret = 0;
+    }
+
+    // Replaces the text (e.g. a saved setting being edited) with the caret at the end.
+    pub fn set(&mut self, value: &str) {
+        self.value = value.to_string();
+        self.caret = value.chars().count();
+    }
+
+    // Applies an editing key: typing inserts at the caret (up to `max_chars`), Backspace/Delete
+    // remove around it, and Left/Right/Home/End move it. Returns false for keys it doesn't use.
+    pub fn handle_key(&mut self, key: KeyCode, max_chars: usize) -> bool {
+        let len = self.value.chars().count();
+        match key {
+            KeyCode::Char(ch) => {
+                if len < max_chars {
+                    let at = self.byte_index(self.caret);
+                    self.value.insert(at, ch);
+                    self.caret += 1;
+                }
+            }
+            KeyCode::Backspace if self.caret > 0 => {
+                self.caret -= 1;
+                let at = self.byte_index(self.caret);
+                self.value.remove(at);
+            }
+            KeyCode::Delete if self.caret < len => {
+                let at = self.byte_index(self.caret);
+                self.value.remove(at);
+            }
+            KeyCode::Left => self.caret = self.caret.saturating_sub(1),
+            KeyCode::Right => self.caret = (self.caret + 1).min(len),
+            KeyCode::Home => self.caret = 0,
+            KeyCode::End => self.caret = len,
+            KeyCode::Backspace | KeyCode::Delete => {}
+            _ => return false,
+        }
+        true
+    }
+
+    fn byte_index(&self, chars: usize) -> usize {
+        self.value
+            .char_indices()
+            .nth(chars)
+            .map_or(self.value.len(), |(idx, _)| idx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn typed(text: &str) -> TextInput {
+        let mut input = TextInput::default();
+        for ch in text.chars() {
+            input.handle_key(KeyCode::Char(ch), 40);
+        }
+        input
+    }
+
+    #[test]
+    fn inserts_and_deletes_at_the_caret() {
+        let mut input = typed("Fridy");
+        input.handle_key(KeyCode::Left, 40);
+        input.handle_key(KeyCode::Char('a'), 40);
+        assert_eq!(input.value(), "Friday");
+        assert_eq!(input.caret(), 5);
+
+        input.handle_key(KeyCode::Home, 40);
+        input.handle_key(KeyCode::Delete, 40);
+        input.handle_key(KeyCode::End, 40);
+        input.handle_key(KeyCode::Backspace, 40);
+        assert_eq!(input.value(), "rida");
+    }
+
+    #[test]
+    fn respects_max_chars_and_multibyte_text() {
+        let mut input = typed("héllo");
+        assert!(input.handle_key(KeyCode::Char('!'), 5));
+        assert_eq!(input.value(), "héllo");
+
+        input.handle_key(KeyCode::Home, 5);
+        input.handle_key(KeyCode::Right, 5);
+        input.handle_key(KeyCode::Delete, 5);
+        assert_eq!(input.value(), "hllo");
+    }
+}
@@ -0,0 +1,28 @@
+use ratatui::layout::Rect;
+
+// Smallest popup/screen box we try to keep, so borders and a line of text still fit.
+const MIN_WIDTH: u16 = 20;
+const MIN_HEIGHT: u16 = 5;
+
+// Returns a box of `percent_x`% by `percent_y`% of `area`, centered inside it.
+// Sizes are rounded to whole cells and never drop below MIN_WIDTH x MIN_HEIGHT,
+// unless `area` itself is smaller, in which case the whole area is used.
+// The leftover space is split evenly, the odd cell going to the right/bottom.
+pub fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
+    let width = scaled_length(area.width, percent_x, MIN_WIDTH);
+    let height = scaled_length(area.height, percent_y, MIN_HEIGHT);
+
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+fn scaled_length(total: u16, percent: u16, min: u16) -> u16 {
+    let percent = u32::from(percent.min(100));
+    // Round to the nearest cell instead of truncating.
+    let scaled = (u32::from(total) * percent + 50) / 100;
+    (scaled as u16).max(min).min(total)
+}
@@ -0,0 +1,22 @@
+// The app's modules, as a library: `main.rs` runs it, and the benches under `benches/` can reach
+// the pure parts (like the board renderer) without a terminal.
+
+mod api;
+pub mod app;
+mod clipboard;
+pub mod config;
+mod engine;
+mod events;
+mod export;
+mod help;
+mod input;
+mod layout;
+mod metrics;
+pub mod models;
+mod nav;
+mod results;
+mod secret;
+pub mod settings;
+mod sound;
+pub mod theme;
+pub mod ui;
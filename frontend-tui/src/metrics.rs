@@ -0,0 +1,164 @@
+use std::{fs, path::PathBuf, time::Duration};
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+// Opt-in client counters for people running their own server (`--metrics <target>`).
+// Without the flag nothing is counted, written or sent anywhere.
+
+// Where the counters go. A statsd endpoint is the only target off this machine.
+#[derive(Debug, Clone)]
+pub enum MetricsTarget {
+    // JSON file with the running totals, rewritten after every event and kept across runs.
+    File(PathBuf),
+    // `host:port` of a statsd server, sent plain UDP counters and timings.
+    Statsd(String),
+}
+
+impl MetricsTarget {
+    // `statsd://host:port`, or anything else as a file path.
+    pub fn parse(raw: &str) -> Result<Self> {
+        match raw.strip_prefix("statsd://") {
+            Some(addr) => Ok(MetricsTarget::Statsd(addr.to_string())),
+            None if raw.is_empty() => bail!("--metrics expects a file path or statsd://host:port"),
+            None => Ok(MetricsTarget::File(PathBuf::from(raw))),
+        }
+    }
+}
+
+// Totals since the metrics file was first written. The average is derived from the two
+// latency fields when saving, so readers of the file don't have to.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase", default)]
+struct Counters {
+    games_created: u64,
+    games_played: u64,
+    moves_made: u64,
+    latency_total_ms: u64,
+    latency_samples: u64,
+    average_latency_ms: Option<u64>,
+}
+
+pub struct Metrics {
+    target: Option<MetricsTarget>,
+    counters: Counters,
+    socket: Option<std::net::UdpSocket>,
+}
+
+impl Metrics {
+    // `None` gives a recorder that ignores every event.
+    pub fn new(target: Option<MetricsTarget>) -> Self {
+        let counters = match &target {
+            Some(MetricsTarget::File(path)) => fs::read_to_string(path)
+                .ok()
+                .and_then(|raw| serde_json::from_str(&raw).ok())
+                .unwrap_or_default(),
+            _ => Counters::default(),
+        };
+        let socket = match &target {
+            Some(MetricsTarget::Statsd(addr)) => std::net::UdpSocket::bind("0.0.0.0:0")
+                .and_then(|socket| socket.connect(addr).map(|()| socket))
+                .ok(),
+            _ => None,
+        };
+        Self {
+            target,
+            counters,
+            socket,
+        }
+    }
+
+    // A solo or PvP game was started or hosted.
+    pub fn game_created(&mut self) {
+        self.counters.games_created += 1;
+        self.emit("games_created:1|c");
+    }
+
+    // A game reached the Game Over screen.
+    pub fn game_played(&mut self) {
+        self.counters.games_played += 1;
+        self.emit("games_played:1|c");
+    }
+
+    // One of my moves went through; `latency` is the server round trip, `None` for local games.
+    pub fn move_made(&mut self, latency: Option<Duration>) {
+        self.counters.moves_made += 1;
+        self.emit("moves_made:1|c");
+        if let Some(latency) = latency {
+            let ms = latency.as_millis() as u64;
+            self.counters.latency_total_ms += ms;
+            self.counters.latency_samples += 1;
+            self.emit(&format!("move_latency:{ms}|ms"));
+        }
+    }
+
+    // Metrics must never get in the way of a game, so a failed write or send is dropped.
+    fn emit(&mut self, stat: &str) {
+        match &self.target {
+            None => {}
+            Some(MetricsTarget::File(path)) => {
+                let counters = &mut self.counters;
+                counters.average_latency_ms = (counters.latency_samples > 0)
+                    .then(|| counters.latency_total_ms / counters.latency_samples);
+                if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+                    let _ = fs::create_dir_all(dir);
+                }
+                if let Ok(raw) = serde_json::to_string_pretty(counters) {
+                    let _ = fs::write(path, raw);
+                }
+            }
+            Some(MetricsTarget::Statsd(_)) => {
+                if let Some(socket) = &self.socket {
+                    let _ = socket.send(format!("tictactoe.{stat}").as_bytes());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_totals_carry_over_between_runs() {
+        let path = std::env::temp_dir().join(format!("metrics-{}.json", uuid::Uuid::new_v4()));
+
+        let mut metrics = Metrics::new(Some(MetricsTarget::File(path.clone())));
+        metrics.game_created();
+        metrics.move_made(Some(Duration::from_millis(30)));
+        metrics.move_made(None);
+
+        let mut metrics = Metrics::new(Some(MetricsTarget::File(path.clone())));
+        metrics.move_made(Some(Duration::from_millis(10)));
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(
+            metrics.counters,
+            Counters {
+                games_created: 1,
+                games_played: 0,
+                moves_made: 3,
+                latency_total_ms: 40,
+                latency_samples: 2,
+                average_latency_ms: Some(20),
+            }
+        );
+    }
+
+    #[test]
+    fn statsd_targets_get_prefixed_udp_stats() {
+        let server = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        server
+            .set_read_timeout(Some(Duration::from_secs(2)))
+            .unwrap();
+        let target = MetricsTarget::parse(&format!("statsd://{}", server.local_addr().unwrap()));
+
+        let mut metrics = Metrics::new(Some(target.unwrap()));
+        metrics.game_created();
+
+        let mut buf = [0; 64];
+        let len = server.recv(&mut buf).unwrap();
+        assert_eq!(&buf[..len], b"tictactoe.games_created:1|c");
+    }
+}
@@ -1,8 +1,9 @@
-use serde::{Deserialize, Serialize};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
 
 // Mirrors backend game JSON shape.
 // Think of this like a TypeScript interface used in API responses.
-#[derive(Debug, Clone, Deserialize)]
+// Serialize writes the same JSON shape back out, for game exports.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApiGame {
     pub id: String,
     pub mode: String,
@@ -11,13 +12,335 @@ pub struct ApiGame {
     pub host_player_id: String,
     #[serde(rename = "guestPlayerId")]
     pub guest_player_id: Option<String>,
+    // Display names are optional: older servers don't send them.
+    #[serde(rename = "hostName", default)]
+    pub host_name: Option<String>,
+    #[serde(rename = "guestName", default)]
+    pub guest_name: Option<String>,
+    // Always BOARD_CELLS long once decoded: a missing, `null` or empty board becomes an empty one.
+    #[serde(default = "empty_board", deserialize_with = "board_cells")]
     pub board: Vec<Option<String>>,
     #[serde(rename = "currentTurn")]
     pub current_turn: String,
+    // Symbol that opened the game; older servers don't send it (X always started there).
+    #[serde(rename = "firstTurn", default)]
+    pub first_turn: Option<String>,
     pub status: String,
     pub winner: Option<String>,
+    // Symbol of the player who resigned (or forfeited by going idle), when the game ended that way.
+    #[serde(rename = "resignedBy", default)]
+    pub resigned_by: Option<String>,
+    // How a won game was won (`LINE`, `FORFEIT` or `TIMEOUT`) and, for `LINE`, the three cells.
+    // Older servers send neither; the line is then worked out from the board.
+    #[serde(rename = "winReason", default)]
+    pub win_reason: Option<String>,
+    #[serde(rename = "winningLine", default)]
+    pub winning_line: Option<Vec<usize>>,
     #[serde(rename = "hasPassword")]
     pub has_password: bool,
+    // Names of people watching right now; `None` from servers that don't track spectators.
+    #[serde(default)]
+    pub spectators: Option<Vec<String>>,
+    // Rematch handshake after a finished PvP game: who asked, then where the new game is.
+    // Both stay `None` on servers without rematch support.
+    #[serde(rename = "rematchRequestedBy", default)]
+    pub rematch_requested_by: Option<String>,
+    #[serde(rename = "rematchGameId", default)]
+    pub rematch_game_id: Option<String>,
+    // Wins of each seat's player across the rematch series, this game included, as counted by
+    // the server. Older servers don't send them, and the series score stays hidden.
+    #[serde(rename = "hostScore", default, skip_serializing_if = "Option::is_none")]
+    pub host_score: Option<u32>,
+    #[serde(
+        rename = "guestScore",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub guest_score: Option<u32>,
+    // ISO-8601 creation time; compares correctly as a string. Older servers don't send it.
+    #[serde(rename = "createdAt", default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    // ISO-8601 time of the last change; polling uses it to drop snapshots older than the one shown.
+    #[serde(rename = "updatedAt", default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+// Order of the lobby's joinable games, cycled with `s` and kept across refreshes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LobbySort {
+    // Whatever order the server sent.
+    #[default]
+    Server,
+    Name,
+    Newest,
+    // Games without a password first.
+    OpenFirst,
+}
+
+impl LobbySort {
+    const ALL: [LobbySort; 4] = [
+        LobbySort::Server,
+        LobbySort::Name,
+        LobbySort::Newest,
+        LobbySort::OpenFirst,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            LobbySort::Server => "server order",
+            LobbySort::Name => "name",
+            LobbySort::Newest => "newest",
+            LobbySort::OpenFirst => "open first",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let idx = Self::ALL.iter().position(|&sort| sort == self).unwrap_or(0);
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+
+    // Stable, so games that tie keep the server's order.
+    pub fn sort(self, games: &mut [ApiGame]) {
+        match self {
+            LobbySort::Server => {}
+            LobbySort::Name => {
+                games.sort_by_key(|game| game.name.as_deref().unwrap_or("Untitled").to_lowercase())
+            }
+            // Games without a creation time go last
+            LobbySort::Newest => games.sort_by(|a, b| b.created_at.cmp(&a.created_at)),
+            LobbySort::OpenFirst => games.sort_by_key(|game| game.has_password),
+        }
+    }
+}
+
+// Which list the PvP lobby shows; `Tab` switches between them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LobbyTab {
+    // Open games to join, plus the live ones to watch.
+    #[default]
+    Open,
+    // My own games that aren't over yet: waiting for an opponent or being played.
+    Mine,
+}
+
+impl LobbyTab {
+    pub fn toggle(self) -> Self {
+        match self {
+            LobbyTab::Open => LobbyTab::Mine,
+            LobbyTab::Mine => LobbyTab::Open,
+        }
+    }
+}
+
+// How a finished game went from one player's side (see `ApiGame::outcome_for`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameOutcome {
+    Won,
+    Lost,
+    Draw,
+}
+
+impl GameOutcome {
+    pub fn label(self) -> &'static str {
+        match self {
+            GameOutcome::Won => "You won!",
+            GameOutcome::Lost => "You lost.",
+            GameOutcome::Draw => "Draw",
+        }
+    }
+
+    // The `outcome` sent with `ReportResultRequest`.
+    pub fn report_value(self) -> &'static str {
+        match self {
+            GameOutcome::Won => "WON",
+            GameOutcome::Lost => "LOST",
+            GameOutcome::Draw => "DRAW",
+        }
+    }
+}
+
+// Number of cells on the 3x3 board; valid move indices are 0..BOARD_CELLS.
+pub const BOARD_CELLS: usize = 9;
+
+fn empty_board() -> Vec<Option<String>> {
+    vec![None; BOARD_CELLS]
+}
+
+// Decodes `board`, padding a missing or empty one to BOARD_CELLS empty cells so the rest of
+// the client can index it. Any other length is a broken game and fails the whole response.
+fn board_cells<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<Option<String>>, D::Error> {
+    let mut cells: Vec<Option<String>> = Option::deserialize(deserializer)?.unwrap_or_default();
+    match cells.len() {
+        0 => cells = empty_board(),
+        BOARD_CELLS => {}
+        len => {
+            return Err(D::Error::custom(format!(
+                "board has {len} cells, expected {BOARD_CELLS}"
+            )))
+        }
+    }
+    Ok(cells)
+}
+
+// Cells named by their place on the board, for recaps like "X: center, O: top-left".
+pub const CELL_NAMES: [&str; BOARD_CELLS] = [
+    "top-left",
+    "top",
+    "top-right",
+    "left",
+    "center",
+    "right",
+    "bottom-left",
+    "bottom",
+    "bottom-right",
+];
+
+// Every row, column and diagonal, in the same order the server checks them.
+pub const WINNING_LINES: [[usize; 3]; 8] = [
+    [0, 1, 2],
+    [3, 4, 5],
+    [6, 7, 8],
+    [0, 3, 6],
+    [1, 4, 7],
+    [2, 5, 8],
+    [0, 4, 8],
+    [2, 4, 6],
+];
+
+// The first row, column or diagonal filled with one symbol, in the server's checking order.
+pub fn completed_line(board: &[Option<String>]) -> Option<[usize; 3]> {
+    let cell = |index: usize| board.get(index).and_then(Option::as_deref);
+    WINNING_LINES
+        .into_iter()
+        .find(|&[a, b, c]| cell(a).is_some() && cell(a) == cell(b) && cell(b) == cell(c))
+}
+
+// Length range of PvP game names, counted after trimming; the backend enforces the same.
+pub const GAME_NAME_MIN_CHARS: usize = 3;
+pub const GAME_NAME_MAX_CHARS: usize = 40;
+
+// The one place game names are checked before a create request, with a message per rule broken.
+pub fn validate_game_name(raw: &str) -> Result<(), String> {
+    let name = raw.trim();
+    if name.chars().all(|ch| ch.is_whitespace() || ch.is_control()) {
+        return Err("Game name cannot be blank".to_string());
+    }
+    if name.chars().any(char::is_control) {
+        return Err("Game name cannot contain control characters".to_string());
+    }
+    match name.chars().count() {
+        count if count < GAME_NAME_MIN_CHARS => Err(format!(
+            "Game name must be at least {GAME_NAME_MIN_CHARS} characters (it has {count})"
+        )),
+        count if count > GAME_NAME_MAX_CHARS => Err(format!(
+            "Game name must be at most {GAME_NAME_MAX_CHARS} characters (it has {count})"
+        )),
+        _ => Ok(()),
+    }
+}
+
+// Mode of local hot-seat games, which exist only in this client (see `engine`).
+pub const MODE_PRACTICE: &str = "PRACTICE";
+// Mode of solo games against the computer played locally; only their result reaches the server.
+pub const MODE_LOCAL_SOLO: &str = "SOLO_LOCAL";
+
+// Status sent while a PvP game has no guest yet.
+pub const STATUS_WAITING_FOR_PLAYER: &str = "WAITING_FOR_PLAYER";
+
+impl ApiGame {
+    // Whether this snapshot of a game predates `other`, e.g. a poll answered from before my own
+    // move landed. Uses `updatedAt` when both have it, else the marks on the board (they only
+    // ever get added). Equal snapshots are not older.
+    pub fn is_older_than(&self, other: &ApiGame) -> bool {
+        match (&self.updated_at, &other.updated_at) {
+            (Some(this), Some(other)) => this < other,
+            _ => self.moves_played() < other.moves_played(),
+        }
+    }
+
+    pub fn is_waiting_for_player(&self) -> bool {
+        self.status == STATUS_WAITING_FOR_PLAYER
+    }
+
+    // Result of a finished game for the side playing `symbol`; anything but a win is a draw.
+    pub fn outcome_for(&self, symbol: &str) -> GameOutcome {
+        match self.winner.as_deref() {
+            _ if self.status != "WON" => GameOutcome::Draw,
+            Some(winner) if winner == symbol => GameOutcome::Won,
+            _ => GameOutcome::Lost,
+        }
+    }
+
+    pub fn first_turn(&self) -> &str {
+        self.first_turn.as_deref().unwrap_or("X")
+    }
+
+    // Moves made by one side, read off the board (marks never move or disappear),
+    // so it is right for resumed games without a local move history too.
+    pub fn moves_by(&self, symbol: &str) -> usize {
+        self.board
+            .iter()
+            .filter(|cell| cell.as_deref() == Some(symbol))
+            .count()
+    }
+
+    pub fn moves_played(&self) -> usize {
+        self.board.iter().filter(|cell| cell.is_some()).count()
+    }
+
+    // The three cells that won the game: the server's `winningLine` when it sends one,
+    // otherwise the first full line of the winner's marks on the board.
+    pub fn winning_line(&self) -> Option<[usize; 3]> {
+        if let Some(&[a, b, c]) = self.winning_line.as_deref() {
+            return Some([a, b, c]);
+        }
+        let winner = self.winner.as_deref()?;
+        completed_line(&self.board).filter(|&[a, ..]| self.board[a].as_deref() == Some(winner))
+    }
+
+    // "Series: You 2 – 1 Opponent" from `you`'s side (spectators get "X 2 – 1 O"). `None` when
+    // the server doesn't keep scores or fewer than two games of the series have been won: the
+    // server scores every finished game, so "1 – 0" would show after any one-off game.
+    pub fn series_line(&self, you: &str) -> Option<String> {
+        let (host, guest) = (self.host_score?, self.guest_score?);
+        if self.mode != "PVP" || host + guest < 2 {
+            return None;
+        }
+        Some(match you {
+            "X" => format!("Series: You {host} – {guest} Opponent"),
+            "O" => format!("Series: You {guest} – {host} Opponent"),
+            _ => format!("Series: X {host} – {guest} O"),
+        })
+    }
+
+    // One-line explanation of how the game was won, e.g. "Won on the top row" or
+    // "Won by forfeit"; `None` for unfinished or drawn games.
+    pub fn win_description(&self) -> Option<String> {
+        if self.status != "WON" {
+            return None;
+        }
+        match self.win_reason.as_deref() {
+            Some("FORFEIT") => return Some("Won by forfeit".to_string()),
+            Some("TIMEOUT") => return Some("Won on time (the opponent went idle)".to_string()),
+            // Servers without `winReason` still say who resigned.
+            None if self.resigned_by.is_some() => return Some("Won by forfeit".to_string()),
+            _ => {}
+        }
+        let line = match self.winning_line()? {
+            [0, 1, 2] => "the top row",
+            [3, 4, 5] => "the middle row",
+            [6, 7, 8] => "the bottom row",
+            [0, 3, 6] => "the left column",
+            [1, 4, 7] => "the middle column",
+            [2, 5, 8] => "the right column",
+            [0, 4, 8] => "the diagonal from top left",
+            [2, 4, 6] => "the diagonal from top right",
+            _ => return None,
+        };
+        Some(format!("Won on {line}"))
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -26,6 +349,10 @@ pub struct CreateSoloRequest {
     pub player_id: String,
     #[serde(rename = "clientName")]
     pub client_name: String,
+    #[serde(rename = "playerName", skip_serializing_if = "Option::is_none")]
+    pub player_name: Option<String>,
+    #[serde(rename = "firstTurn", skip_serializing_if = "Option::is_none")]
+    pub first_turn: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -34,6 +361,10 @@ pub struct CreatePvpRequest {
     pub player_id: String,
     pub name: String,
     pub password: Option<String>,
+    #[serde(rename = "playerName", skip_serializing_if = "Option::is_none")]
+    pub player_name: Option<String>,
+    #[serde(rename = "firstTurn", skip_serializing_if = "Option::is_none")]
+    pub first_turn: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -41,6 +372,8 @@ pub struct JoinPvpRequest {
     #[serde(rename = "playerId")]
     pub player_id: String,
     pub password: Option<String>,
+    #[serde(rename = "playerName", skip_serializing_if = "Option::is_none")]
+    pub player_name: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -50,13 +383,178 @@ pub struct PlayMoveRequest {
     pub index: usize,
 }
 
+// Body of `GET /health`.
+#[derive(Debug, Deserialize)]
+pub struct HealthResponse {
+    pub status: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VersionResponse {
+    pub version: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ResignGameRequest {
+    #[serde(rename = "playerId")]
+    pub player_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CancelPvpRequest {
+    #[serde(rename = "playerId")]
+    pub player_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ClaimWinRequest {
+    #[serde(rename = "playerId")]
+    pub player_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RematchRequest {
+    #[serde(rename = "playerId")]
+    pub player_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WatchGameRequest {
+    #[serde(rename = "playerId")]
+    pub player_id: String,
+    #[serde(rename = "playerName", skip_serializing_if = "Option::is_none")]
+    pub player_name: Option<String>,
+}
+
+// One page of `GET /games/pvp/open`; `total` is `None` from servers that don't page the list.
+#[derive(Debug, Clone, Default)]
+pub struct OpenGamesPage {
+    pub games: Vec<ApiGame>,
+    pub total: Option<usize>,
+}
+
+// Result of a solo game played locally, sent to `POST /games/results` for stats.
+// Also the on-disk format of the upload queue (see `results`), so it deserializes too.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportResultRequest {
+    // Made up by the client; the server ignores a result id it has already recorded,
+    // so retrying an upload that did arrive never counts the game twice.
+    pub result_id: String,
+    pub player_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub player_name: Option<String>,
+    pub client_name: String,
+    // "WON", "LOST" or "DRAW", from the player's point of view.
+    pub outcome: String,
+    pub first_turn: String,
+    pub board: Vec<Option<String>>,
+    // Seconds since the Unix epoch.
+    pub finished_at: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportResultResponse {
+    // False when the server already had this result.
+    pub recorded: bool,
+}
+
+// One placed mark, tracked locally so finished games can be replayed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MoveRecord {
+    pub index: usize,
+    pub symbol: String,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Screen {
+    Setup,
     Home,
     SoloGame,
     PvpLobby,
     PvpCreate,
     PvpGame,
     GameOver,
+    Replay,
+    Settings,
     Info,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(board: &str) -> Result<ApiGame, serde_json::Error> {
+        let board_field = if board.is_empty() {
+            String::new()
+        } else {
+            format!(r#""board":{board},"#)
+        };
+        serde_json::from_str(&format!(
+            r#"{{"id":"g-1","mode":"PVP","name":null,"hostPlayerId":"p-1","guestPlayerId":null,
+            {board_field}"currentTurn":"X","status":"IN_PROGRESS","winner":null,"hasPassword":false}}"#
+        ))
+    }
+
+    #[test]
+    fn missing_or_null_board_is_an_empty_board() {
+        for board in ["", "null", "[]"] {
+            assert_eq!(
+                decode(board).unwrap().board,
+                vec![None; BOARD_CELLS],
+                "board: {board:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn wrong_board_length_is_an_error() {
+        let err = decode(r#"["X",null,"O"]"#).unwrap_err();
+        assert!(
+            err.to_string().contains("board has 3 cells, expected 9"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn outcome_is_read_from_each_side() {
+        let mut game = decode("").unwrap();
+        game.status = "WON".to_string();
+        game.winner = Some("O".to_string());
+        assert_eq!(game.outcome_for("O"), GameOutcome::Won);
+        assert_eq!(game.outcome_for("X"), GameOutcome::Lost);
+
+        game.status = "DRAW".to_string();
+        game.winner = None;
+        assert_eq!(game.outcome_for("X"), GameOutcome::Draw);
+    }
+
+    #[test]
+    fn series_score_is_read_from_my_seat() {
+        let mut game = decode("").unwrap();
+        assert_eq!(game.series_line("X"), None);
+
+        // A one-off win is no series yet
+        game.host_score = Some(1);
+        game.guest_score = Some(0);
+        assert_eq!(game.series_line("X"), None);
+
+        // Nor is a solo game, whatever the server scores
+        game.guest_score = Some(2);
+        game.mode = "SOLO".to_string();
+        assert_eq!(game.series_line("X"), None);
+
+        game.mode = "PVP".to_string();
+        game.guest_score = Some(2);
+        assert_eq!(
+            game.series_line("O").as_deref(),
+            Some("Series: You 2 – 1 Opponent")
+        );
+        assert_eq!(
+            game.series_line("X").as_deref(),
+            Some("Series: You 1 – 2 Opponent")
+        );
+        assert_eq!(game.series_line("?").as_deref(), Some("Series: X 1 – 2 O"));
+    }
+}
@@ -0,0 +1,51 @@
+use crossterm::event::KeyCode;
+
+// Rows skipped by PageUp/PageDown in every list.
+pub const PAGE: usize = 5;
+
+// Shared movement keys for every list on screen (menus, lobby, move history, replay, messages),
+// so each one answers the same keys the same way:
+// Up/Down or k/j move one row, PageUp/PageDown move a page, Home/End jump to the ends.
+// Returns the new position in a list of `len` rows, or `None` when `key` isn't a movement key.
+pub fn step(key: KeyCode, current: usize, len: usize) -> Option<usize> {
+    let last = len.saturating_sub(1);
+    let next = match key {
+        KeyCode::Up | KeyCode::Char('k') => current.saturating_sub(1),
+        KeyCode::Down | KeyCode::Char('j') => current + 1,
+        KeyCode::PageUp => current.saturating_sub(PAGE),
+        KeyCode::PageDown => current + PAGE,
+        KeyCode::Home => 0,
+        KeyCode::End => last,
+        _ => return None,
+    };
+    Some(next.min(last))
+}
+
+// Esc or `b` leaves a list screen, wherever it was opened from.
+pub fn is_back(key: KeyCode) -> bool {
+    matches!(key, KeyCode::Esc | KeyCode::Char('b'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn moves_within_the_list() {
+        assert_eq!(step(KeyCode::Char('j'), 0, 3), Some(1));
+        assert_eq!(step(KeyCode::Down, 2, 3), Some(2));
+        assert_eq!(step(KeyCode::Char('k'), 0, 3), Some(0));
+        assert_eq!(step(KeyCode::PageDown, 1, 20), Some(6));
+        assert_eq!(step(KeyCode::PageDown, 18, 20), Some(19));
+        assert_eq!(step(KeyCode::PageUp, 3, 20), Some(0));
+        assert_eq!(step(KeyCode::End, 0, 20), Some(19));
+        assert_eq!(step(KeyCode::Home, 7, 20), Some(0));
+    }
+
+    #[test]
+    fn ignores_other_keys_and_empty_lists() {
+        assert_eq!(step(KeyCode::Enter, 0, 3), None);
+        assert_eq!(step(KeyCode::Char('x'), 0, 3), None);
+        assert_eq!(step(KeyCode::Down, 0, 0), Some(0));
+    }
+}
@@ -0,0 +1,46 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+
+use crate::models::ReportResultRequest;
+
+// Results of local solo games that haven't reached the server yet, kept next to the settings
+// so they survive restarts and get uploaded once the server is reachable again.
+
+// `<config_dir>/pending_results.json`, next to `settings.json`.
+fn queue_path(config_dir: &Path) -> PathBuf {
+    config_dir.join("pending_results.json")
+}
+
+// An empty queue when nothing was ever saved.
+pub fn load(config_dir: &Path) -> Result<Vec<ReportResultRequest>> {
+    let path = queue_path(config_dir);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let raw =
+        fs::read_to_string(&path).with_context(|| format!("could not read {}", path.display()))?;
+    serde_json::from_str(&raw).with_context(|| format!("could not parse {}", path.display()))
+}
+
+// Rewrites the whole queue; an empty queue removes the file.
+pub fn save(config_dir: &Path, results: &[ReportResultRequest]) -> Result<()> {
+    let path = queue_path(config_dir);
+    if results.is_empty() {
+        if path.exists() {
+            fs::remove_file(&path)
+                .with_context(|| format!("could not remove {}", path.display()))?;
+        }
+        return Ok(());
+    }
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("could not create {}", dir.display()))?;
+    }
+    let raw = serde_json::to_string_pretty(results)?;
+    fs::write(&path, raw).with_context(|| format!("could not write {}", path.display()))
+}
@@ -0,0 +1,76 @@
+use std::fmt;
+
+use crossterm::event::KeyCode;
+
+use crate::input::TextInput;
+
+// Wraps a value that must never show up in logs, debug dumps or error messages (passwords).
+// `{:?}` and `{}` print a placeholder instead of the value; the only way to read it is
+// `expose`, which is called right where it is handed to the API client.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Secret<T>(T);
+
+impl<T> Secret<T> {
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    pub fn expose(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> fmt::Debug for Secret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Secret(***)")
+    }
+}
+
+impl<T> fmt::Display for Secret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("***")
+    }
+}
+
+// A password field: edited like any `TextInput`, but the screen only gets the masked text.
+impl Secret<TextInput> {
+    pub fn handle_key(&mut self, code: KeyCode, max_chars: usize) -> bool {
+        self.0.handle_key(code, max_chars)
+    }
+
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    pub fn caret(&self) -> usize {
+        self.0.caret()
+    }
+
+    pub fn is_blank(&self) -> bool {
+        self.0.value().trim().is_empty()
+    }
+
+    // One `*` per character, so the caret lines up with the mask.
+    pub fn masked(&self) -> String {
+        "*".repeat(self.0.value().chars().count())
+    }
+
+    // The typed password without surrounding whitespace, still wrapped, ready to send.
+    pub fn to_secret(&self) -> Secret<String> {
+        Secret(self.0.value().trim().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formatting_never_shows_the_value() {
+        let password = Secret::new("hunter2".to_string());
+
+        assert_eq!(format!("{password}"), "***");
+        assert!(!format!("{password:?}").contains("hunter2"));
+        assert!(!format!("{:?}", Some(&password)).contains("hunter2"));
+    }
+}
@@ -0,0 +1,140 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use ratatui::text::Span;
+
+use crate::{
+    sound::SoundEvent,
+    theme::{BoardSymbols, LayoutSize, Theme},
+};
+
+// Values that survive restarts, stored as JSON in the user's config directory.
+// Like a typed wrapper around localStorage: missing fields fall back to defaults,
+// so older files keep loading after new settings are added.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Settings {
+    pub player_id: String,
+    pub display_name: String,
+    // Server chosen on the settings screen; `--server` still wins when given.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
+    // Servers used before, most recent first (see `remember_server`).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub recent_servers: Vec<String>,
+    pub theme: Theme,
+    pub ascii_mode: bool,
+    // Draw my own X/O marks in the theme's accent color.
+    pub highlight_own_marks: bool,
+    pub board_symbols: BoardSymbols,
+    // Glyphs for X and O when `board_symbols` is `Custom`, as "<x> <o>" (see `validate_custom_symbols`).
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub custom_symbols: String,
+    // Readability mode: high contrast, boxed board, bigger boxes, turn cues spelled out.
+    pub accessible: bool,
+    // Label the board's columns A..C and rows 1..3.
+    pub show_coordinates: bool,
+    // Home shows a one-line key legend (`s` solo, `p` PvP, ...) instead of the arrow-key list.
+    pub quick_keys_home: bool,
+    // Play solo games locally (instant moves) and only upload their results to the server.
+    pub local_solo: bool,
+    // How much of the terminal the screens use.
+    pub layout: LayoutSize,
+    // After a move lands on the board (mine or the opponent's), jump the cursor to the next
+    // empty cell instead of leaving it on the filled one.
+    pub auto_advance: bool,
+    // `--sound` cues switched off on the settings screen; every other event rings.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub muted_sounds: Vec<SoundEvent>,
+    // Game open when the app last ran; offered for resuming on the next launch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_game_id: Option<String>,
+}
+
+// How many servers `recent_servers` keeps.
+pub const RECENT_SERVERS_MAX: usize = 5;
+
+impl Settings {
+    // Moves `url` to the front of the recent servers, dropping an older copy and the oldest
+    // entry past the cap.
+    pub fn remember_server(&mut self, url: &str) {
+        self.recent_servers.retain(|known| known != url);
+        self.recent_servers.insert(0, url.to_string());
+        self.recent_servers.truncate(RECENT_SERVERS_MAX);
+    }
+
+    // `Ok(None)` means first run: there is no settings file yet. `config_dir` is usually
+    // `app_config_dir()`.
+    pub fn load(config_dir: &Path) -> Result<Option<Self>> {
+        let path = settings_path(config_dir);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let raw = fs::read_to_string(&path)
+            .with_context(|| format!("could not read {}", path.display()))?;
+        let settings = serde_json::from_str(&raw)
+            .with_context(|| format!("could not parse {}", path.display()))?;
+        Ok(Some(settings))
+    }
+
+    pub fn save(&self, config_dir: &Path) -> Result<()> {
+        let path = settings_path(config_dir);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("could not create {}", dir.display()))?;
+        }
+
+        let raw = serde_json::to_string_pretty(self)?;
+        fs::write(&path, raw).with_context(|| format!("could not write {}", path.display()))
+    }
+}
+
+// `<config_dir>/settings.json`.
+fn settings_path(config_dir: &Path) -> PathBuf {
+    config_dir.join("settings.json")
+}
+
+// `$XDG_CONFIG_HOME/tictactoe_tui`, falling back to `~/.config/tictactoe_tui`.
+pub fn app_config_dir() -> Result<PathBuf> {
+    let config_dir = match std::env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => {
+            let home = std::env::var_os("HOME").context("HOME is not set")?;
+            PathBuf::from(home).join(".config")
+        }
+    };
+
+    Ok(config_dir.join(env!("CARGO_PKG_NAME")))
+}
+
+// Custom board symbols are two different glyphs separated by whitespace, e.g. "# @" or "🐱 🐶".
+// Each may be at most 2 columns wide so the board cells can be padded to line up.
+pub fn validate_custom_symbols(raw: &str) -> Result<String, String> {
+    let glyphs: Vec<&str> = raw.split_whitespace().collect();
+    let [x, o] = glyphs[..] else {
+        return Err("Enter two symbols separated by a space, e.g. \"# @\"".to_string());
+    };
+    if x == o {
+        return Err("X and O need different symbols".to_string());
+    }
+    if [x, o].iter().any(|glyph| Span::raw(*glyph).width() > 2) {
+        return Err("Symbols can be at most 2 columns wide".to_string());
+    }
+    Ok(format!("{x} {o}"))
+}
+
+// Display names are trimmed and must be 1..=32 characters (the backend enforces the same range).
+pub fn validate_display_name(raw: &str) -> Result<String, String> {
+    let name = raw.trim();
+    match name.chars().count() {
+        0 => Err("Name cannot be empty".to_string()),
+        1..=32 => Ok(name.to_string()),
+        _ => Err("Name must be at most 32 characters".to_string()),
+    }
+}
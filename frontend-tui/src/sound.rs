@@ -0,0 +1,67 @@
+use std::{io::Write, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+// Terminal-bell cues for game events (`--sound`). Each event rings its own pattern of bells, so
+// they can be told apart without looking; it's all BEL characters, no audio library involved.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SoundEvent {
+    YourTurn,
+    Won,
+    Lost,
+    OpponentJoined,
+    Error,
+}
+
+impl SoundEvent {
+    // In the order of their rows on the settings screen.
+    pub const ALL: [SoundEvent; 5] = [
+        SoundEvent::YourTurn,
+        SoundEvent::Won,
+        SoundEvent::Lost,
+        SoundEvent::OpponentJoined,
+        SoundEvent::Error,
+    ];
+
+    // How many bells to ring and the pause between them.
+    pub fn pattern(self) -> (usize, Duration) {
+        match self {
+            SoundEvent::YourTurn => (1, Duration::ZERO),
+            SoundEvent::OpponentJoined => (2, Duration::from_millis(150)),
+            SoundEvent::Won => (3, Duration::from_millis(150)),
+            SoundEvent::Lost => (2, Duration::from_millis(500)),
+            SoundEvent::Error => (4, Duration::from_millis(80)),
+        }
+    }
+}
+
+// Rings `event`'s pattern in the background, so the pauses never hold up the UI.
+pub fn play(event: SoundEvent) {
+    let (bells, pause) = event.pattern();
+    tokio::spawn(async move {
+        for bell in 0..bells {
+            if bell > 0 {
+                tokio::time::sleep(pause).await;
+            }
+            // BEL character: most terminals beep or flash the window.
+            print!("\x07");
+            let _ = std::io::stdout().flush();
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_event_has_its_own_pattern() {
+        for (index, event) in SoundEvent::ALL.iter().enumerate() {
+            for other in &SoundEvent::ALL[index + 1..] {
+                assert_ne!(event.pattern(), other.pattern(), "{event:?} and {other:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,254 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    symbols::border,
+    widgets::{Block, Borders},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::layout::centered_rect;
+
+// Color schemes selectable from the settings screen, stored by name in the settings file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Theme {
+    #[default]
+    Classic,
+    Ocean,
+    Forest,
+    HighContrast,
+}
+
+impl Theme {
+    const ALL: [Theme; 4] = [
+        Theme::Classic,
+        Theme::Ocean,
+        Theme::Forest,
+        Theme::HighContrast,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Theme::Classic => "Classic",
+            Theme::Ocean => "Ocean",
+            Theme::Forest => "Forest",
+            Theme::HighContrast => "High contrast",
+        }
+    }
+
+    // Cycles through the themes in a fixed order, wrapping at the end.
+    pub fn next(self) -> Self {
+        let idx = Self::ALL
+            .iter()
+            .position(|&theme| theme == self)
+            .unwrap_or(0);
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+
+    // `ascii_mode` drops every color and draws borders with plain ASCII characters,
+    // for terminals without color or box-drawing support.
+    pub fn palette(self, ascii_mode: bool) -> Palette {
+        if ascii_mode {
+            return Palette {
+                accent: Color::Reset,
+                border: Color::Reset,
+                ascii: true,
+                accessible: false,
+                size: LayoutSize::default(),
+            };
+        }
+
+        let (accent, border) = match self {
+            Theme::Classic => (Color::Cyan, Color::Reset),
+            Theme::Ocean => (Color::LightBlue, Color::Blue),
+            Theme::Forest => (Color::LightGreen, Color::Green),
+            Theme::HighContrast => (Color::Yellow, Color::White),
+        };
+        Palette {
+            accent,
+            border,
+            ascii: false,
+            accessible: false,
+            size: LayoutSize::default(),
+        }
+    }
+
+    // Accessibility mode: high contrast whatever the chosen theme, box-drawing borders
+    // (the boxed board needs them), and the flag the UI checks for labels and larger boxes.
+    pub fn accessible_palette() -> Palette {
+        Palette {
+            accessible: true,
+            ..Theme::HighContrast.palette(false)
+        }
+    }
+}
+
+// How much of the terminal the screen boxes take, picked on the settings screen.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LayoutSize {
+    // Smaller boxes with more margin around them.
+    Compact,
+    // The sizes each screen was designed with.
+    #[default]
+    Comfortable,
+    // Nearly the whole terminal, for big screens.
+    Full,
+}
+
+impl LayoutSize {
+    const ALL: [LayoutSize; 3] = [
+        LayoutSize::Compact,
+        LayoutSize::Comfortable,
+        LayoutSize::Full,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            LayoutSize::Compact => "Compact",
+            LayoutSize::Comfortable => "Comfortable",
+            LayoutSize::Full => "Full screen",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let idx = Self::ALL.iter().position(|&size| size == self).unwrap_or(0);
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+
+    // A screen's designed share of the terminal (in percent), scaled to this size.
+    fn scale(self, percent: u16) -> u16 {
+        match self {
+            LayoutSize::Compact => percent * 4 / 5,
+            LayoutSize::Comfortable => percent.min(100),
+            LayoutSize::Full => 98,
+        }
+    }
+}
+
+// How X and O are drawn on the board. Only the display changes: moves, turns and
+// the server all keep using the plain "X"/"O" symbols.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BoardSymbols {
+    #[default]
+    Letters,
+    Emoji,
+    // The two glyphs saved in the settings' `customSymbols`.
+    Custom,
+}
+
+impl BoardSymbols {
+    const ALL: [BoardSymbols; 3] = [
+        BoardSymbols::Letters,
+        BoardSymbols::Emoji,
+        BoardSymbols::Custom,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            BoardSymbols::Letters => "X / O",
+            BoardSymbols::Emoji => "❌ / ⭕",
+            BoardSymbols::Custom => "Custom",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let idx = Self::ALL
+            .iter()
+            .position(|&symbols| symbols == self)
+            .unwrap_or(0);
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+
+    // Glyphs for X and O. `custom` is the saved "<x> <o>" pair; letters are used while it is unset.
+    pub fn glyphs(self, custom: &str) -> [String; 2] {
+        match self {
+            BoardSymbols::Letters => ["X".to_string(), "O".to_string()],
+            BoardSymbols::Emoji => ["❌".to_string(), "⭕".to_string()],
+            BoardSymbols::Custom => match custom.split_whitespace().collect::<Vec<_>>()[..] {
+                [x, o] => [x.to_string(), o.to_string()],
+                _ => BoardSymbols::Letters.glyphs(custom),
+            },
+        }
+    }
+}
+
+// Resolved colors for one frame; the UI asks it for styled blocks instead of picking colors itself.
+#[derive(Debug, Clone, Copy)]
+pub struct Palette {
+    accent: Color,
+    border: Color,
+    ascii: bool,
+    accessible: bool,
+    size: LayoutSize,
+}
+
+const ASCII_BORDER: border::Set = border::Set {
+    top_left: "+",
+    top_right: "+",
+    bottom_left: "+",
+    bottom_right: "+",
+    vertical_left: "|",
+    vertical_right: "|",
+    horizontal_top: "-",
+    horizontal_bottom: "-",
+};
+
+impl Palette {
+    // Bordered block with a title, the building block of every screen.
+    pub fn block<'a>(&self, title: &'a str) -> Block<'a> {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.border))
+            .title(title);
+        if self.ascii {
+            block.border_set(ASCII_BORDER)
+        } else {
+            block
+        }
+    }
+
+    // Traffic-light style for status indicators; plain text in ASCII mode.
+    pub fn indicator(&self, color: Color) -> Style {
+        if self.ascii {
+            Style::default()
+        } else {
+            Style::default().fg(color)
+        }
+    }
+
+    // Accessibility mode: cues that are only a color get spelled out in text as well.
+    pub fn is_accessible(&self) -> bool {
+        self.accessible
+    }
+
+    // ASCII mode: only plain characters, for terminals with limited glyph support.
+    pub fn is_ascii(&self) -> bool {
+        self.ascii
+    }
+
+    // The same colors, with screen boxes sized for `size`.
+    pub fn with_size(self, size: LayoutSize) -> Self {
+        Palette { size, ..self }
+    }
+
+    // `centered_rect` for screen boxes, scaled to the layout size; accessibility mode grows
+    // them halfway to full screen.
+    pub fn centered(&self, percent_x: u16, percent_y: u16, area: Rect) -> Rect {
+        let (percent_x, percent_y) = (self.size.scale(percent_x), self.size.scale(percent_y));
+        if self.accessible {
+            let grow = |percent: u16| percent + (100 - percent.min(100)) / 2;
+            centered_rect(grow(percent_x), grow(percent_y), area)
+        } else {
+            centered_rect(percent_x, percent_y, area)
+        }
+    }
+
+    // Highlighted text: selected menu items, notices, the waiting banner.
+    pub fn selected(&self) -> Style {
+        Style::default()
+            .fg(self.accent)
+            .add_modifier(Modifier::BOLD)
+    }
+}
@@ -1,50 +1,284 @@
 // Importing UI rendering primitives from ratatui crate and our API game model
+use std::time::Duration; // Used for request latency and opponent inactivity
+
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect}, // Layout handles positioning and size of widgets
-    style::{Modifier, Style}, // Style lets us control text formatting like bold
-    text::{Line, Span}, // Line and Span let us create individual styled pieces of text
-    widgets::{Block, Borders, List, ListItem, Paragraph}, // Various UI widgets for display
+    style::{Color, Modifier, Style}, // Style lets us control text formatting like bold or colors
+    text::{Line, Span},              // Line and Span let us create individual styled pieces of text
+    widgets::{Block, Clear, List, ListItem, ListState, Paragraph, Wrap}, // Various UI widgets for display
     Frame, // Frame is the canvas to render widgets onto
 };
 
-use crate::models::ApiGame; // Our own API game type
+use crate::input::TextInput; // Text field value + caret position
+                             // Our own API game type, locally tracked moves and the game-name limits shown on the create form
+use crate::models::{
+    ApiGame, GameOutcome, LobbyTab, MoveRecord, BOARD_CELLS, CELL_NAMES, GAME_NAME_MAX_CHARS,
+    GAME_NAME_MIN_CHARS, MODE_PRACTICE,
+};
+use crate::secret::Secret; // Password fields; only their masked text is readable here
+use crate::sound::SoundEvent; // Each sound cue has its own settings row
+use crate::theme::{Palette, Theme}; // Active theme colors; every bordered block comes from `palette.block(...)`
+
+/// Local, per-frame board presentation state that isn't part of the server's game.
+/// Fields:
+/// - `cursor`: Which cell is 'hovered' for input (0..8), `None` for read-only boards.
+/// - `pending_cell`: Cell marked by the first Enter in two-step move mode, awaiting confirmation.
+/// - `highlighted_cell`: Cell the opponent just played, shown with `*` markers for a moment.
+/// - `numpad`: Whether digit shortcuts follow the numpad layout (7 8 9 on top).
+/// - `idle_warning`: How long the opponent has been sitting on their turn, once it's worth a warning.
+/// - `focused_panel`: Panel that receives navigation keys (Tab switches).
+/// - `history_scroll`: Move selected in the history panel, `None` to follow the newest move.
+/// - `show_full_id`: Whether the footer spells out the full game id (toggled with `i`).
+/// - `highlight_own_marks`: Whether the local player's marks are drawn in the accent color.
+/// - `opponent_name`: Replaces the guest's name from the server (the solo computer's label).
+/// - `marks`: Glyphs drawn for X and O on the board; display only, the game logic keeps X/O.
+/// - `coordinates`: Whether columns A..C and rows 1..3 are labeled around the board.
+/// - `other_games`: My parked PvP games, `None` unless I'm playing the PvP game on screen.
+/// - `controls`: Keys usable right now (see `help::footer`), listed in the Controls box.
+/// - `analysis`: Header note while analysis mode has moves switched off, `None` otherwise.
+/// - `ghost`: Cell and symbol drawn dimmed as a "what if I played here" preview.
+/// - `winning_line`: Cells of the line that won the game, drawn in bold accent.
+/// - `enter_hint`: What Enter would do on the hovered cell (toggled with `h`), `None` when hidden.
+/// - `privacy`: Privacy mode (Ctrl+P): the game id is hidden, for screenshots.
+pub struct BoardView {
+    pub cursor: Option<usize>,
+    pub pending_cell: Option<usize>,
+    pub highlighted_cell: Option<usize>,
+    pub numpad: bool,
+    pub idle_warning: Option<Duration>,
+    pub focused_panel: GamePanel,
+    pub history_scroll: Option<usize>,
+    pub show_full_id: bool,
+    pub highlight_own_marks: bool,
+    pub opponent_name: Option<String>,
+    pub marks: [String; 2],
+    pub coordinates: bool,
+    pub other_games: Option<Vec<OtherGame>>,
+    pub controls: String,
+    pub analysis: Option<String>,
+    pub ghost: Option<(usize, String)>,
+    pub winning_line: Option<[usize; 3]>,
+    pub enter_hint: Option<&'static str>,
+    pub privacy: bool,
+}
+
+impl BoardView {
+    /// A board nobody plays on (screensaver, replay, copied text): marks only, no cursor,
+    /// highlights, hints or side panels.
+    pub fn read_only(marks: [String; 2]) -> Self {
+        Self {
+            cursor: None,
+            pending_cell: None,
+            highlighted_cell: None,
+            numpad: false,
+            idle_warning: None,
+            focused_panel: GamePanel::Board,
+            history_scroll: None,
+            show_full_id: false,
+            highlight_own_marks: false,
+            opponent_name: None,
+            marks,
+            coordinates: false,
+            other_games: None,
+            controls: String::new(),
+            analysis: None,
+            ghost: None,
+            winning_line: None,
+            enter_hint: None,
+            privacy: false,
+        }
+    }
+}
+
+/// A PvP game of mine that is running off screen, listed under the board's controls.
+/// Fields:
+/// - `name`: Game name (or shortened id) to tell the games apart.
+/// - `needs_me`: Whether it's my turn there, or the game ended and its result is waiting.
+pub struct OtherGame {
+    pub name: String,
+    pub needs_me: bool,
+}
+
+/// Panels of the game screen that can take keyboard focus.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GamePanel {
+    #[default]
+    Board,
+    Moves,
+}
+
+/// Draws the one-time "Enter your display name" screen shown on first launch.
+/// Arguments:
+/// - `frame`: Drawing surface for widgets.
+/// - `palette`: Colors and border style of the active theme.
+/// - `name_input`: Name typed so far, with its caret.
+/// - `caret_on`: Blink phase of the caret (drawn only while on).
+pub fn draw_setup(
+    frame: &mut Frame<'_>,
+    palette: &Palette,
+    name_input: &TextInput,
+    caret_on: bool,
+) {
+    let area = palette.centered(60, 40, frame.area());
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(4),
+            Constraint::Min(0),
+        ])
+        .split(area);
+
+    frame.render_widget(
+        Paragraph::new("Welcome! Pick a display name")
+            .alignment(Alignment::Center)
+            .block(palette.block("First run")),
+        chunks[0],
+    );
+    frame.render_widget(
+        Paragraph::new(Line::from(
+            [
+                vec![Span::raw("> ")],
+                input_spans(name_input.value(), Some(name_input.caret()), caret_on),
+            ]
+            .concat(),
+        ))
+        .block(palette.block("Display name (1..32)")),
+        chunks[1],
+    );
+    frame.render_widget(
+        Paragraph::new(
+            "Other players see this name in the lobby and in games.\nEnter to save, Esc to quit.",
+        )
+        .block(palette.block("Help")),
+        chunks[2],
+    );
+}
+
+/// Home menu entries, in display order. The app maps the selected index back onto these.
+pub const HOME_ITEMS: [&str; 7] = [
+    "Solo vs Computer",
+    "PvP",
+    "Quick match",
+    "Practice (play both sides)",
+    "Settings",
+    "About",
+    "Exit",
+];
+
+/// Key legend of the "quick keys" Home layout; every entry is one key press away.
+pub const HOME_QUICK_KEYS: &str =
+    "s Solo   p PvP   g Quick match   t Practice   o Settings   a About   q Quit";
+
+/// Rows of the settings screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingRow {
+    ServerUrl,
+    Theme,
+    Colors,
+    DisplayName,
+    HighlightOwnMarks,
+    BoardSymbols,
+    CustomSymbols,
+    Accessibility,
+    Coordinates,
+    QuickKeysHome,
+    SoloGames,
+    Layout,
+    AutoAdvance,
+    Sound(SoundEvent),
+}
+
+impl SettingRow {
+    /// Every row, in display order; the sound rows follow `SoundEvent::ALL`.
+    pub const ALL: [SettingRow; 18] = [
+        SettingRow::ServerUrl,
+        SettingRow::Theme,
+        SettingRow::Colors,
+        SettingRow::DisplayName,
+        SettingRow::HighlightOwnMarks,
+        SettingRow::BoardSymbols,
+        SettingRow::CustomSymbols,
+        SettingRow::Accessibility,
+        SettingRow::Coordinates,
+        SettingRow::QuickKeysHome,
+        SettingRow::SoloGames,
+        SettingRow::Layout,
+        SettingRow::AutoAdvance,
+        SettingRow::Sound(SoundEvent::YourTurn),
+        SettingRow::Sound(SoundEvent::Won),
+        SettingRow::Sound(SoundEvent::Lost),
+        SettingRow::Sound(SoundEvent::OpponentJoined),
+        SettingRow::Sound(SoundEvent::Error),
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SettingRow::ServerUrl => "Server URL",
+            SettingRow::Theme => "Theme",
+            SettingRow::Colors => "Colors",
+            SettingRow::DisplayName => "Display name",
+            SettingRow::HighlightOwnMarks => "Highlight my marks",
+            SettingRow::BoardSymbols => "Board symbols",
+            SettingRow::CustomSymbols => "Custom symbols",
+            SettingRow::Accessibility => "Accessibility mode",
+            SettingRow::Coordinates => "Board coordinates",
+            SettingRow::QuickKeysHome => "Quick keys home",
+            SettingRow::SoloGames => "Solo games",
+            SettingRow::Layout => "Layout",
+            SettingRow::AutoAdvance => "Auto-advance cursor",
+            SettingRow::Sound(SoundEvent::YourTurn) => "Sound: your turn",
+            SettingRow::Sound(SoundEvent::Won) => "Sound: you won",
+            SettingRow::Sound(SoundEvent::Lost) => "Sound: you lost",
+            SettingRow::Sound(SoundEvent::OpponentJoined) => "Sound: opponent joined",
+            SettingRow::Sound(SoundEvent::Error) => "Sound: error",
+        }
+    }
+}
 
 // Draw the home screen UI. home_index determines which menu item is highlighted.
 /// Draws the main Home screen of the TUI application.
 /// Arguments:
 /// - `frame`: The drawing surface passed in each render cycle. Ratatui's Frame is what you use to render widgets.
+/// - `palette`: Colors and border style of the active theme.
 /// - `home_index`: Which menu item to highlight (e.g. user selection).
-pub fn draw_home(frame: &mut Frame<'_>, home_index: usize) {
+/// - `quick_keys`: Show the single-key legend instead of the arrow-key list.
+/// - `server_online`: Result of the startup health check (`None` before it ran), shown as a badge.
+pub fn draw_home(
+    frame: &mut Frame<'_>,
+    palette: &Palette,
+    home_index: usize,
+    quick_keys: bool,
+    server_online: Option<bool>,
+) {
+    if quick_keys {
+        draw_home_quick_keys(frame, palette, server_online);
+        return;
+    }
+
     // Layout splits the rendering area vertically using percentage and fixed constraints
-    let area = centered_rect(70, 65, frame.area());
+    let area = palette.centered(70, 65, frame.area());
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(3),    // Title
-            Constraint::Length(8),    // Menu
-            Constraint::Length(3),    // Help area
-            Constraint::Min(1),       // Fills remaining space
+            Constraint::Length(3), // Title
+            Constraint::Length(9), // Menu
+            Constraint::Length(5), // Help area
+            Constraint::Min(1),    // Fills remaining space
         ])
         .split(area);
 
-    // Title with borders and centered alignment
-    let title = Paragraph::new("Tic-Tac-Toe (NestJS + Rust TUI)")
-        .alignment(Alignment::Center)
-        .block(Block::default().borders(Borders::ALL).title("Home"));
-    frame.render_widget(title, chunks[0]);
+    frame.render_widget(home_title(palette, server_online), chunks[0]);
 
     // Menu items for navigating different modes. ListItem allows custom highlighting.
-    let items = ["Solo vs Computer", "PvP", "Exit"];
-    let menu_items: Vec<ListItem> = items
+    let menu_items: Vec<ListItem> = HOME_ITEMS
         .iter()
         .enumerate()
         .map(|(idx, label)| {
             let line = if idx == home_index {
                 // Highlight selected item with bold and prefix
-                Line::from(vec![Span::styled(
-                    format!("> {label}"),
-                    Style::default().add_modifier(Modifier::BOLD),
-                )])
+                Line::from(vec![Span::styled(format!("> {label}"), palette.selected())])
             } else {
                 Line::from(format!("  {label}"))
             };
@@ -52,51 +286,186 @@ pub fn draw_home(frame: &mut Frame<'_>, home_index: usize) {
         })
         .collect();
 
-    let list = List::new(menu_items).block(Block::default().borders(Borders::ALL).title("Menu"));
+    let list = List::new(menu_items).block(palette.block("Menu"));
     frame.render_widget(list, chunks[1]);
 
     // Help paragraph, contains quick instructions for the user
     let help = Paragraph::new(
-        "Arrow Up/Down + Enter to select.\nq exits from anywhere.\nPlayer session id is generated once per app launch.",
+        "Up/Down (or k/j) + Enter to select. : opens the command palette. q exits from anywhere.\nc hosts a new PvP game, g quick-matches against whoever is waiting.\nSettings changes server, theme and name; they are saved between launches.",
     )
-    .block(Block::default().borders(Borders::ALL).title("Help"));
+    .block(palette.block("Help"));
     frame.render_widget(help, chunks[2]);
 }
 
+/// Title box of both Home layouts: the app name and an online/offline badge for the server.
+fn home_title(palette: &Palette, server_online: Option<bool>) -> Paragraph<'static> {
+    let badge = match server_online {
+        Some(true) => Span::styled("  [server online]", palette.indicator(Color::Green)),
+        Some(false) => Span::styled("  [server offline]", palette.indicator(Color::Red)),
+        None => Span::raw(""),
+    };
+    Paragraph::new(Line::from(vec![
+        Span::raw("Tic-Tac-Toe (NestJS + Rust TUI)"),
+        badge,
+    ]))
+    .alignment(Alignment::Center)
+    .block(palette.block("Home"))
+}
+
+/// Shown once at startup while the first health check runs.
+/// Arguments:
+/// - `frame`: Drawing surface for widgets.
+/// - `palette`: Colors and border style of the active theme.
+/// - `server`: Base URL being checked.
+/// - `timeout`: How long the check may take before the server counts as offline.
+pub fn draw_connecting(frame: &mut Frame<'_>, palette: &Palette, server: &str, timeout: Duration) {
+    let area = palette.centered(60, 25, frame.area());
+    let text = vec![
+        Line::from(format!("Connecting to {server}...")),
+        Line::from(""),
+        Line::from(format!(
+            "Gives up after {}s and starts offline.",
+            timeout.as_secs()
+        )),
+    ];
+    frame.render_widget(
+        Paragraph::new(text)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true })
+            .block(palette.block("Tic-Tac-Toe")),
+        area,
+    );
+}
+
+/// Compact Home layout for the "Quick keys home" setting: the title and one line of keys.
+/// Arguments:
+/// - `frame`: Drawing surface passed each render cycle.
+/// - `palette`: Colors and border style of the active theme.
+/// - `server_online`: Result of the last health check, shown as a badge in the title.
+fn draw_home_quick_keys(frame: &mut Frame<'_>, palette: &Palette, server_online: Option<bool>) {
+    let area = palette.centered(80, 40, frame.area());
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Title
+            Constraint::Length(3), // Key legend
+            Constraint::Length(4), // Help area
+            Constraint::Min(0),
+        ])
+        .split(area);
+
+    frame.render_widget(home_title(palette, server_online), chunks[0]);
+
+    // Wraps instead of clipping on narrow terminals, so no key drops out of sight
+    let keys = Paragraph::new(Span::styled(HOME_QUICK_KEYS, palette.selected()))
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .block(palette.block("Keys"));
+    frame.render_widget(keys, chunks[1]);
+
+    let help = Paragraph::new(
+        "c hosts a new PvP game right away. : opens the command palette.\nSettings > Quick keys home brings back the menu list.",
+    )
+    .block(palette.block("Help"));
+    frame.render_widget(help, chunks[2]);
+}
+
+/// Cells the screensaver fills, one per step, X and O alternating; X completes the bottom row.
+const SCREENSAVER_MOVES: [usize; BOARD_CELLS] = [4, 0, 8, 2, 6, 3, 5, 1, 7];
+/// How long each screensaver step stays on screen.
+const SCREENSAVER_STEP: Duration = Duration::from_millis(700);
+/// Steps the full board is held before it clears and the next round starts.
+const SCREENSAVER_HOLD_STEPS: usize = 3;
+
+/// The screensaver's board after it has run for `elapsed`, plus the cell filled last.
+/// Purely time based, so it needs no state between frames; every round swaps who starts.
+fn screensaver_board(elapsed: Duration) -> (Vec<Option<String>>, Option<usize>) {
+    let step = (elapsed.as_millis() / SCREENSAVER_STEP.as_millis()) as usize;
+    let cycle = SCREENSAVER_MOVES.len() + SCREENSAVER_HOLD_STEPS;
+    let placed = (step % cycle + 1).min(SCREENSAVER_MOVES.len());
+    let marks = if (step / cycle).is_multiple_of(2) {
+        ["X", "O"]
+    } else {
+        ["O", "X"]
+    };
+
+    let mut board = vec![None; BOARD_CELLS];
+    for (turn, &cell) in SCREENSAVER_MOVES[..placed].iter().enumerate() {
+        board[cell] = Some(marks[turn % 2].to_string());
+    }
+    (board, Some(SCREENSAVER_MOVES[placed - 1]))
+}
+
+/// Idle animation shown over Home (`--screensaver`): a game playing itself on a loop.
+/// Arguments:
+/// - `frame`: Drawing surface passed each render cycle.
+/// - `palette`: Colors and border style of the active theme.
+/// - `elapsed`: How long the screensaver has been running; drives the animation.
+/// - `marks`: Glyphs drawn for X and O, as on the real board.
+pub fn draw_screensaver(
+    frame: &mut Frame<'_>,
+    palette: &Palette,
+    elapsed: Duration,
+    marks: [String; 2],
+) {
+    let (board, latest) = screensaver_board(elapsed);
+    let view = BoardView {
+        highlighted_cell: latest,
+        ..BoardView::read_only(marks)
+    };
+
+    let mut lines = vec![Line::from(""), Line::from("")];
+    lines.extend(render_board_text(&board, &view, palette, None));
+    lines.push(Line::from(""));
+    lines.push(Line::from("Press any key"));
+    let area = palette.centered(40, 60, frame.area());
+    frame.render_widget(
+        Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .block(palette.block("Tic-Tac-Toe")),
+        area,
+    );
+}
+
 /// Draws the main Tic-Tac-Toe gameplay UI.
 /// Arguments:
 /// - `frame`: Drawing surface passed each render cycle.
+/// - `palette`: Colors and border style of the active theme.
 /// - `game`: Optionally references the game state (None: no game running).
 /// - `title`: A string used in the UI block title.
-/// - `board_cursor`: Which cell is 'hovered' for input.
+/// - `view`: Local board presentation state (cursor, pending move, digit layout).
 /// - `player_symbol`: The player's game symbol (e.g. 'X' or 'O').
+/// - `moves`: Moves played so far, listed in the scrollable "Moves" panel.
+///
+/// Terminals at least `WIDE_LAYOUT_MIN_WIDTH` columns wide get two columns (board on the left,
+/// header/history/controls on the right); narrower ones keep the vertical stack and show the
+/// history in place of the board while the "Moves" panel has focus.
 ///
 /// Rust lifetime syntax ('_): Means 'frame' can borrow from its context for as long as needed in this function.
 pub fn draw_game(
     frame: &mut Frame<'_>,
+    palette: &Palette,
     game: Option<&ApiGame>,
     title: &str,
-    board_cursor: usize,
+    view: &BoardView,
     player_symbol: String,
+    moves: &[MoveRecord],
 ) {
-    // Use centered_rect to calculate the display area: makes UI responsive to terminal size.
-    let area = centered_rect(80, 90, frame.area());
-    // Layout splits this area vertically for different widget blocks
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(4),     // Header
-            Constraint::Length(11),    // Tic-tac-toe board
-            Constraint::Length(5),     // Controls/hint
-            Constraint::Min(1),        // Fills space
-        ])
-        .split(area);
+    let title = &privacy_title(palette, title, view.privacy);
+    // Use palette.centered (centered_rect, larger in accessibility mode) to calculate the display area: makes UI responsive to terminal size.
+    let wide = frame.area().width >= WIDE_LAYOUT_MIN_WIDTH;
+    let area = palette.centered(if wide { 90 } else { 80 }, 90, frame.area());
+    let board_height = board_height(palette, view) + TURN_INDICATOR_LINES;
+    let layout = if wide {
+        GameLayout::wide(area, board_height)
+    } else {
+        GameLayout::stacked(area, board_height, view.focused_panel == GamePanel::Moves)
+    };
 
     // If game is None, show empty message and return
     let Some(game) = game else {
         frame.render_widget(
-            Paragraph::new("No active game.")
-                .block(Block::default().borders(Borders::ALL).title(title)),
+            Paragraph::new("No active game.").block(palette.block(title)),
             area,
         );
         return;
@@ -108,52 +477,536 @@ pub fn draw_game(
             "Status: WON | Winner: {}",
             game.winner.clone().unwrap_or_default()
         )
+    } else if game.is_waiting_for_player() {
+        "Status: Waiting for opponent".to_string()
+    } else if let Some(idle) = view.idle_warning {
+        format!(
+            "Status: {} | Opponent inactive for {}s",
+            game.status,
+            idle.as_secs()
+        )
     } else {
         format!("Status: {}", game.status)
     };
 
-    // Render header with game info
-    let header = Paragraph::new(format!(
-        "Game id: {}\nMode: {} | You are: {} | Current turn: {}\n{}",
-        game.id, game.mode, player_symbol, game.current_turn, status_line
-    ))
-    .block(Block::default().borders(Borders::ALL).title(title));
-    frame.render_widget(header, chunks[0]);
-
-    // Render tic-tac-toe board (uses helper below to make board text)
-    let board_text = render_board_text(&game.board, board_cursor);
-    let board = Paragraph::new(board_text).block(
-        Block::default()
-            .borders(Borders::ALL)
-            .title("Board (Arrows or 1..9, Enter to play)"),
-    );
-    frame.render_widget(board, chunks[1]);
+    // Display names are optional, so fall back to the seat label
+    let host = game.host_name.as_deref().unwrap_or("Host");
+    let guest = view
+        .opponent_name
+        .as_deref()
+        .or(game.guest_name.as_deref())
+        .unwrap_or(if game.guest_player_id.is_some() {
+            "Guest"
+        } else {
+            "(open seat)"
+        });
 
-    // Input hint and PvP info
-    let hint = Paragraph::new(
-        "Controls: Enter/Space = move, b = back, q = exit.\nPvP screen auto-refreshes each second for opponent moves.",
-    )
-    .block(Block::default().borders(Borders::ALL).title("Controls"));
-    frame.render_widget(hint, chunks[2]);
+    // A UUID is 36 chars; when it doesn't fit next to its label, show just its first chars
+    let id_width = layout
+        .header
+        .width
+        .saturating_sub(2 + "Game id: ".len() as u16);
+    let game_id = if view.privacy {
+        HIDDEN_ID.to_string()
+    } else if game.id.chars().count() <= id_width as usize {
+        game.id.clone()
+    } else {
+        format!("{} (i = full)", short_id(&game.id))
+    };
+    // The local player's seat and turn are emphasized so "you" stand out from the opponent
+    let seat_style = |symbol: &str| {
+        if symbol == player_symbol {
+            palette.selected()
+        } else {
+            Style::default()
+        }
+    };
+    // Accessibility mode labels my seat in words too
+    let you_label = |symbol: &str| {
+        if symbol == player_symbol && palette.is_accessible() {
+            " (you)"
+        } else {
+            ""
+        }
+    };
+    let turn = if player_symbol == "?" || game.status != "IN_PROGRESS" {
+        Span::raw(format!("Current turn: {}", game.current_turn))
+    } else if game.current_turn == player_symbol && palette.is_accessible() {
+        // The accent color alone may not be visible, so the cue is in the text itself
+        Span::styled(
+            format!(">> YOUR TURN ({player_symbol}) <<"),
+            palette.selected(),
+        )
+    } else if game.current_turn == player_symbol {
+        Span::styled(format!("Your turn ({player_symbol})"), palette.selected())
+    } else {
+        Span::raw(format!("Opponent's turn ({})", game.current_turn))
+    };
+    let header = Paragraph::new(vec![
+        Line::from(format!("Game id: {game_id}")),
+        Line::from(vec![
+            Span::raw("Players: "),
+            Span::styled(format!("{host} (X){}", you_label("X")), seat_style("X")),
+            Span::raw(" vs "),
+            Span::styled(format!("{guest} (O){}", you_label("O")), seat_style("O")),
+            // Who opens alternates between rematches, so it's worth spelling out
+            Span::raw(" | "),
+            Span::styled(
+                format!("{} goes first", game.first_turn()),
+                seat_style(game.first_turn()),
+            ),
+        ]),
+        Line::from(vec![
+            Span::raw(format!("Mode: {} | You are: ", game.mode)),
+            Span::styled(
+                if player_symbol == "?" {
+                    "watching (not a player)".to_string()
+                } else {
+                    player_symbol.clone()
+                },
+                palette.selected(),
+            ),
+            // Spectators and practice (both sides are "you") have no moves of their own
+            Span::raw(if player_symbol == "?" || game.mode == MODE_PRACTICE {
+                " | ".to_string()
+            } else {
+                format!(" ({} moves) | ", game.moves_by(&player_symbol))
+            }),
+            turn,
+        ]),
+        Line::from(vec![
+            Span::raw(match &game.spectators {
+                // Servers that don't track spectators send nothing, and the count stays hidden
+                Some(spectators) if game.mode == "PVP" => {
+                    format!("{status_line} | {} watching", spectators.len())
+                }
+                _ => status_line,
+            }),
+            // Rematch series score kept by the server, hidden on servers without it
+            match game.series_line(&player_symbol) {
+                Some(series) => Span::raw(format!(" | {series}")),
+                None => Span::raw(""),
+            },
+            // Analysis mode ignores Enter, so say so before the game looks frozen
+            match &view.analysis {
+                Some(note) => Span::styled(format!(" | {note}"), palette.selected()),
+                None => Span::raw(""),
+            },
+        ]),
+    ])
+    .block(palette.block(title));
+    frame.render_widget(header, layout.header);
+
+    // A waiting game has nothing to play yet, so the board is replaced by an explanation
+    if layout.board.is_empty() {
+        // Narrow layout with the history focused: the board is hidden for now
+    } else if game.is_waiting_for_player() {
+        // Right after hosting: what was created, and what to do with it
+        let access = if game.has_password {
+            "Locked: your friend also needs the password"
+        } else {
+            "Open: anyone in the lobby can join"
+        };
+        let key = |label: &str| Span::styled(label.to_string(), palette.selected());
+        let waiting = Paragraph::new(vec![
+            Line::from(vec![
+                Span::raw("You are hosting "),
+                key(game.name.as_deref().unwrap_or("Untitled")),
+            ]),
+            Line::from(format!("Game id: {}", shown_id(&game.id, view.privacy))),
+            Line::from(access),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Share this id with a friend to play",
+                palette.selected(),
+            )),
+            Line::from(vec![
+                key("y"),
+                Span::raw(" copies the id, "),
+                key("s"),
+                Span::raw(" shows a ready-made join command"),
+            ]),
+            Line::from(""),
+            Line::from("Waiting for opponent... moves unlock once someone joins."),
+        ])
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .block(palette.block("Board"));
+        frame.render_widget(waiting, layout.board);
+    } else {
+        // Render tic-tac-toe board (uses helper below to make board text)
+        let own_mark = view.highlight_own_marks.then_some(player_symbol.as_str());
+        let mut board_text = turn_indicator(game, [host, guest], view, palette, &player_symbol);
+        board_text.extend(render_board_text(&game.board, view, palette, own_mark));
+        let board_title = if view.pending_cell.is_some() {
+            "Board (Enter again to confirm, move to cancel)"
+        } else if view.coordinates {
+            "Board (Arrows, 1..9 or e.g. B2, Enter to play)"
+        } else {
+            "Board (Arrows or 1..9, Enter to play)"
+        };
+        let board = Paragraph::new(board_text).block(palette.block(board_title));
+        frame.render_widget(board, layout.board);
+    }
+
+    if let Some(history_area) = layout.history {
+        draw_move_history(frame, palette, moves, view, history_area);
+    }
+
+    // Names of the people watching, under the board on wide terminals
+    if let (Some(area), Some(spectators)) = (layout.spectators, &game.spectators) {
+        if !spectators.is_empty() && !area.is_empty() {
+            let names: Vec<ListItem> = spectators
+                .iter()
+                .map(|name| ListItem::new(name.as_str()))
+                .collect();
+            frame.render_widget(List::new(names).block(palette.block("Spectators")), area);
+        }
+    }
+
+    // Input hint and PvP info; `i` swaps the second line for the full game id
+    let hint_text = if view.show_full_id {
+        format!(
+            "Controls: {}.\nGame id: {}",
+            view.controls,
+            shown_id(&game.id, view.privacy)
+        )
+    } else {
+        format!(
+            "Controls: {}.\nTab switches between board and moves (PgUp/PgDn scroll), i shows the full game id.",
+            view.controls
+        )
+    };
+    let mut hint_lines: Vec<Line> = Vec::new();
+    // Beginner hint: why Enter does (or doesn't do) something on the hovered cell
+    if let Some(effect) = view.enter_hint {
+        hint_lines.push(Line::from(vec![
+            Span::raw("Enter here: "),
+            Span::styled(effect, palette.selected()),
+        ]));
+    }
+    hint_lines.extend(hint_text.lines().map(Line::from));
+    // Parked games: the ones waiting on me stand out so I know where to switch
+    match view.other_games.as_deref() {
+        Some([]) => hint_lines.push(Line::from(
+            "l = lobby while this game keeps running (to play several games at once).",
+        )),
+        Some(others) => {
+            let mut spans = vec![Span::raw("Other games (n = next, l = lobby): ")];
+            for (idx, other) in others.iter().enumerate() {
+                if idx > 0 {
+                    spans.push(Span::raw(", "));
+                }
+                if other.needs_me {
+                    let label = format!("{} (needs you)", other.name);
+                    spans.push(Span::styled(label, palette.selected()));
+                } else {
+                    spans.push(Span::raw(other.name.clone()));
+                }
+            }
+            hint_lines.push(Line::from(spans));
+        }
+        None => {}
+    }
+    let hint = Paragraph::new(hint_lines)
+        .wrap(Wrap { trim: false })
+        .block(palette.block("Controls"));
+    frame.render_widget(hint, layout.controls);
+}
+
+/// Terminal width (in columns) from which `draw_game` puts the board next to the header.
+const WIDE_LAYOUT_MIN_WIDTH: u16 = 110;
+
+/// Rows taken by the bordered board box: the grid, a blank line and the digit hints.
+/// The boxed grid of accessibility mode has two more lines (top and bottom edges),
+/// and coordinates add the line of column letters.
+fn board_height(palette: &Palette, view: &BoardView) -> u16 {
+    let grid = if palette.is_accessible() { 13 } else { 11 };
+    grid + u16::from(view.coordinates)
+}
+
+/// Rows `turn_indicator` adds above the board in `draw_game`.
+const TURN_INDICATOR_LINES: u16 = 2;
+
+/// Builds the two lines drawn above the board: both players with their marks, and a row of
+/// `^` under whoever is to move (none once the game is over).
+/// Arguments:
+/// - `game`: Game whose turn is shown.
+/// - `names`: Display names of the X and O players, as shown in the header.
+/// - `view`: Supplies the glyphs drawn for X and O.
+/// - `palette`: Accent style for my side while it's my turn.
+/// - `player_symbol`: The local player's symbol ("?" for spectators).
+fn turn_indicator(
+    game: &ApiGame,
+    names: [&str; 2],
+    view: &BoardView,
+    palette: &Palette,
+    player_symbol: &str,
+) -> Vec<Line<'static>> {
+    let in_progress = game.status == "IN_PROGRESS";
+    let mut sides = Vec::new();
+    let mut arrows = Vec::new();
+    for (idx, (symbol, name)) in ["X", "O"].into_iter().zip(names).enumerate() {
+        if idx > 0 {
+            sides.push(Span::raw("   vs   "));
+            arrows.push(Span::raw(" ".repeat(8)));
+        }
+        let label = format!("{} {name}", view.marks[idx]);
+        let width = Span::raw(label.as_str()).width();
+        let to_move = in_progress && game.current_turn == symbol;
+        let style = if to_move && symbol == player_symbol {
+            palette.selected()
+        } else if to_move {
+            Style::default().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        sides.push(Span::styled(label, style));
+        arrows.push(Span::styled(
+            if to_move { "^" } else { " " }.repeat(width),
+            style,
+        ));
+    }
+    vec![Line::from(sides), Line::from(arrows)]
+}
+
+/// Screen regions used by `draw_game`; `history` and `spectators` are `None` when they aren't
+/// shown, and `board` is an empty rect while the history takes its place.
+struct GameLayout {
+    header: Rect,
+    board: Rect,
+    history: Option<Rect>,
+    spectators: Option<Rect>,
+    controls: Rect,
+}
+
+impl GameLayout {
+    /// Everything in one column: header, board (or history when it has focus), controls.
+    fn stacked(area: Rect, board_height: u16, show_history: bool) -> Self {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(6),            // Header
+                Constraint::Length(board_height), // Tic-tac-toe board
+                Constraint::Length(5),            // Controls/hint
+                Constraint::Min(1),               // Fills space
+            ])
+            .split(area);
+
+        if show_history {
+            Self {
+                header: chunks[0],
+                board: Rect::default(),
+                history: Some(chunks[1]),
+                spectators: None,
+                controls: chunks[2],
+            }
+        } else {
+            Self {
+                header: chunks[0],
+                board: chunks[1],
+                history: None,
+                spectators: None,
+                controls: chunks[2],
+            }
+        }
+    }
+
+    /// Board and spectators in the left column, header/history/controls stacked in the right one.
+    fn wide(area: Rect, board_height: u16) -> Self {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+            .split(area);
+        let left = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(board_height), Constraint::Min(0)])
+            .split(columns[0]);
+        let right = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(6), // Header
+                Constraint::Min(3),    // Move history
+                Constraint::Length(5), // Controls/hint
+            ])
+            .split(columns[1]);
+
+        Self {
+            header: right[0],
+            board: left[0],
+            history: Some(right[1]),
+            spectators: Some(left[1]),
+            controls: right[2],
+        }
+    }
+}
+
+/// Draws the move list one move per line, with the selected move highlighted while focused.
+/// Arguments:
+/// - `frame`: Drawing surface for widgets.
+/// - `palette`: Colors and border style of the active theme.
+/// - `moves`: Moves played so far.
+/// - `view`: Supplies the focused panel and the scroll position (`None` follows the newest move).
+/// - `area`: Where the panel goes.
+///
+/// `ListState` scrolls the list just enough to keep the selected move visible, so selecting the
+/// last move keeps the newest entries on screen.
+fn draw_move_history(
+    frame: &mut Frame<'_>,
+    palette: &Palette,
+    moves: &[MoveRecord],
+    view: &BoardView,
+    area: Rect,
+) {
+    let focused = view.focused_panel == GamePanel::Moves;
+    let title = if focused {
+        "Moves (Up/Down or k/j, PgUp/PgDn, Tab/Esc back to board)"
+    } else {
+        "Moves (Tab to scroll)"
+    };
+    let mut block = palette.block(title);
+    if focused {
+        block = block.border_style(palette.selected());
+    }
+
+    if moves.is_empty() {
+        frame.render_widget(Paragraph::new("No moves yet.").block(block), area);
+        return;
+    }
+
+    let items: Vec<ListItem> = moves
+        .iter()
+        .enumerate()
+        .map(|(idx, record)| {
+            ListItem::new(format!(
+                "{}. {} -> cell {}",
+                idx + 1,
+                record.symbol,
+                record.index + 1
+            ))
+        })
+        .collect();
+    let selected = view
+        .history_scroll
+        .unwrap_or(moves.len() - 1)
+        .min(moves.len() - 1);
+    let mut state = ListState::default().with_selected(Some(selected));
+
+    let mut list = List::new(items).block(block);
+    if focused {
+        list = list
+            .highlight_style(palette.selected())
+            .highlight_symbol("> ");
+    }
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+/// Shown in place of game ids and passwords while privacy mode is on.
+pub const HIDDEN_ID: &str = "•••";
+
+/// `id`, or `HIDDEN_ID` in privacy mode.
+fn shown_id(id: &str, privacy: bool) -> &str {
+    if privacy {
+        HIDDEN_ID
+    } else {
+        id
+    }
+}
+
+/// Screen title with a lock appended while privacy mode is on (a word in ASCII mode).
+fn privacy_title(palette: &Palette, title: &str, privacy: bool) -> String {
+    match (privacy, palette.is_ascii()) {
+        (false, _) => title.to_string(),
+        (true, false) => format!("{title} 🔒"),
+        (true, true) => format!("{title} [private]"),
+    }
+}
+
+/// Characters of a game id kept when the full id doesn't fit.
+const SHORT_ID_CHARS: usize = 8;
+
+/// Column widths (in characters) of the game name and host in lobby rows.
+const LOBBY_NAME_WIDTH: usize = 24;
+const LOBBY_HOST_WIDTH: usize = 16;
+
+/// First `SHORT_ID_CHARS` characters of an id followed by `...`, e.g. `2d5f4a45...`.
+pub fn short_id(id: &str) -> String {
+    truncate_with_ellipsis(id, SHORT_ID_CHARS + 3)
+}
+
+/// Cuts `text` to at most `max_chars` characters, ending in `...` when something was cut.
+/// Counts chars rather than bytes, so names with accents or emoji are never split mid-character.
+fn truncate_with_ellipsis(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let kept: String = text.chars().take(max_chars.saturating_sub(3)).collect();
+    format!("{kept}...")
+}
+
+/// Lobby contents, split into the sections shown on screen.
+/// Fields:
+/// - `own_games`: Waiting games hosted by this player, listed first under "Your games".
+/// - `joinable_games`: Games with a free seat hosted by someone else.
+/// - `live_games`: In-progress games that can be watched.
+/// - `selected_index`: Highlighted game, counting through the three lists in that order.
+/// - `show_previews`: Whether live games get a mini board under their row.
+/// - `sort`: Label of the order the joinable games are listed in (`s` cycles it).
+/// - `open_loaded`: Open games fetched so far, own and joinable ones together.
+/// - `open_total`: Open games on the server, when it reports the total.
+/// - `has_more`: Whether a "Load more" row follows the games (selected at index = game count).
+/// - `stale_for`: Age of the shown lists when the last manual refresh failed.
+/// - `tab`: Which list is on screen, the open games above or `my_games`.
+/// - `my_games`: My unfinished games, for the "My games" tab.
+/// - `my_selected_index`: Highlighted row of `my_games`, kept apart from `selected_index`.
+/// - `privacy`: Privacy mode (Ctrl+P): game ids and the join password are hidden.
+pub struct LobbyView<'a> {
+    pub own_games: &'a [ApiGame],
+    pub joinable_games: &'a [ApiGame],
+    pub live_games: &'a [ApiGame],
+    pub selected_index: usize,
+    pub show_previews: bool,
+    pub sort: &'static str,
+    pub open_loaded: usize,
+    pub open_total: Option<usize>,
+    pub has_more: bool,
+    pub stale_for: Option<Duration>,
+    pub tab: LobbyTab,
+    pub my_games: &'a [MyGameRow],
+    pub my_selected_index: usize,
+    pub privacy: bool,
+}
+
+/// One of my unfinished games in the lobby's "My games" tab.
+/// Fields:
+/// - `name`: Game name (or shortened id).
+/// - `opponent`: Who I play against, or "(open seat)" while nobody joined.
+/// - `state`: "waiting for an opponent", "your turn" or "their turn".
+pub struct MyGameRow {
+    pub name: String,
+    pub opponent: String,
+    pub state: &'static str,
 }
 
 /// Draws the PvP lobby screen displaying available multiplayer games.
 /// Arguments:
 /// - `frame`: Drawing surface for rendering widgets (see ratatui Frame).
-/// - `pvp_games`: Slice of available game objects for lobby display.
-/// - `selected_index`: Which list item is highlighted (current selection).
+/// - `palette`: Colors and border style of the active theme.
+/// - `lobby`: Games to list per section and the current selection.
 /// - `join_password`: Current password input for joining a game.
 /// - `editing_join_password`: Boolean, true if currently in password editing mode.
+/// - `caret_on`: Blink phase of the caret shown while editing the password.
 ///
 /// This function uses ratatui's List and Paragraph widgets extensively to visualize lobby options and information.
 pub fn draw_pvp_lobby(
     frame: &mut Frame<'_>,
-    pvp_games: &[ApiGame],
-    selected_index: usize,
-    join_password: &str,
+    palette: &Palette,
+    lobby: &LobbyView,
+    join_password: &Secret<TextInput>,
     editing_join_password: bool,
+    caret_on: bool,
 ) {
-    let area = centered_rect(90, 90, frame.area());
+    let area = palette.centered(90, 90, frame.area());
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -164,33 +1017,57 @@ pub fn draw_pvp_lobby(
         ])
         .split(area);
 
-    let title = Paragraph::new("Open PvP games")
+    // A failed refresh keeps the old lists on screen; the title line says so instead
+    let title = match lobby.stale_for {
+        Some(age) => Paragraph::new(format!(
+            "Refresh failed, showing cached list (age: {}s)",
+            age.as_secs()
+        ))
+        .style(palette.indicator(Color::Yellow)),
+        None => {
+            let tab = |label: &str, active: bool| {
+                if active {
+                    Span::styled(format!("[{label}]"), palette.selected())
+                } else {
+                    Span::raw(format!(" {label} "))
+                }
+            };
+            Paragraph::new(Line::from(vec![
+                tab("Open games", lobby.tab == LobbyTab::Open),
+                Span::raw("  "),
+                tab("My games", lobby.tab == LobbyTab::Mine),
+                Span::raw("  (Tab switches)"),
+            ]))
+        }
+    };
+    let block_title = privacy_title(palette, "PvP Lobby", lobby.privacy);
+    let title = title
         .alignment(Alignment::Center)
-        .block(Block::default().borders(Borders::ALL).title("PvP Lobby"));
+        .block(palette.block(&block_title));
     frame.render_widget(title, chunks[0]);
 
-    let items: Vec<ListItem> = if pvp_games.is_empty() {
-        vec![ListItem::new("No open games")]
+    if lobby.tab == LobbyTab::Mine {
+        draw_my_games(frame, palette, lobby, chunks[1]);
     } else {
-        pvp_games
-            .iter()
-            .enumerate()
-            .map(|(idx, game)| {
-                let prefix = if idx == selected_index { ">" } else { " " };
-                let name = game.name.clone().unwrap_or_else(|| "Untitled".to_string());
-                let pass = if game.has_password { "locked" } else { "open" };
-                ListItem::new(format!("{prefix} {name} | id={} | {pass}", game.id))
-            })
-            .collect()
-    };
-
-    let list = List::new(items).block(Block::default().borders(Borders::ALL).title("Games"));
-    frame.render_widget(list, chunks[1]);
+        draw_open_games(frame, palette, lobby, chunks[1]);
+    }
 
-    let password_info = if join_password.is_empty() {
-        "Join password: <empty>".to_string()
+    let masked = join_password.masked();
+    // Even the masked length is left out in privacy mode
+    let password_info = if lobby.privacy {
+        Line::from(format!("Join password: {HIDDEN_ID}"))
+    } else if editing_join_password {
+        Line::from(
+            [
+                vec![Span::raw("Join password: ")],
+                input_spans(&masked, Some(join_password.caret()), caret_on),
+            ]
+            .concat(),
+        )
+    } else if masked.is_empty() {
+        Line::from("Join password: <empty>")
     } else {
-        format!("Join password: {}", "*".repeat(join_password.len()))
+        Line::from(format!("Join password: {masked}"))
     };
     let password_title = if editing_join_password {
         "Join Password (editing, Enter/Esc to stop)"
@@ -198,33 +1075,179 @@ pub fn draw_pvp_lobby(
         "Join Password (press p to edit)"
     };
     frame.render_widget(
-        Paragraph::new(password_info)
-            .block(Block::default().borders(Borders::ALL).title(password_title)),
+        Paragraph::new(password_info).block(palette.block(password_title)),
         chunks[2],
     );
 
-    let help = Paragraph::new(
-        "c=create game | p=edit join password | j/enter=join selected | r=refresh | b=home | q=exit",
-    )
-    .block(Block::default().borders(Borders::ALL).title("Help"));
+    let help = Paragraph::new(if lobby.tab == LobbyTab::Mine {
+        "tab=open games | enter=resume | x=cancel a game still waiting for an opponent | c=create game\nup/down or k/j=move | r=refresh | b/esc/m=home | q=exit"
+    } else {
+        "tab=my games | c=create game | g=quick match | p=edit join password | enter=join, reopen or watch | x=cancel your game\nup/down or k/j=move | v=toggle live board previews | s=sort | r=refresh | b/esc/m=home | q=exit"
+    })
+    .block(palette.block("Help"));
     frame.render_widget(help, chunks[3]);
 }
 
+/// Lobby "Open games" tab: my waiting games, the joinable ones and live ones, in that order,
+/// then the "Load more" row when the server has more open games.
+/// Arguments:
+/// - `frame`: Drawing surface for widgets.
+/// - `palette`: Colors and border style of the active theme.
+/// - `lobby`: Games to list per section and the current selection.
+/// - `area`: Where the list goes.
+fn draw_open_games(frame: &mut Frame<'_>, palette: &Palette, lobby: &LobbyView, area: Rect) {
+    let selected_index = lobby.selected_index;
+    // One row per game; `idx` is the row's position across all sections
+    // Names and hosts are cut to fixed-width columns so the id/lock columns line up
+    let game_row = |idx: usize, game: &ApiGame, preview: bool| {
+        let prefix = if idx == selected_index { ">" } else { " " };
+        let name =
+            truncate_with_ellipsis(game.name.as_deref().unwrap_or("Untitled"), LOBBY_NAME_WIDTH);
+        let host = truncate_with_ellipsis(
+            game.host_name.as_deref().unwrap_or("anonymous"),
+            LOBBY_HOST_WIDTH,
+        );
+        let pass = if game.has_password { "locked" } else { "open" };
+        let style = if idx == selected_index {
+            palette.selected()
+        } else {
+            Style::default()
+        };
+        let mut lines = vec![Line::from(format!(
+            "{prefix} {name:<name_width$} | by {host:<host_width$} | id={} | {pass}",
+            shown_id(&game.id, lobby.privacy),
+            name_width = LOBBY_NAME_WIDTH,
+            host_width = LOBBY_HOST_WIDTH,
+        ))];
+        if preview {
+            // Mini board indented under the row, like a thumbnail
+            let board_lines = render_mini_board(&game.board)
+                .into_iter()
+                .map(|row| Line::from(format!("      {row}")));
+            lines.extend(board_lines);
+        }
+        ListItem::new(lines).style(style)
+    };
+
+    let own_count = lobby.own_games.len();
+    let joinable_count = lobby.joinable_games.len();
+    let mut items: Vec<ListItem> = Vec::new();
+    if own_count > 0 {
+        items.push(ListItem::new("Your games (waiting for an opponent):"));
+        items.extend(
+            lobby
+                .own_games
+                .iter()
+                .enumerate()
+                .map(|(idx, game)| game_row(idx, game, false)),
+        );
+        items.push(ListItem::new(""));
+        items.push(ListItem::new("Joinable games:"));
+    }
+    if joinable_count == 0 {
+        items.push(ListItem::new("No open games"));
+    } else {
+        items.extend(
+            lobby
+                .joinable_games
+                .iter()
+                .enumerate()
+                .map(|(idx, game)| game_row(own_count + idx, game, false)),
+        );
+    }
+    if !lobby.live_games.is_empty() {
+        items.push(ListItem::new(""));
+        items.push(ListItem::new("Live games (Enter to watch):"));
+        items.extend(lobby.live_games.iter().enumerate().map(|(idx, game)| {
+            game_row(own_count + joinable_count + idx, game, lobby.show_previews)
+        }));
+    }
+
+    if lobby.has_more {
+        let idx = own_count + joinable_count + lobby.live_games.len();
+        let prefix = if idx == selected_index { ">" } else { " " };
+        let style = if idx == selected_index {
+            palette.selected()
+        } else {
+            Style::default()
+        };
+        items.push(ListItem::new(""));
+        items.push(ListItem::new(format!("{prefix} Load more open games")).style(style));
+    }
+
+    let games_title = match lobby.open_total {
+        Some(total) => format!(
+            "Games (sorted: {}, showing {} of {total})",
+            lobby.sort, lobby.open_loaded
+        ),
+        None => format!("Games (sorted: {})", lobby.sort),
+    };
+    let list = List::new(items).block(palette.block(&games_title));
+    frame.render_widget(list, area);
+}
+
+/// Lobby "My games" tab: each of my unfinished games with its opponent and whose turn it is.
+/// Arguments:
+/// - `frame`: Drawing surface for widgets.
+/// - `palette`: Colors and border style of the active theme.
+/// - `lobby`: Supplies `my_games` and `my_selected_index`.
+/// - `area`: Where the list goes.
+fn draw_my_games(frame: &mut Frame<'_>, palette: &Palette, lobby: &LobbyView, area: Rect) {
+    let items: Vec<ListItem> = if lobby.my_games.is_empty() {
+        vec![ListItem::new(
+            "No running games. c hosts one, Tab goes back to the open games.",
+        )]
+    } else {
+        lobby
+            .my_games
+            .iter()
+            .enumerate()
+            .map(|(idx, row)| {
+                let selected = idx == lobby.my_selected_index;
+                let prefix = if selected { ">" } else { " " };
+                let name = truncate_with_ellipsis(&row.name, LOBBY_NAME_WIDTH);
+                let opponent = truncate_with_ellipsis(&row.opponent, LOBBY_HOST_WIDTH);
+                let style = if selected {
+                    palette.selected()
+                } else {
+                    Style::default()
+                };
+                ListItem::new(format!(
+                    "{prefix} {name:<name_width$} | vs {opponent:<host_width$} | {}",
+                    row.state,
+                    name_width = LOBBY_NAME_WIDTH,
+                    host_width = LOBBY_HOST_WIDTH,
+                ))
+                .style(style)
+            })
+            .collect()
+    };
+    let list = List::new(items).block(palette.block("My games (Enter resumes)"));
+    frame.render_widget(list, area);
+}
+
 /// Draws the PvP game creation screen.
 /// Arguments:
 /// - `frame`: Drawing surface for rendering widgets.
+/// - `palette`: Colors and border style of the active theme.
 /// - `create_name`: Current name input for new game.
 /// - `create_password`: Current password input for new game.
 /// - `create_field_index`: Which input field is selected (0 for name, 1 for password).
+/// - `caret_on`: Blink phase of the caret (drawn only while on).
+/// - `privacy`: Privacy mode (Ctrl+P): the password isn't drawn at all, not even masked.
 ///
 /// Explains input UX and visual feedback for both fields, including password hiding.
+/// The selected field shows its caret as a reversed cell.
 pub fn draw_pvp_create(
     frame: &mut Frame<'_>,
-    create_name: &str,
-    create_password: &str,
+    palette: &Palette,
+    create_name: &TextInput,
+    create_password: &Secret<TextInput>,
     create_field_index: usize,
+    caret_on: bool,
+    privacy: bool,
 ) {
-    let area = centered_rect(75, 65, frame.area());
+    let area = palette.centered(75, 65, frame.area());
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -238,117 +1261,981 @@ pub fn draw_pvp_create(
     frame.render_widget(
         Paragraph::new("Create PvP game")
             .alignment(Alignment::Center)
-            .block(Block::default().borders(Borders::ALL).title("Create")),
+            .block(palette.block(&privacy_title(palette, "Create", privacy))),
         chunks[0],
     );
 
     let name_marker = if create_field_index == 0 { ">" } else { " " };
     let pass_marker = if create_field_index == 1 { ">" } else { " " };
 
+    let mut name_line = vec![Span::raw(format!(
+        "{name_marker} Name ({GAME_NAME_MIN_CHARS}..{GAME_NAME_MAX_CHARS}): "
+    ))];
+    name_line.extend(input_spans(
+        create_name.value(),
+        (create_field_index == 0).then_some(create_name.caret()),
+        caret_on,
+    ));
     frame.render_widget(
-        Paragraph::new(format!("{name_marker} Name (3..40): {create_name}"))
-            .block(Block::default().borders(Borders::ALL).title("Name")),
+        Paragraph::new(Line::from(name_line)).block(palette.block("Name")),
         chunks[1],
     );
 
+    // The password is masked one `*` per character, so the caret lines up the same way
+    let masked = create_password.masked();
+    let mut pass_line = vec![Span::raw(format!(
+        "{pass_marker} Password optional (3..32): "
+    ))];
+    if privacy {
+        pass_line.push(Span::raw(HIDDEN_ID));
+    } else {
+        pass_line.extend(input_spans(
+            &masked,
+            (create_field_index == 1).then_some(create_password.caret()),
+            caret_on,
+        ));
+    }
     frame.render_widget(
-        Paragraph::new(format!(
-            "{pass_marker} Password optional (3..32): {}",
-            "*".repeat(create_password.len())
-        ))
-        .block(Block::default().borders(Borders::ALL).title("Password")),
+        Paragraph::new(Line::from(pass_line)).block(palette.block("Password")),
         chunks[2],
     );
 
     frame.render_widget(
-        Paragraph::new("Type text, Tab to switch field, Enter to create, Esc/b to go back")
-            .block(Block::default().borders(Borders::ALL).title("Help")),
+        Paragraph::new("Type text, Left/Right/Home/End move the caret, Tab to switch field, Enter to create, Esc/b to go back (what you typed is kept), Ctrl+R to clear the form")
+            .wrap(Wrap { trim: true })
+            .block(palette.block("Help")),
         chunks[3],
     );
 }
 
+/// Splits a text field into spans with the caret drawn as a reversed cell.
+/// Arguments:
+/// - `text`: What the field shows (already masked for passwords).
+/// - `caret`: Caret position in characters, `None` when the field isn't being edited.
+/// - `caret_on`: Blink phase; while off the text is drawn plain.
+///
+/// A caret at the end highlights a trailing space, like a block cursor in a terminal.
+fn input_spans(text: &str, caret: Option<usize>, caret_on: bool) -> Vec<Span<'static>> {
+    let Some(caret) = caret.filter(|_| caret_on) else {
+        return vec![Span::raw(text.to_string())];
+    };
+
+    let before: String = text.chars().take(caret).collect();
+    let under: String = text
+        .chars()
+        .nth(caret)
+        .map_or(" ".to_string(), String::from);
+    let after: String = text.chars().skip(caret + 1).collect();
+    vec![
+        Span::raw(before),
+        Span::styled(under, Style::default().add_modifier(Modifier::REVERSED)),
+        Span::raw(after),
+    ]
+}
+
 /// Shows a single informational message popup.
 /// Arguments:
 /// - `frame`: Drawing surface for widgets.
+/// - `palette`: Colors and border style of the active theme.
 /// - `info_message`: The text to display.
+/// - `can_retry`: The message is about a request that failed transiently and `r` sends it again.
+/// - `can_concede`: The message interrupted a running PvP game, so offer to resign it on the way out.
+/// - `scroll`: Lines scrolled off the top; clamped so the last line stays at the bottom.
 ///
 /// Uses a simple paragraph block. This can be used for error messages, notifications, etc.
-pub fn draw_info(frame: &mut Frame<'_>, info_message: &str) {
-    let area = centered_rect(75, 40, frame.area());
+/// Long lines wrap, and messages taller than the box (stack traces, big JSON bodies) scroll.
+pub fn draw_info(
+    frame: &mut Frame<'_>,
+    palette: &Palette,
+    info_message: &str,
+    can_retry: bool,
+    can_concede: bool,
+    scroll: u16,
+) {
+    let area = info_area(palette, frame.area());
+    let max_scroll = info_max_scroll(palette, frame.area(), info_message, can_retry, can_concede);
+    let title = if max_scroll > 0 {
+        "Message (Up/Down or k/j, PgUp/PgDn to scroll)"
+    } else {
+        "Message"
+    };
     frame.render_widget(
-        Paragraph::new(info_message)
+        info_paragraph(info_message, can_retry, can_concede)
             .alignment(Alignment::Left)
-            .block(Block::default().borders(Borders::ALL).title("Message")),
+            .scroll((scroll.min(max_scroll), 0))
+            .block(palette.block(title)),
         area,
     );
 }
 
-pub fn draw_game_over(frame: &mut Frame<'_>, game_over_message: &str) {
-    let area = centered_rect(70, 45, frame.area());
+/// How far the Info message can scroll on a terminal of size `screen` before its last line
+/// reaches the bottom of the box (0 when everything fits). `palette` decides the box size.
+pub fn info_max_scroll(
+    palette: &Palette,
+    screen: Rect,
+    info_message: &str,
+    can_retry: bool,
+    can_concede: bool,
+) -> u16 {
+    let area = info_area(palette, screen);
+    // The box border takes one cell on each side
+    let lines = info_paragraph(info_message, can_retry, can_concede)
+        .line_count(area.width.saturating_sub(2));
+    u16::try_from(lines)
+        .unwrap_or(u16::MAX)
+        .saturating_sub(area.height.saturating_sub(2))
+}
+
+fn info_area(palette: &Palette, screen: Rect) -> Rect {
+    palette.centered(75, 40, screen)
+}
+
+/// The Info message as a wrapping paragraph, followed by the keys for retrying and resigning when offered.
+fn info_paragraph(info_message: &str, can_retry: bool, can_concede: bool) -> Paragraph<'static> {
+    let mut text = info_message.to_string();
+    if can_retry || can_concede {
+        text.push('\n');
+    }
+    if can_retry {
+        text.push_str("\nPress r to try again.");
+    }
+    if can_concede {
+        text.push_str(
+            "\nPress x to resign the game and return Home (your opponent won't be left waiting).",
+        );
+    }
+    if can_retry || can_concede {
+        text.push_str("\nPress Enter, b or m to return Home.");
+    }
+    Paragraph::new(text).wrap(Wrap { trim: false })
+}
+
+/// Game result screen contents.
+/// Fields:
+/// - `message`: Result summary built by the app; the game id is shown under its first line.
+/// - `outcome`: My result, shown as a colored badge; `None` for watched and practice games.
+/// - `controls`: Keys usable on this screen right now (see `help::footer`).
+/// - `rematch_hint`: Where the rematch handshake stands (empty when there is nothing to offer).
+/// - `autoreturn_in`: Seconds until the app goes back Home by itself (`--autoreturn`), if counting down.
+/// - `seed`: Seed of the computer's moves in a local solo game, so the game can be reproduced.
+/// - `moves`: Every move of the game, for the recap under the result (hidden when empty).
+/// - `winner`: Symbol that won, `None` for a draw.
+/// - `recap_scroll`: Lines of the recap scrolled past.
+/// - `game_id`: Id of the finished game, if known.
+/// - `privacy`: Privacy mode (Ctrl+P): the game id is hidden.
+pub struct GameOverView<'a> {
+    pub message: &'a str,
+    pub outcome: Option<GameOutcome>,
+    pub controls: String,
+    pub rematch_hint: &'a str,
+    pub autoreturn_in: Option<u64>,
+    pub seed: Option<u64>,
+    pub moves: &'a [MoveRecord],
+    pub winner: Option<&'a str>,
+    pub recap_scroll: u16,
+    pub game_id: Option<&'a str>,
+    pub privacy: bool,
+}
+
+/// Draws the game result screen.
+/// Arguments:
+/// - `frame`: Drawing surface for widgets.
+/// - `palette`: Colors and border style of the active theme.
+/// - `view`: Result and the hints to show under it.
+pub fn draw_game_over(frame: &mut Frame<'_>, palette: &Palette, view: &GameOverView) {
+    let GameOverView {
+        message: game_over_message,
+        outcome,
+        ref controls,
+        rematch_hint,
+        autoreturn_in,
+        seed,
+        moves,
+        winner,
+        recap_scroll,
+        game_id,
+        privacy,
+    } = *view;
+    let game_over_message = match (game_id, game_over_message.split_once('\n')) {
+        (Some(id), Some((headline, details))) => {
+            format!("{headline}\nGame id: {}\n{details}", shown_id(id, privacy))
+        }
+        _ => game_over_message.to_string(),
+    };
+    let area = palette.centered(70, 60, frame.area());
+    // The recap gets three lines of its own; longer ones scroll
+    let recap_height = if moves.is_empty() { 0 } else { 5 };
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(recap_height)])
+        .split(area);
+    let rematch_hint = if rematch_hint.is_empty() {
+        String::new()
+    } else {
+        format!("\n\n{rematch_hint}")
+    };
+    let seed_hint = match seed {
+        Some(seed) => {
+            format!("\nSeed: {seed} (start with --seed {seed} for the same computer moves).")
+        }
+        None => String::new(),
+    };
+    let autoreturn_hint = match autoreturn_in {
+        Some(secs) => format!("\n\nReturning to Main Menu in {secs}s (press any key to stay)."),
+        None => String::new(),
+    };
     frame.render_widget(
         Paragraph::new(format!(
-            "{game_over_message}\n\nPress Enter or b to return to Main Menu.\nPress q to exit."
+            "{game_over_message}{rematch_hint}{autoreturn_hint}\n\nKeys: {controls}.{seed_hint}"
         ))
         .alignment(Alignment::Left)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title("Game Finished"),
+        .wrap(Wrap { trim: false })
+        .block(game_over_block(palette, outcome)),
+        chunks[0],
+    );
+
+    if !moves.is_empty() {
+        frame.render_widget(
+            Paragraph::new(move_recap(moves, winner))
+                .wrap(Wrap { trim: true })
+                .scroll((recap_scroll, 0))
+                .block(palette.block("Recap")),
+            chunks[1],
+        );
+    }
+}
+
+/// Builds the one-line story of a game, e.g. "X: center, O: top-left, X: bottom-right - X wins".
+/// Arguments:
+/// - `moves`: Every move of the game, in play order.
+/// - `winner`: Symbol that won, `None` for a draw.
+pub fn move_recap(moves: &[MoveRecord], winner: Option<&str>) -> String {
+    let sequence = moves
+        .iter()
+        .map(|record| format!("{}: {}", record.symbol, CELL_NAMES[record.index]))
+        .collect::<Vec<_>>()
+        .join(", ");
+    match winner {
+        Some(winner) => format!("{sequence} - {winner} wins"),
+        None => format!("{sequence} - draw"),
+    }
+}
+
+// The result block, with my outcome as a badge on the right of its top border.
+fn game_over_block(palette: &Palette, outcome: Option<GameOutcome>) -> Block<'static> {
+    let block = palette.block("Game Finished");
+    let Some(outcome) = outcome else {
+        return block;
+    };
+    let color = match outcome {
+        GameOutcome::Won => Color::Green,
+        GameOutcome::Lost => Color::Red,
+        GameOutcome::Draw => Color::Yellow,
+    };
+    block.title(
+        Line::styled(format!(" {} ", outcome.label()), palette.indicator(color)).right_aligned(),
+    )
+}
+
+/// Draws the replay viewer for a finished game.
+/// Arguments:
+/// - `frame`: Drawing surface for widgets.
+/// - `palette`: Colors and border style of the active theme.
+/// - `moves`: Every move of the game, in play order.
+/// - `step`: How many moves are applied to the shown board (0 = empty board).
+/// - `marks`: Glyphs drawn for X and O (see `BoardView::marks`).
+/// - `winning_line`: Cells that won the game, highlighted once the last move is shown.
+///
+/// The board is rebuilt from scratch for the chosen step and fed to `render_board_text`,
+/// with the latest applied move starred and no cursor.
+pub fn draw_replay(
+    frame: &mut Frame<'_>,
+    palette: &Palette,
+    moves: &[MoveRecord],
+    step: usize,
+    marks: &[String; 2],
+    winning_line: Option<[usize; 3]>,
+) {
+    let last_move = step.checked_sub(1).map(|idx| &moves[idx]);
+    let view = BoardView {
+        highlighted_cell: last_move.map(|record| record.index),
+        winning_line: winning_line.filter(|_| step == moves.len()),
+        ..BoardView::read_only(marks.clone())
+    };
+
+    let area = palette.centered(60, 70, frame.area());
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Length(board_height(palette, &view)),
+            Constraint::Length(3),
+            Constraint::Min(0),
+        ])
+        .split(area);
+
+    let mut board = vec![None; BOARD_CELLS];
+    for record in &moves[..step] {
+        board[record.index] = Some(record.symbol.clone());
+    }
+
+    let status = match last_move {
+        Some(record) => format!(
+            "Move {step} of {}: {} in cell {}",
+            moves.len(),
+            record.symbol,
+            record.index + 1
         ),
+        None => format!("Start (0 of {} moves)", moves.len()),
+    };
+    frame.render_widget(
+        Paragraph::new(status).block(palette.block("Replay")),
+        chunks[0],
+    );
+
+    frame.render_widget(
+        Paragraph::new(render_board_text(&board, &view, palette, None))
+            .block(palette.block("Board")),
+        chunks[1],
+    );
+
+    frame.render_widget(
+        Paragraph::new("Left/Right, Up/Down or k/j = step | PgUp/PgDn = 5 steps | Home/End = first/last | b/Esc = back | m = main menu")
+            .block(palette.block("Help")),
+        chunks[2],
+    );
+}
+
+/// Settings screen contents.
+/// Fields:
+/// - `values`: Current value of each row in `SettingRow::ALL`, already formatted.
+/// - `selected_index`: Which row is highlighted.
+/// - `editing`: Text typed so far when the highlighted row is being edited.
+/// - `server_status`: Result of the last health check against the configured server.
+/// - `recent_servers`: Servers used before, most recent first; listed while editing the URL.
+/// - `recent_index`: Recent server currently picked, if any.
+pub struct SettingsView<'a> {
+    pub values: &'a [String],
+    pub selected_index: usize,
+    pub editing: Option<&'a TextInput>,
+    pub server_status: &'a str,
+    pub recent_servers: &'a [String],
+    pub recent_index: Option<usize>,
+}
+
+/// Draws the settings screen.
+/// Arguments:
+/// - `frame`: Drawing surface for widgets.
+/// - `palette`: Colors and border style of the active theme.
+/// - `view`: Rows, edit state and server info to show.
+/// - `caret_on`: Blink phase of the caret in the edited row.
+pub fn draw_settings(
+    frame: &mut Frame<'_>,
+    palette: &Palette,
+    view: &SettingsView,
+    caret_on: bool,
+) {
+    let SettingsView {
+        values,
+        selected_index,
+        editing,
+        server_status,
+        recent_servers,
+        recent_index,
+    } = *view;
+    let picking_server = editing.is_some()
+        && SettingRow::ALL[selected_index] == SettingRow::ServerUrl
+        && !recent_servers.is_empty();
+    let server_rows = if picking_server {
+        recent_servers.len() as u16
+    } else {
+        1
+    };
+    let area = palette.centered(75, 65, frame.area());
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Length(SettingRow::ALL.len() as u16 + 2), // One row per option plus borders
+            Constraint::Length(server_rows + 2),
+            Constraint::Length(4),
+            Constraint::Min(0),
+        ])
+        .split(area);
+
+    frame.render_widget(
+        Paragraph::new("Settings")
+            .alignment(Alignment::Center)
+            .block(palette.block("Settings")),
+        chunks[0],
+    );
+
+    // The edited row shows the input buffer instead of the saved value
+    let items: Vec<ListItem> = SettingRow::ALL
+        .iter()
+        .zip(values)
+        .enumerate()
+        .map(|(idx, (row, value))| {
+            let label = row.label();
+            if idx != selected_index {
+                return ListItem::new(format!("  {label}: {value}"));
+            }
+            let shown = match editing {
+                Some(input) => Line::from(
+                    [
+                        vec![Span::raw(format!("> {label}: "))],
+                        input_spans(input.value(), Some(input.caret()), caret_on),
+                    ]
+                    .concat(),
+                ),
+                None => Line::from(format!("> {label}: {value}")),
+            };
+            ListItem::new(shown).style(palette.selected())
+        })
+        .collect();
+    // On short terminals the list scrolls to keep the selected row in view
+    let mut state = ListState::default().with_selected(Some(selected_index));
+    frame.render_stateful_widget(
+        List::new(items).block(palette.block("Options")),
+        chunks[1],
+        &mut state,
+    );
+
+    // While the URL is edited, the server box becomes a list of servers to pick from
+    if picking_server {
+        let items: Vec<ListItem> = recent_servers
+            .iter()
+            .enumerate()
+            .map(|(idx, url)| {
+                if Some(idx) == recent_index {
+                    ListItem::new(format!("> {url}")).style(palette.selected())
+                } else {
+                    ListItem::new(format!("  {url}"))
+                }
+            })
+            .collect();
+        frame.render_widget(
+            List::new(items).block(palette.block("Recent servers")),
+            chunks[2],
+        );
+    } else {
+        frame.render_widget(
+            Paragraph::new(server_status).block(palette.block("Server")),
+            chunks[2],
+        );
+    }
+
+    let help = if picking_server {
+        "Type a URL or Up/Down to pick a recent server, Enter to save, Esc to cancel."
+    } else if editing.is_some() {
+        "Type the new value, Enter to save, Esc to cancel."
+    } else {
+        "Up/Down (or k/j) to select, Enter to edit or change, Left/Right to cycle themes.\nb/Esc/m = back to Home. Changes are saved immediately."
+    };
+    frame.render_widget(Paragraph::new(help).block(palette.block("Help")), chunks[3]);
+}
+
+/// Draws a centered popup on top of the current screen.
+/// Arguments:
+/// - `frame`: Drawing surface for widgets.
+/// - `palette`: Colors and border style of the active theme.
+/// - `title`: Popup block title.
+/// - `body`: Popup text, may span several lines.
+pub fn draw_popup(frame: &mut Frame<'_>, palette: &Palette, title: &str, body: &str) {
+    let area = palette.centered(70, 40, frame.area());
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(body)
+            .wrap(Wrap { trim: false })
+            .block(palette.block(title)),
         area,
     );
 }
 
-/// Constructs a string representation of the tic-tac-toe board for display in the UI.
+/// Draws the `:` command palette as a one-line input at the bottom of the screen.
+/// Arguments:
+/// - `frame`: Drawing surface for widgets.
+/// - `palette`: Colors and border style of the active theme.
+/// - `input`: Text typed so far (without the leading `:`).
+/// - `caret_on`: Blink phase of the caret, which always sits at the end of the input.
+pub fn draw_command_palette(frame: &mut Frame<'_>, palette: &Palette, input: &str, caret_on: bool) {
+    let screen = frame.area();
+    let height = 3.min(screen.height);
+    let area = Rect::new(
+        screen.x,
+        screen.y + screen.height - height,
+        screen.width,
+        height,
+    );
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(Line::from(
+            [
+                vec![Span::raw(":")],
+                input_spans(input, Some(input.chars().count()), caret_on),
+            ]
+            .concat(),
+        ))
+        .block(palette.block(
+            "Command (solo | pvp | quick | join <id> | create <name> | server <url> | stats | quit, Esc to close)",
+        )),
+        area,
+    );
+}
+
+/// Draws a short-lived notification box in the top-right corner, on top of the current screen.
+/// Arguments:
+/// - `frame`: Drawing surface for widgets.
+/// - `palette`: Colors and border style of the active theme.
+/// - `message`: The notification text (kept to one line).
+///
+/// `Clear` wipes the cells underneath first, so the toast doesn't blend with the screen behind it.
+pub fn draw_toast(frame: &mut Frame<'_>, palette: &Palette, message: &str) {
+    let screen = frame.area();
+    let width = (message.chars().count() as u16 + 4).min(screen.width);
+    let height = 3.min(screen.height);
+    let area = Rect::new(screen.x + screen.width - width, screen.y, width, height);
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(message)
+            .style(palette.selected())
+            .block(palette.block("Notice")),
+        area,
+    );
+}
+
+/// Inverts the colors of everything drawn so far, for the short win/lose flash.
+/// Arguments:
+/// - `frame`: Drawing surface for widgets, already holding the finished screen.
+///
+/// Works on the frame's buffer directly, so it needs no knowledge of what is on screen.
+pub fn draw_flash(frame: &mut Frame<'_>) {
+    let area = frame.area();
+    frame
+        .buffer_mut()
+        .set_style(area, Style::default().add_modifier(Modifier::REVERSED));
+}
+
+/// Draws the one-line status bar in the bottom-right corner, on top of the current screen.
+/// Arguments:
+/// - `frame`: Drawing surface for widgets.
+/// - `palette`: Colors and border style of the active theme.
+/// - `latency`: Round trip of the last answered request, `None` before the first one.
+///
+/// The latency is green under 150ms, yellow under 500ms and red above.
+pub fn draw_status_bar(frame: &mut Frame<'_>, palette: &Palette, latency: Option<Duration>) {
+    let Some(latency) = latency else {
+        return;
+    };
+
+    let millis = latency.as_millis();
+    let color = match millis {
+        0..150 => Color::Green,
+        150..500 => Color::Yellow,
+        _ => Color::Red,
+    };
+    let text = format!(" last: {millis}ms ");
+    let screen = frame.area();
+    if screen.height == 0 {
+        return;
+    }
+    let width = (text.len() as u16).min(screen.width);
+    let area = Rect::new(
+        screen.x + screen.width - width,
+        screen.y + screen.height - 1,
+        width,
+        1,
+    );
+
+    frame.render_widget(Paragraph::new(text).style(palette.indicator(color)), area);
+}
+
+/// Compact 3-line version of the board for list previews: just the marks, `.` for empty cells.
+/// Arguments:
+/// - `board`: Cell values, same shape as in `render_board_text`.
+///
+/// Returns:
+/// - One string per board row, e.g. `X . O`.
+fn render_mini_board(board: &[Option<String>]) -> Vec<String> {
+    board
+        .chunks(3)
+        .map(|row| {
+            row.iter()
+                .map(|cell| cell.as_deref().unwrap_or("."))
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect()
+}
+
+/// Constructs the lines of the tic-tac-toe board for display in the UI.
 /// Arguments:
 /// - `board`: Represents the current board cell values. Each Option<String> is either Some(symbol) or None.
-/// - `board_cursor`: Index (0..8) of the cell currently highlighted/selected.
+///   Any square board works (e.g. 81 cells for 9x9); the digit hint grid is only added for 3x3.
+/// - `view`: Cursor (bracketed cell), pending two-step move (braced cell), opponent's last move (starred cell)
+///   and digit layout for the hint grid.
+/// - `palette`: Supplies the accent style for `own_mark`.
+/// - `own_mark`: Symbol whose cells are drawn in the accent color (the local player's), if any.
+///
 /// Returns:
-/// - String: Multi-line string representing the board layout.
+/// - Vec<Line>: One line per board row, separator and hint row.
 ///
 /// This visualization is used for rendering the board in the terminal. Highlighted cells are bracketed.
-fn render_board_text(board: &[Option<String>], board_cursor: usize) -> String {
-    // Explicit board mapping to keep control flow easy to follow for beginners.
-    let mut rows = Vec::new();
-
-    for r in 0..3 {
-        let mut cells = Vec::new();
-        for c in 0..3 {
-            let idx = r * 3 + c;
-            let value = board[idx].as_deref().unwrap_or(" ");
-            let label = if board_cursor == idx {
-                format!("[{value}]") // Highlight selected cell with brackets
+/// X and O are drawn with `view.marks`; every cell is padded to the widest glyph so that
+/// double-width emoji keep the columns aligned.
+pub fn render_board_text(
+    board: &[Option<String>],
+    view: &BoardView,
+    palette: &Palette,
+    own_mark: Option<&str>,
+) -> Vec<Line<'static>> {
+    // Accessibility mode draws a box-drawing grid with wider cells
+    let boxed = palette.is_accessible();
+    let separator = if boxed { "│" } else { "|" };
+    let cell_width = view
+        .marks
+        .iter()
+        .map(|mark| Span::raw(mark.as_str()).width())
+        .max()
+        .unwrap_or(1)
+        .max(1);
+    let side = board.len().isqrt();
+    // Cells are padded by a space (or a bracket) on each side, and by two more when boxed
+    let cell_span = cell_width + if boxed { 4 } else { 2 };
+    // Row numbers take a column of their own, wide enough for the last one
+    let label_width = if view.coordinates {
+        side.to_string().len() + 1
+    } else {
+        0
+    };
+    let indent = " ".repeat(label_width);
+    let rule = |left: &str, mid: &str, right: &str| {
+        let inner = "─".repeat(cell_span);
+        let mut line = format!("{indent}{left}{inner}");
+        for _ in 1..side {
+            line.push_str(mid);
+            line.push_str(&inner);
+        }
+        line.push_str(right);
+        Line::from(line)
+    };
+
+    let mut lines = Vec::with_capacity(2 * side + 6);
+    // Optional coordinates: column letters centered above the cells
+    if view.coordinates {
+        let mut header = " ".repeat(label_width + usize::from(boxed));
+        for (c, letter) in ('A'..='Z').take(side).enumerate() {
+            if c > 0 {
+                header.push(' '); // column separator
+            }
+            let left = cell_span / 2;
+            header.push_str(&" ".repeat(left));
+            header.push(letter);
+            header.push_str(&" ".repeat(cell_span - left - 1));
+        }
+        lines.push(Line::from(header.trim_end().to_string()));
+    }
+    if boxed {
+        lines.push(rule("┌", "┬", "┐"));
+    }
+    for r in 0..side {
+        if r > 0 && boxed {
+            lines.push(rule("├", "┼", "┤"));
+        } else if r > 0 {
+            let width = side * cell_span + side - 1;
+            lines.push(Line::from(format!("{indent}{}", "-".repeat(width)))); // row separator
+        }
+        let mut cells = Vec::with_capacity(2 * side + 2);
+        if view.coordinates {
+            cells.push(Span::raw(format!("{:<label_width$}", r + 1))); // row number
+        }
+        for c in 0..side {
+            let idx = r * side + c;
+            let ghost = view
+                .ghost
+                .as_ref()
+                .filter(|(cell, _)| *cell == idx && board[idx].is_none())
+                .map(|(_, symbol)| symbol.as_str());
+            let glyph = match board[idx].as_deref().or(ghost) {
+                Some("X") => view.marks[0].as_str(),
+                Some("O") => view.marks[1].as_str(),
+                Some(other) => other,
+                None => "",
+            };
+            let (open, close) = if view.pending_cell == Some(idx) {
+                ('{', '}') // Pending two-step move gets braces
+            } else if view.cursor == Some(idx) {
+                ('[', ']') // Highlight selected cell with brackets
+            } else if view.highlighted_cell == Some(idx) {
+                ('*', '*') // Opponent's latest move
+            } else {
+                (' ', ' ') // Unselected cell
+            };
+            // Built in one buffer; padded by display width, not chars: an emoji takes two columns
+            let mut label = String::with_capacity(glyph.len() + cell_width + 4);
+            if boxed {
+                label.push(' ');
+            }
+            label.push(open);
+            label.push_str(glyph);
+            for _ in Span::raw(glyph).width()..cell_width {
+                label.push(' ');
+            }
+            label.push(close);
+            if boxed {
+                label.push(' ');
+            }
+            let style = if view.winning_line.is_some_and(|line| line.contains(&idx)) {
+                palette.selected().add_modifier(Modifier::BOLD)
+            } else if ghost.is_some() {
+                Style::default().add_modifier(Modifier::DIM)
+            } else if own_mark.is_some() && board[idx].as_deref() == own_mark {
+                palette.selected()
             } else {
-                format!(" {value} ") // Unselected cell
+                Style::default()
             };
-            cells.push(label);
+            if c > 0 || boxed {
+                cells.push(Span::raw(separator)); // column separator (and left edge when boxed)
+            }
+            cells.push(Span::styled(label, style));
+        }
+        if boxed {
+            cells.push(Span::raw(separator)); // right edge
         }
-        rows.push(cells.join("|")); // row separator
+        lines.push(Line::from(cells));
+    }
+    if boxed {
+        lines.push(rule("└", "┴", "┘"));
     }
 
-    // Headers for numeric cell input shortcuts
-    format!(
-        "{}\n-----------\n{}\n-----------\n{}\n\n1 2 3\n4 5 6\n7 8 9",
-        rows[0], rows[1], rows[2]
-    )
+    // Hint grid for numeric cell input shortcuts, matching the active digit mapping
+    if side == 3 {
+        let hint = if view.numpad {
+            ["7 8 9", "4 5 6", "1 2 3"]
+        } else {
+            ["1 2 3", "4 5 6", "7 8 9"]
+        };
+        lines.push(Line::default());
+        lines.extend(hint.into_iter().map(Line::from));
+    }
+    lines
 }
 
-fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
-    let popup_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Percentage((100 - percent_y) / 2),
-            Constraint::Percentage(percent_y),
-            Constraint::Percentage((100 - percent_y) / 2),
-        ])
-        .split(r);
+/// Plain ASCII copy of a board for pasting into a chat.
+/// Arguments:
+/// - `board`: Cells of the game, X/O or empty.
+///
+/// Returns:
+/// - String: The board drawn like `render_board_text` does in ASCII mode, but without cursor,
+///   highlights or the digit hint grid, and with trailing spaces trimmed.
+pub fn board_as_text(board: &[Option<String>]) -> String {
+    let view = BoardView::read_only(["X".to_string(), "O".to_string()]);
+    let lines = render_board_text(board, &view, &Theme::default().palette(true), None);
+    // The last four lines are the blank line and the hint grid
+    lines[..lines.len() - 4]
+        .iter()
+        .map(|line| line.to_string().trim_end().to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
 
-    Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage((100 - percent_x) / 2),
-            Constraint::Percentage(percent_x),
-            Constraint::Percentage((100 - percent_x) / 2),
-        ])
-        .split(popup_layout[1])[1]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::{BoardSymbols, LayoutSize, Theme};
+
+    // X in the top-left corner, O in the center.
+    fn sample_board() -> Vec<Option<String>> {
+        let mut board = vec![None; BOARD_CELLS];
+        board[0] = Some("X".to_string());
+        board[4] = Some("O".to_string());
+        board
+    }
+
+    fn sample_view(marks: [String; 2]) -> BoardView {
+        BoardView {
+            cursor: Some(8),
+            ..BoardView::read_only(marks)
+        }
+    }
+
+    #[test]
+    fn screensaver_fills_the_board_then_swaps_who_starts() {
+        let (board, latest) = screensaver_board(Duration::ZERO);
+        assert_eq!(board.iter().flatten().count(), 1);
+        assert_eq!(board[4].as_deref(), Some("X"));
+        assert_eq!(latest, Some(4));
+
+        // Held full for a few steps, then the next round opens with O
+        let (board, _) = screensaver_board(SCREENSAVER_STEP * 11);
+        assert!(board.iter().all(Option::is_some));
+        let (board, _) = screensaver_board(SCREENSAVER_STEP * 12);
+        assert_eq!(board[4].as_deref(), Some("O"));
+    }
+
+    #[test]
+    fn turn_indicator_points_at_the_player_to_move() {
+        let game: ApiGame = serde_json::from_value(serde_json::json!({
+            "id": "g-1", "mode": "PVP", "name": null, "hostPlayerId": "p-1",
+            "guestPlayerId": "p-2", "currentTurn": "O", "status": "IN_PROGRESS",
+            "winner": null, "hasPassword": false
+        }))
+        .unwrap();
+        let view = sample_view(BoardSymbols::Letters.glyphs(""));
+        let palette = Theme::Classic.palette(false);
+
+        let lines = turn_indicator(&game, ["Ann", "Bob"], &view, &palette, "O");
+        assert_eq!(lines[0].to_string(), "X Ann   vs   O Bob");
+        assert_eq!(lines[1].to_string(), "             ^^^^^");
+        // My turn: my side is in the accent color
+        assert_eq!(lines[0].spans[2].style, palette.selected());
+
+        // No arrow once the game is over
+        let finished = ApiGame {
+            status: "DRAW".to_string(),
+            ..game
+        };
+        let lines = turn_indicator(&finished, ["Ann", "Bob"], &view, &palette, "O");
+        assert_eq!(lines[1].to_string().trim(), "");
+    }
+
+    #[test]
+    fn emoji_marks_keep_board_rows_aligned() {
+        let view = sample_view(BoardSymbols::Emoji.glyphs(""));
+
+        let lines = render_board_text(&sample_board(), &view, &Theme::Classic.palette(false), None);
+
+        // Three cell rows and two separators, all equally wide; the hint grid follows
+        let widths: Vec<usize> = lines[..5].iter().map(Line::width).collect();
+        assert!(widths.iter().all(|&width| width == widths[0]), "{widths:?}");
+        assert!(lines[0].to_string().contains('❌'));
+        assert!(lines[2].to_string().contains('⭕'));
+    }
+
+    #[test]
+    fn accessible_board_is_boxed_with_aligned_edges() {
+        let view = sample_view(BoardSymbols::Letters.glyphs(""));
+
+        let lines = render_board_text(&sample_board(), &view, &Theme::accessible_palette(), None);
+
+        // Top edge, three rows with two separators between them, bottom edge
+        let widths: Vec<usize> = lines[..7].iter().map(Line::width).collect();
+        assert!(widths.iter().all(|&width| width == widths[0]), "{widths:?}");
+        assert!(lines[0].to_string().starts_with('┌'));
+        assert!(lines[1].to_string().starts_with("│  X  │"));
+        assert!(lines[6].to_string().ends_with('┘'));
+    }
+
+    #[test]
+    fn coordinate_letters_sit_above_their_column() {
+        let mut view = sample_view(BoardSymbols::Letters.glyphs(""));
+        view.coordinates = true;
+
+        let lines = render_board_text(&sample_board(), &view, &Theme::Classic.palette(false), None);
+
+        // Column letters line up with the marks below them: X in A1, O in B2
+        let header = lines[0].to_string();
+        assert_eq!(header.find('A'), lines[1].to_string().find('X'));
+        assert_eq!(header.find('B'), lines[3].to_string().find('O'));
+        assert!(lines[5].to_string().starts_with("3 "));
+    }
+
+    #[test]
+    fn larger_boards_get_a_grid_of_their_own_size() {
+        let mut view = sample_view(BoardSymbols::Letters.glyphs(""));
+        view.coordinates = true;
+        view.cursor = None;
+        let mut board = vec![None; 81];
+        board[80] = Some("X".to_string());
+
+        let lines = render_board_text(&board, &view, &Theme::Classic.palette(false), None);
+
+        // Letters, nine rows with eight rules between them, and no digit hints
+        assert_eq!(lines.len(), 18);
+        assert!(lines[0].to_string().ends_with('I'));
+        let widths: Vec<usize> = lines[1..].iter().map(Line::width).collect();
+        assert!(widths.iter().all(|&width| width == widths[0]), "{widths:?}");
+        assert_eq!(
+            lines[0].to_string().find('I'),
+            lines[17].to_string().find('X')
+        );
+    }
+
+    #[test]
+    fn winning_line_is_drawn_in_bold_accent() {
+        let palette = Theme::Classic.palette(false);
+        let mut view = sample_view(BoardSymbols::Letters.glyphs(""));
+        view.cursor = None;
+        view.winning_line = Some([0, 4, 8]);
+        let mut board = sample_board();
+        board[4] = Some("X".to_string());
+        board[8] = Some("X".to_string());
+
+        let lines = render_board_text(&board, &view, &palette, None);
+
+        let winning = palette.selected().add_modifier(Modifier::BOLD);
+        // Rows are cell, separator, cell...: spans alternate cell and column separator
+        assert_eq!(lines[0].spans[0].style, winning);
+        assert_eq!(lines[2].spans[2].style, winning);
+        assert_eq!(lines[4].spans[4].style, winning);
+        assert_eq!(lines[0].spans[2].style, Style::default());
+    }
+
+    #[test]
+    fn layout_size_scales_screen_boxes() {
+        let terminal = Rect::new(0, 0, 100, 50);
+        let palette = Theme::default().palette(false);
+        let width = |size| palette.with_size(size).centered(60, 70, terminal).width;
+        assert_eq!(width(LayoutSize::Comfortable), 60);
+        assert_eq!(width(LayoutSize::Compact), 48);
+        assert_eq!(width(LayoutSize::Full), 98);
+    }
+
+    #[test]
+    fn board_text_has_no_cursor_or_hints() {
+        assert_eq!(
+            board_as_text(&sample_board()),
+            " X |   |\n-----------\n   | O |\n-----------\n   |   |"
+        );
+    }
+
+    #[test]
+    fn recap_names_cells_and_ends_with_the_result() {
+        let moves = [
+            MoveRecord {
+                index: 4,
+                symbol: "X".to_string(),
+            },
+            MoveRecord {
+                index: 0,
+                symbol: "O".to_string(),
+            },
+            MoveRecord {
+                index: 8,
+                symbol: "X".to_string(),
+            },
+        ];
+        assert_eq!(
+            move_recap(&moves, Some("X")),
+            "X: center, O: top-left, X: bottom-right - X wins"
+        );
+        assert_eq!(move_recap(&moves[..1], None), "X: center - draw");
+    }
+
+    #[test]
+    fn privacy_mode_hides_ids_and_marks_the_title() {
+        assert_eq!(shown_id("2d5f4a45-1111", true), HIDDEN_ID);
+        assert_eq!(shown_id("2d5f4a45-1111", false), "2d5f4a45-1111");
+
+        let palette = Theme::default().palette(false);
+        assert_eq!(privacy_title(&palette, "PvP Lobby", false), "PvP Lobby");
+        assert_eq!(privacy_title(&palette, "PvP Lobby", true), "PvP Lobby 🔒");
+        let ascii = Theme::default().palette(true);
+        assert_eq!(
+            privacy_title(&ascii, "PvP Lobby", true),
+            "PvP Lobby [private]"
+        );
+    }
 }